@@ -42,6 +42,28 @@ def bench_monotonicity_bbf(arity):
     return result
 
 
+def bench_monotonicity_bbf_universe(arity):
+    num_vars = 2 ** arity
+
+    variables = [var_name(i) for i in range(num_vars)]
+    universe = bbf.BddUniverse(variables)
+    literals = [universe.mk_literal(var, True) for var in variables]
+    result = universe.mk_const(True)
+
+    for i in range(arity):
+        block_size = 2 ** (i + 1)
+        half_block = int(block_size / 2)
+        regulator_formula = universe.mk_const(True)
+        for block in range(int(num_vars / block_size)):
+            for block_item in range(half_block):
+                var1 = literals[block_size * block + block_item]
+                var2 = literals[block_size * block + block_item + half_block]
+                implies = bbf.UniverseBdd.mk_or(bbf.UniverseBdd.mk_not(var1), var2)
+                regulator_formula = bbf.UniverseBdd.mk_and(regulator_formula, implies)
+        result = bbf.UniverseBdd.mk_and(result, regulator_formula)
+    return result
+
+
 def bench_monotonicity_pyeda(arity):
     num_vars = 2 ** arity
 
@@ -66,25 +88,32 @@ def bench_monotonicity_pyeda(arity):
 header = [
     "Var. count",
     "BBF[avg]",
+    "BBF-Universe[avg]",
     "PyEDA[avg]",
     "BBF[dev]",
+    "BBF-Universe[dev]",
     "PyEDA[dev]",
     "BBF[times]",
+    "BBF-Universe[times]",
     "PyEDA[times]"
 ]
 print("\t".join(header))
 
 for num_vars in range(1, 6):
     (bbf_avg, bbf_dev, bbf_times) = run_bench(lambda: bench_monotonicity_bbf(num_vars))
+    (universe_avg, universe_dev, universe_times) = run_bench(lambda: bench_monotonicity_bbf_universe(num_vars))
     (pyeda_avg, pyeda_dev, pyeda_times) = run_bench(lambda: bench_monotonicity_pyeda(num_vars))
 
     row = [
         str(num_vars),
         str(bbf_avg),
+        str(universe_avg),
         str(pyeda_avg),
         str(bbf_dev),
+        str(universe_dev),
         str(pyeda_dev),
         str(bbf_times),
+        str(universe_times),
         str(pyeda_times)
     ]
 
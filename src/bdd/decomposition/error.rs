@@ -0,0 +1,15 @@
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BddDecompositionError {
+    #[error("The variables {0} appear in both sets of the bi-decomposition partition")]
+    OverlappingPartition(String),
+}
+
+#[cfg(feature = "python")]
+impl From<BddDecompositionError> for PyErr {
+    fn from(err: BddDecompositionError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
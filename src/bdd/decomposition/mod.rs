@@ -0,0 +1,733 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddValuation, BddVariable, BddVariableSet};
+
+use crate::bdd::Bdd;
+use crate::expressions::{Expression, ExpressionNode};
+use crate::traits::BooleanFunction;
+
+pub use error::BddDecompositionError;
+
+mod error;
+
+/// The largest support of a prime block for which [Bdd::disjoint_decomposition] searches
+/// for bound sets. The search tests exponentially many candidate sets.
+const PRIME_BOUND_SET_MAX_SUPPORT: usize = 12;
+
+/// The kind of a block in a [DisjointDecomposition].
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DecompositionKind {
+    /// A constant function without inputs.
+    Constant,
+    /// A positive or negative literal of a single input.
+    Literal,
+    /// A conjunction of (possibly negated) blocks.
+    And,
+    /// A disjunction of (possibly negated) blocks.
+    Or,
+    /// An exclusive disjunction of blocks (possibly negated as a whole).
+    Xor,
+    /// A function of blocks which cannot be decomposed any further.
+    Prime,
+}
+
+/// The operator which combines the two functions of a [BiDecomposition].
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DecompositionOperator {
+    And,
+    Or,
+    Xor,
+}
+
+/// A node of the disjoint-support decomposition tree of a Boolean function.
+///
+/// The [DisjointDecomposition::function] of a node is expressed over the inputs which are
+/// literals of the node, and over one *representative* variable of every child block
+/// (the smallest variable of the block's support). Substituting the functions of the
+/// [DisjointDecomposition::children] for their representatives yields the function
+/// of the node, see [DisjointDecomposition::to_expression].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointDecomposition<T: Debug + Clone + Ord> {
+    kind: DecompositionKind,
+    function: Expression<T>,
+    children: BTreeMap<T, DisjointDecomposition<T>>,
+}
+
+/// A decomposition of a function `F` into `G operator H`, see [Bdd::bi_decomposition].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BiDecomposition<T: Debug + Clone + Ord> {
+    operator: DecompositionOperator,
+    first: Expression<T>,
+    second: Expression<T>,
+}
+
+impl<T: Debug + Clone + Ord + 'static> DisjointDecomposition<T> {
+    pub fn kind(&self) -> DecompositionKind {
+        self.kind
+    }
+
+    /// The function of this block, expressed over the representatives of its children.
+    pub fn function(&self) -> &Expression<T> {
+        &self.function
+    }
+
+    /// The child blocks, indexed by their representative variable. Blocks consisting of
+    /// a single literal are not included, the literals appear directly in the function.
+    pub fn children(&self) -> &BTreeMap<T, DisjointDecomposition<T>> {
+        &self.children
+    }
+
+    /// Composes the whole decomposition tree back into a single `Expression`.
+    pub fn to_expression(&self) -> Expression<T> {
+        let mapping = self
+            .children
+            .iter()
+            .map(|(representative, child)| (representative.clone(), child.to_expression()))
+            .collect();
+        self.function.substitute(&mapping)
+    }
+}
+
+impl<T: Debug + Clone + Ord> BiDecomposition<T> {
+    pub fn operator(&self) -> DecompositionOperator {
+        self.operator
+    }
+
+    /// The function which does not depend on the second set of variables.
+    pub fn first(&self) -> &Expression<T> {
+        &self.first
+    }
+
+    /// The function which does not depend on the first set of variables.
+    pub fn second(&self) -> &Expression<T> {
+        &self.second
+    }
+
+    /// The `Expression` `first operator second`.
+    pub fn to_expression(&self) -> Expression<T> {
+        match self.operator {
+            DecompositionOperator::And => Expression::binary_and(&self.first, &self.second),
+            DecompositionOperator::Or => Expression::binary_or(&self.first, &self.second),
+            DecompositionOperator::Xor => self.first.clone() ^ self.second.clone(),
+        }
+    }
+}
+
+impl DecompositionOperator {
+    fn apply(self, left: &InnerBdd, right: &InnerBdd) -> InnerBdd {
+        match self {
+            DecompositionOperator::And => left.and(right),
+            DecompositionOperator::Or => left.or(right),
+            DecompositionOperator::Xor => left.xor(right),
+        }
+    }
+}
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// Computes the disjoint-support decomposition of this function, i.e. the tree of blocks
+    /// `F(X) = G(H_1(A_1), ..., H_k(A_k), B)` where the sets `A_i` and `B` are disjoint.
+    ///
+    /// Every inner node is either an `AND`, `OR` or `XOR` of the finest possible blocks, or
+    /// a [DecompositionKind::Prime] block whose children are its maximal proper bound sets.
+    /// The tree only contains the essential inputs of this function.
+    ///
+    /// ### Implementation notes
+    ///
+    /// The `AND`/`OR`/`XOR` blocks are computed symbolically, by merging the blocks of
+    /// the smallest counterexample until the function is equivalent to the combination
+    /// of its cofactors. The children of a prime block are found by testing candidate bound
+    /// sets, which is exponential in the number of variables of the prime block. Therefore,
+    /// a prime block with more than 12 variables is not decomposed further, i.e. all its
+    /// variables appear as literals of its function.
+    pub fn disjoint_decomposition(&self) -> DisjointDecomposition<T> {
+        let variables = BddVariableSet::new_anonymous(self.bdd.num_vars());
+        self.decompose(&variables, &self.bdd)
+    }
+
+    /// Decomposes this function into `G(first, C) operator H(second, C)`, where `C` are
+    /// the inputs which are in neither of the two sets.
+    ///
+    /// Returns `Ok(None)` if no such decomposition exists. The decomposition is not unique,
+    /// for `AND` (`OR`), the returned functions are the largest (smallest) possible.
+    /// Returns an `Err` if `first` and `second` are not disjoint.
+    pub fn bi_decomposition(
+        &self,
+        operator: DecompositionOperator,
+        first: &BTreeSet<T>,
+        second: &BTreeSet<T>,
+    ) -> Result<Option<BiDecomposition<T>>, BddDecompositionError> {
+        let overlap = first.intersection(second).collect::<Vec<_>>();
+        if !overlap.is_empty() {
+            return Err(BddDecompositionError::OverlappingPartition(format!(
+                "{:?}",
+                overlap
+            )));
+        }
+
+        let to_inner = |variables: &BTreeSet<T>| {
+            variables
+                .iter()
+                .filter_map(|variable| self.map_var_outer_to_inner(variable))
+                .collect::<Vec<_>>()
+        };
+        let (first, second) = (to_inner(first), to_inner(second));
+        let (left, right) = match operator {
+            DecompositionOperator::And => (self.bdd.exists(&second), self.bdd.exists(&first)),
+            DecompositionOperator::Or => (self.bdd.for_all(&second), self.bdd.for_all(&first)),
+            DecompositionOperator::Xor => {
+                // `F[second = 0]` and `F[first = 0] ^ F[first = 0, second = 0]`.
+                let zero = |variables: &[BddVariable]| {
+                    variables
+                        .iter()
+                        .map(|variable| (*variable, false))
+                        .collect::<Vec<_>>()
+                };
+                let left = self.bdd.restrict(&zero(&second));
+                let right = self
+                    .bdd
+                    .restrict(&zero(&first))
+                    .xor(&left.restrict(&zero(&first)));
+                (left, right)
+            }
+        };
+
+        if operator.apply(&left, &right) != self.bdd {
+            return Ok(None);
+        }
+        Ok(Some(BiDecomposition {
+            operator,
+            first: self.inner_to_expression(left),
+            second: self.inner_to_expression(right),
+        }))
+    }
+
+    fn decompose(
+        &self,
+        variables: &BddVariableSet,
+        function: &InnerBdd,
+    ) -> DisjointDecomposition<T> {
+        if function.is_true() || function.is_false() {
+            let constant = ExpressionNode::Constant(function.is_true()).into();
+            return DisjointDecomposition::leaf(DecompositionKind::Constant, constant);
+        }
+
+        let support = sorted_support(function);
+        if let [variable] = support[..] {
+            let is_positive = function.var_restrict(variable, true).is_true();
+            let literal = self.literal(variable, is_positive);
+            return DisjointDecomposition::leaf(DecompositionKind::Literal, literal);
+        }
+
+        let operators = [
+            (DecompositionOperator::And, DecompositionKind::And),
+            (DecompositionOperator::Or, DecompositionKind::Or),
+            (DecompositionOperator::Xor, DecompositionKind::Xor),
+        ];
+        for (operator, kind) in operators {
+            let Some((blocks, is_negated)) =
+                operator_blocks(variables, function, &support, operator)
+            else {
+                continue;
+            };
+
+            let mut children = BTreeMap::new();
+            let terms = blocks
+                .iter()
+                .map(|block| self.child_term(variables, block, &mut children))
+                .collect::<Vec<_>>();
+            let function = match operator {
+                DecompositionOperator::And => Expression::n_ary_and(&terms),
+                DecompositionOperator::Or => Expression::n_ary_or(&terms),
+                DecompositionOperator::Xor => terms
+                    .into_iter()
+                    .reduce(|result, term| result ^ term)
+                    .expect("There are at least two blocks"),
+            };
+            let function = if is_negated {
+                Expression::negate(&function)
+            } else {
+                function
+            };
+            return DisjointDecomposition {
+                kind,
+                function,
+                children,
+            };
+        }
+
+        self.decompose_prime(variables, function, &support)
+    }
+
+    /// Decomposes a function without `AND`/`OR`/`XOR` blocks. Each maximal bound set is
+    /// replaced by its representative one by one, such that the remaining function
+    /// eventually only depends on the representatives.
+    ///
+    /// If the `support` is larger than [PRIME_BOUND_SET_MAX_SUPPORT], every variable is used
+    /// as a single-variable block instead.
+    fn decompose_prime(
+        &self,
+        variables: &BddVariableSet,
+        function: &InnerBdd,
+        support: &[BddVariable],
+    ) -> DisjointDecomposition<T> {
+        let mut remaining = function.clone();
+        let mut children = BTreeMap::new();
+        let mut unassigned = support.to_vec();
+        while let Some(&variable) = unassigned.first() {
+            let block = if support.len() <= PRIME_BOUND_SET_MAX_SUPPORT {
+                largest_bound_set(function, support, &unassigned, variable)
+            } else {
+                vec![variable]
+            };
+            unassigned.retain(|it| !block.contains(it));
+
+            let rest = sorted_support(&remaining)
+                .into_iter()
+                .filter(|it| !block.contains(it))
+                .collect::<Vec<_>>();
+            let (block_function, low, high) = bound_set_cofactors(&remaining, &block, &rest)
+                .expect("Blocks of the original function are bound sets of the remaining function");
+            self.child_term(variables, &block_function, &mut children);
+            remaining = InnerBdd::if_then_else(&variables.mk_var(block[0]), &high, &low);
+        }
+
+        DisjointDecomposition {
+            kind: DecompositionKind::Prime,
+            function: self.inner_to_expression(remaining),
+            children,
+        }
+    }
+
+    /// The term which represents the given `block` function in its parent: either a literal,
+    /// or the representative of a new child block.
+    fn child_term(
+        &self,
+        variables: &BddVariableSet,
+        block: &InnerBdd,
+        children: &mut BTreeMap<T, DisjointDecomposition<T>>,
+    ) -> Expression<T> {
+        let support = sorted_support(block);
+        if let [variable] = support[..] {
+            return self.literal(variable, block.var_restrict(variable, true).is_true());
+        }
+
+        let representative = self
+            .map_var_inner_to_outer(support[0])
+            .expect("Support variables are inputs");
+        children.insert(representative.clone(), self.decompose(variables, block));
+        ExpressionNode::Literal(representative).into()
+    }
+
+    fn literal(&self, variable: BddVariable, is_positive: bool) -> Expression<T> {
+        let variable = self
+            .map_var_inner_to_outer(variable)
+            .expect("Support variables are inputs");
+        let literal = ExpressionNode::Literal(variable).into();
+        if is_positive {
+            literal
+        } else {
+            Expression::negate(&literal)
+        }
+    }
+
+    fn inner_to_expression(&self, bdd: InnerBdd) -> Expression<T> {
+        Expression::from(Bdd::new(bdd, self.inputs.clone()))
+    }
+}
+
+impl<T: Debug + Clone + Ord> DisjointDecomposition<T> {
+    fn leaf(kind: DecompositionKind, function: Expression<T>) -> DisjointDecomposition<T> {
+        DisjointDecomposition {
+            kind,
+            function,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+fn sorted_support(function: &InnerBdd) -> Vec<BddVariable> {
+    let mut support = function.support_set().into_iter().collect::<Vec<_>>();
+    support.sort();
+    support
+}
+
+/// Splits the `support` of a non-constant `function` into the finest blocks such that
+/// the function is the `operator` applied to functions of the individual blocks. Returns
+/// the block functions and whether the combination must be negated (only for `XOR`),
+/// or `None` if there is only one block.
+///
+/// The block functions are the cofactors of `function` where the variables of other blocks
+/// are fixed to a *neutral point* (a satisfying point for `AND`, a falsifying point for `OR`).
+/// Whenever the combined blocks differ from the function, the counterexample with the fewest
+/// differing blocks must differ only within a single block of the finest decomposition,
+/// hence its differing blocks can be safely merged.
+fn operator_blocks(
+    variables: &BddVariableSet,
+    function: &InnerBdd,
+    support: &[BddVariable],
+    operator: DecompositionOperator,
+) -> Option<(Vec<InnerBdd>, bool)> {
+    let point = match operator {
+        DecompositionOperator::And => function.sat_witness(),
+        DecompositionOperator::Or => function.not().sat_witness(),
+        DecompositionOperator::Xor => Some(BddValuation::all_false(variables.num_vars())),
+    }
+    .expect("The function is not constant");
+    let point_value = function.eval_in(&point);
+
+    let mut blocks = support.iter().map(|it| vec![*it]).collect::<Vec<_>>();
+    while blocks.len() > 1 {
+        let functions = blocks
+            .iter()
+            .map(|block| {
+                let fixed = support
+                    .iter()
+                    .filter(|it| !block.contains(it))
+                    .map(|it| (*it, point[*it]))
+                    .collect::<Vec<_>>();
+                function.restrict(&fixed)
+            })
+            .collect::<Vec<_>>();
+
+        // For `XOR`, every block function also contains the value of the point.
+        let point_constant = if point_value {
+            variables.mk_true()
+        } else {
+            variables.mk_false()
+        };
+        let combined = match operator {
+            DecompositionOperator::And => functions
+                .iter()
+                .fold(variables.mk_true(), |result, it| result.and(it)),
+            DecompositionOperator::Or => functions
+                .iter()
+                .fold(variables.mk_false(), |result, it| result.or(it)),
+            DecompositionOperator::Xor => {
+                functions.iter().fold(point_constant.clone(), |result, it| {
+                    result.xor(it).xor(&point_constant)
+                })
+            }
+        };
+
+        let mismatch = function.xor(&combined);
+        if mismatch.is_false() {
+            let is_negated =
+                operator == DecompositionOperator::Xor && point_value && blocks.len() % 2 == 0;
+            return Some((functions, is_negated));
+        }
+
+        let counterexample = fewest_differing_blocks(variables, &mismatch, &blocks, &point);
+        let (merged, mut kept): (Vec<_>, Vec<_>) = blocks
+            .into_iter()
+            .partition(|block| block.iter().any(|it| counterexample[*it] != point[*it]));
+        let mut merged = merged.concat();
+        merged.sort();
+        kept.push(merged);
+        kept.sort();
+        blocks = kept;
+    }
+    None
+}
+
+/// A point of `set` which differs from `point` in the smallest number of `blocks`.
+fn fewest_differing_blocks(
+    variables: &BddVariableSet,
+    set: &InnerBdd,
+    blocks: &[Vec<BddVariable>],
+    point: &BddValuation,
+) -> BddValuation {
+    // `exactly[j]` are the points which differ from `point` in exactly `j` blocks.
+    let mut exactly = vec![variables.mk_true()];
+    for block in blocks {
+        let differs = block.iter().fold(variables.mk_false(), |result, it| {
+            result.or(&variables.mk_literal(*it, !point[*it]))
+        });
+        let mut next = exactly
+            .iter()
+            .map(|it| it.and_not(&differs))
+            .collect::<Vec<_>>();
+        next.push(variables.mk_false());
+        for (count, it) in exactly.iter().enumerate() {
+            next[count + 1] = next[count + 1].or(&it.and(&differs));
+        }
+        exactly = next;
+    }
+
+    exactly
+        .iter()
+        .find_map(|it| set.and(it).sat_witness())
+        .expect("The set is not empty")
+}
+
+/// The largest proper bound set of `function` which contains `variable` and otherwise only
+/// `unassigned` variables, or just the `variable` if there is no such set.
+fn largest_bound_set(
+    function: &InnerBdd,
+    support: &[BddVariable],
+    unassigned: &[BddVariable],
+    variable: BddVariable,
+) -> Vec<BddVariable> {
+    let candidates = unassigned
+        .iter()
+        .copied()
+        .filter(|it| *it != variable)
+        .collect::<Vec<_>>();
+    let largest = candidates.len().min(support.len().saturating_sub(2));
+    for size in (1..=largest).rev() {
+        let masks = (0usize..(1 << candidates.len())).filter(|it| it.count_ones() as usize == size);
+        for mask in masks {
+            let mut block = candidates
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| mask & (1 << index) != 0)
+                .map(|(_, it)| *it)
+                .chain([variable])
+                .collect::<Vec<_>>();
+            block.sort();
+            let rest = support
+                .iter()
+                .copied()
+                .filter(|it| !block.contains(it))
+                .collect::<Vec<_>>();
+            if bound_set_cofactors(function, &block, &rest).is_some() {
+                return block;
+            }
+        }
+    }
+    vec![variable]
+}
+
+/// If `block` is a bound set of `function`, i.e. `function = ite(H(block), high, low)` where
+/// `high` and `low` only depend on the `rest` of the variables, returns `(H, low, high)`.
+fn bound_set_cofactors(
+    function: &InnerBdd,
+    block: &[BddVariable],
+    rest: &[BddVariable],
+) -> Option<(InnerBdd, InnerBdd, InnerBdd)> {
+    // The valuations of `block` which produce the same cofactor as the given valuation.
+    let same_cofactor = |cofactor: &InnerBdd| function.iff(cofactor).for_all(rest);
+
+    let low_point = block.iter().map(|it| (*it, false)).collect::<Vec<_>>();
+    let low = function.restrict(&low_point);
+    let low_points = same_cofactor(&low);
+
+    let high_point = low_points.not().sat_witness()?;
+    let high_point = block
+        .iter()
+        .map(|it| (*it, high_point[*it]))
+        .collect::<Vec<_>>();
+    let high = function.restrict(&high_point);
+    let high_points = same_cofactor(&high);
+
+    low_points
+        .or(&high_points)
+        .is_true()
+        .then_some((high_points, low, high))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::traits::GatherLiterals;
+
+    fn assert_composes_to(decomposition: &DisjointDecomposition<String>, function: &Bdd<String>) {
+        let composed = Bdd::try_from(decomposition.to_expression()).unwrap();
+        assert!(composed.is_equivalent(function));
+    }
+
+    fn set(variables: &[&str]) -> BTreeSet<String> {
+        variables.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_disjoint_decomposition_operators_ok() {
+        let function = (var("a") & !var("b")) | (var("c") ^ var("d")) | !(var("e") | var("f"));
+        let function = Bdd::try_from(function).unwrap();
+        let decomposition = function.disjoint_decomposition();
+        assert_composes_to(&decomposition, &function);
+
+        assert_eq!(decomposition.kind(), DecompositionKind::Or);
+        let kinds = decomposition
+            .children()
+            .iter()
+            .map(|(variable, child)| (variable.as_str(), child.kind()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                ("a", DecompositionKind::And),
+                ("c", DecompositionKind::Xor),
+                ("e", DecompositionKind::And),
+            ]
+        );
+        assert_eq!(
+            decomposition.children()["a"].function(),
+            &(var("a") & !var("b"))
+        );
+    }
+
+    #[test]
+    fn test_disjoint_decomposition_xor_negated_ok() {
+        let function = Bdd::try_from(!(var("a") ^ var("b") ^ (var("c") & var("d")))).unwrap();
+        let decomposition = function.disjoint_decomposition();
+        assert_composes_to(&decomposition, &function);
+
+        assert_eq!(decomposition.kind(), DecompositionKind::Xor);
+        assert_eq!(decomposition.children().len(), 1);
+        assert_eq!(decomposition.children()["c"].kind(), DecompositionKind::Or);
+    }
+
+    #[test]
+    fn test_disjoint_decomposition_prime_ok() {
+        // A multiplexer selecting between two blocks.
+        let first = var("a") & var("b");
+        let second = var("c") ^ var("d");
+        let function = (var("s") & first) | (!var("s") & second) | var("x");
+        let function = Bdd::try_from(function).unwrap();
+        let decomposition = function.disjoint_decomposition();
+        assert_composes_to(&decomposition, &function);
+
+        assert_eq!(decomposition.kind(), DecompositionKind::Or);
+        let (representative, multiplexer) = decomposition.children().first_key_value().unwrap();
+        assert_eq!(representative, "a");
+        assert_eq!(multiplexer.kind(), DecompositionKind::Prime);
+        assert_eq!(
+            multiplexer
+                .children()
+                .keys()
+                .cloned()
+                .collect::<BTreeSet<_>>(),
+            set(&["a", "c"])
+        );
+        assert_eq!(multiplexer.children()["c"].kind(), DecompositionKind::Xor);
+
+        let majority = (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c"));
+        let majority = Bdd::try_from(majority).unwrap();
+        let decomposition = majority.disjoint_decomposition();
+        assert_composes_to(&decomposition, &majority);
+        assert_eq!(decomposition.kind(), DecompositionKind::Prime);
+        assert!(decomposition.children().is_empty());
+    }
+
+    #[test]
+    fn test_disjoint_decomposition_large_prime_ok() {
+        // A multiplexer over blocks which are too large to search for bound sets.
+        let block = |prefix: &str| {
+            (0..PRIME_BOUND_SET_MAX_SUPPORT / 2)
+                .map(|index| var(format!("{prefix}{index}")))
+                .collect::<Vec<_>>()
+        };
+        let first = Expression::n_ary_and(&block("a"));
+        let second = Expression::n_ary_or(&block("b"));
+        let function = (var("s") & first) | (!var("s") & second);
+        let function = Bdd::try_from(function).unwrap();
+        let decomposition = function.disjoint_decomposition();
+        assert_composes_to(&decomposition, &function);
+
+        assert_eq!(decomposition.kind(), DecompositionKind::Prime);
+        assert!(decomposition.children().is_empty());
+        assert_eq!(
+            decomposition.function().gather_literals(),
+            function.gather_literals()
+        );
+    }
+
+    #[test]
+    fn test_disjoint_decomposition_exhaustive_ok() {
+        // Every function of three inputs, and a sample of functions of four inputs.
+        let functions = (0u32..256)
+            .map(|outputs| (3, outputs))
+            .chain((0u32..65536).step_by(251).map(|outputs| (4, outputs)));
+        for (variable_count, outputs) in functions {
+            let minterms = (0..1u32 << variable_count)
+                .filter(|row| outputs & (1 << row) != 0)
+                .map(|row| {
+                    let literals = (0..variable_count)
+                        .map(|index| {
+                            let literal = var(format!("x{index}"));
+                            if row & (1 << index) != 0 {
+                                literal
+                            } else {
+                                !literal
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    Expression::n_ary_and(&literals)
+                })
+                .collect::<Vec<_>>();
+            let function = Bdd::try_from(Expression::n_ary_or(&minterms)).unwrap();
+            assert_composes_to(&function.disjoint_decomposition(), &function);
+        }
+    }
+
+    #[test]
+    fn test_disjoint_decomposition_leaves_ok() {
+        let function = Bdd::try_from(!var("a") | (var("b") & !var("b"))).unwrap();
+        let decomposition = function.disjoint_decomposition();
+        assert_eq!(decomposition.kind(), DecompositionKind::Literal);
+        assert_eq!(decomposition.function(), &!var("a"));
+
+        let decomposition = Bdd::<String>::mk_const(true).disjoint_decomposition();
+        assert_eq!(decomposition.kind(), DecompositionKind::Constant);
+    }
+
+    #[test]
+    fn test_bi_decomposition_ok() {
+        let function = (var("a") & var("c")) | (var("b") & !var("c"));
+        let function = Bdd::try_from(function).unwrap();
+        let (first, second) = (set(&["a"]), set(&["b"]));
+
+        let operators = [
+            DecompositionOperator::And,
+            DecompositionOperator::Or,
+            DecompositionOperator::Xor,
+        ];
+        for operator in operators {
+            let decomposition = function
+                .bi_decomposition(operator, &first, &second)
+                .unwrap()
+                .unwrap();
+            assert_eq!(decomposition.operator(), operator);
+            assert!(!decomposition.first().gather_literals().contains("b"));
+            assert!(!decomposition.second().gather_literals().contains("a"));
+
+            let composed = decomposition.to_expression();
+            assert!(Bdd::try_from(composed).unwrap().is_equivalent(&function));
+        }
+
+        let function = Bdd::try_from(var("a") ^ var("b")).unwrap();
+        for operator in [DecompositionOperator::And, DecompositionOperator::Or] {
+            let result = function.bi_decomposition(operator, &first, &second);
+            assert_eq!(result, Ok(None));
+        }
+
+        // The decomposed functions can be composed through substitution as well.
+        let decomposition = function
+            .bi_decomposition(DecompositionOperator::Xor, &first, &second)
+            .unwrap()
+            .unwrap();
+        let mapping = BTreeMap::from([
+            ("g".to_string(), decomposition.first().clone()),
+            ("h".to_string(), decomposition.second().clone()),
+        ]);
+        let composed = (var("g") ^ var("h")).substitute(&mapping);
+        assert!(Bdd::try_from(composed).unwrap().is_equivalent(&function));
+    }
+
+    #[test]
+    fn test_bi_decomposition_overlapping_err() {
+        let function = Bdd::try_from(var("a") & var("b")).unwrap();
+        let result =
+            function.bi_decomposition(DecompositionOperator::And, &set(&["a"]), &set(&["a", "b"]));
+        assert_eq!(
+            result,
+            Err(BddDecompositionError::OverlappingPartition(
+                "[\"a\"]".to_string()
+            ))
+        );
+    }
+}
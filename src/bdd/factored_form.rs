@@ -0,0 +1,245 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use biodivine_lib_bdd::BddPointer;
+
+use crate::bdd::Bdd;
+use crate::expressions::{Expression, ExpressionNode};
+
+/// An operand of a [FactoredNode]: either a constant or a reference to another node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FactoredOperand {
+    Constant(bool),
+    /// Index into [FactoredForm::nodes].
+    Node(usize),
+}
+
+/// The Shannon expansion `if variable then high else low`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FactoredNode<T> {
+    pub variable: T,
+    pub low: FactoredOperand,
+    pub high: FactoredOperand,
+}
+
+/// A network of if-then-else (Shannon expansion) nodes following the structure of a `Bdd`.
+///
+/// Every node only refers to nodes with smaller indices, so the nodes can be evaluated
+/// (or emitted as assignments by a code generator) in order. The number of nodes is the
+/// number of non-terminal nodes of the `Bdd`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FactoredForm<T> {
+    nodes: Vec<FactoredNode<T>>,
+    root: FactoredOperand,
+}
+
+impl<T: Debug + Clone + Ord> FactoredForm<T> {
+    pub fn nodes(&self) -> &[FactoredNode<T>] {
+        &self.nodes
+    }
+
+    /// The operand representing the whole function.
+    pub fn root(&self) -> FactoredOperand {
+        self.root
+    }
+
+    /// Converts this factored form into an `Expression`. The expression of every node is
+    /// created once and referenced by all its parents, but the result is still a tree:
+    /// its size (and the cost of every operation that traverses it, e.g. printing it) can be
+    /// exponential in the number of nodes. This factored form is the compact representation.
+    pub fn to_expression(&self) -> Expression<T> {
+        let mut expressions: Vec<Expression<T>> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let operand = |operand: FactoredOperand| match operand {
+                FactoredOperand::Constant(value) => Err(value),
+                FactoredOperand::Node(index) => Ok(expressions[index].clone()),
+            };
+            let literal: Expression<T> = ExpressionNode::Literal(node.variable.clone()).into();
+            let not_literal = Expression::negate(&literal);
+
+            let expression = match (operand(node.low), operand(node.high)) {
+                (Err(false), Err(true)) => literal,
+                (Err(true), Err(false)) => not_literal,
+                (Err(false), Ok(high)) => Expression::binary_and(&literal, &high),
+                (Ok(low), Err(false)) => Expression::binary_and(&not_literal, &low),
+                (Err(true), Ok(high)) => Expression::binary_or(&not_literal, &high),
+                (Ok(low), Err(true)) => Expression::binary_or(&literal, &low),
+                (Ok(low), Ok(high)) => Expression::binary_or(
+                    &Expression::binary_and(&literal, &high),
+                    &Expression::binary_and(&not_literal, &low),
+                ),
+                (Err(_), Err(_)) => unreachable!("BDD nodes have different children"),
+            };
+            expressions.push(expression);
+        }
+
+        match self.root {
+            FactoredOperand::Constant(value) => ExpressionNode::Constant(value).into(),
+            FactoredOperand::Node(index) => expressions.swap_remove(index),
+        }
+    }
+}
+
+impl Display for FactoredOperand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactoredOperand::Constant(value) => write!(f, "{value}"),
+            FactoredOperand::Node(index) => write!(f, "n{index}"),
+        }
+    }
+}
+
+/// Writes one assignment `n<index> = ite(variable, high, low)` per line, followed by
+/// `root = <operand>`.
+impl<T: Debug + Clone + Ord + Display> Display for FactoredForm<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, node) in self.nodes.iter().enumerate() {
+            writeln!(
+                f,
+                "n{index} = ite({}, {}, {})",
+                node.variable, node.high, node.low
+            )?;
+        }
+        write!(f, "root = {}", self.root)
+    }
+}
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// Converts this `Bdd` into a network of if-then-else nodes (see [FactoredForm]).
+    pub fn to_factored_form(&self) -> FactoredForm<T> {
+        let operand = |pointer: BddPointer| match pointer.to_index() {
+            0 => FactoredOperand::Constant(false),
+            1 => FactoredOperand::Constant(true),
+            // The two terminal nodes are not part of the factored form.
+            index => FactoredOperand::Node(index - 2),
+        };
+
+        // `lib_bdd` stores nodes in DFS post-order, so children always precede their parents.
+        let nodes = (2..self.bdd.size())
+            .map(BddPointer::from_index)
+            .map(|pointer| FactoredNode {
+                variable: self.inputs[self.bdd.var_of(pointer).to_index()].clone(),
+                low: operand(self.bdd.low_link_of(pointer)),
+                high: operand(self.bdd.high_link_of(pointer)),
+            })
+            .collect();
+
+        FactoredForm {
+            nodes,
+            root: operand(self.bdd.root_pointer()),
+        }
+    }
+
+    /// Converts this `Bdd` into an `Expression` that follows the if-then-else structure
+    /// of the BDD (see [FactoredForm::to_expression]).
+    ///
+    /// The expression can be exponentially larger than the BDD, since it does not share
+    /// subterms as a graph. Use [Bdd::to_factored_form] to get a compact representation.
+    pub fn to_ite_expression(&self) -> Expression<T> {
+        self.to_factored_form().to_expression()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::traits::{BooleanFunction, Evaluate, SemanticEq};
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    /// The number of distinct expression nodes, counting shared subterms once.
+    fn graph_size(expression: &Expression<String>) -> usize {
+        fn rec(expression: &Expression<String>, seen: &mut HashSet<*const ()>) {
+            let node = expression.node();
+            if !seen.insert(node as *const ExpressionNode<String> as *const ()) {
+                return;
+            }
+            match node {
+                ExpressionNode::Literal(_) | ExpressionNode::Constant(_) => (),
+                ExpressionNode::Not(inner) => rec(inner, seen),
+                ExpressionNode::And(es) | ExpressionNode::Or(es) => {
+                    es.iter().for_each(|e| rec(e, seen))
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        rec(expression, &mut seen);
+        seen.len()
+    }
+
+    #[test]
+    fn test_to_ite_expression_ok() {
+        let input = Expression::from_str("(a & !b) | (c & !d) | (!c & d) | (a & d)").unwrap();
+        let bdd = Bdd::try_from(input.clone()).unwrap();
+
+        let actual = bdd.to_ite_expression();
+
+        assert!(actual.semantic_eq(&input));
+        assert!(Bdd::try_from(actual).unwrap().is_equivalent(&bdd));
+    }
+
+    #[test]
+    fn test_to_ite_expression_parity_linear_ok() {
+        let variables = (0..16).map(var).collect::<Vec<_>>();
+        let parity = variables
+            .iter()
+            .skip(1)
+            .fold(variables[0].clone(), |acc, it| acc ^ it.clone());
+        let bdd = Bdd::try_from(parity).unwrap();
+
+        let actual = bdd.to_ite_expression();
+
+        // Every BDD node contributes at most 5 expression nodes: a literal, its negation,
+        // two conjunctions and a disjunction.
+        assert!(graph_size(&actual) <= 5 * bdd.node_count());
+        let point = (0..16).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let valuation = (0..16)
+            .map(|i| i.to_string())
+            .zip(point.iter().copied())
+            .collect();
+        let expected = point.iter().filter(|it| **it).count() % 2 == 1;
+        assert_eq!(actual.evaluate(&valuation), expected);
+    }
+
+    #[test]
+    fn test_to_factored_form_ok() {
+        let bdd = Bdd::try_from(var("a") & !var("b")).unwrap();
+
+        let actual = bdd.to_factored_form();
+
+        assert_eq!(
+            actual.nodes(),
+            &[
+                FactoredNode {
+                    variable: "b".to_string(),
+                    low: FactoredOperand::Constant(true),
+                    high: FactoredOperand::Constant(false),
+                },
+                FactoredNode {
+                    variable: "a".to_string(),
+                    low: FactoredOperand::Constant(false),
+                    high: FactoredOperand::Node(0),
+                },
+            ]
+        );
+        assert_eq!(actual.root(), FactoredOperand::Node(1));
+        assert_eq!(
+            actual.to_string(),
+            "n0 = ite(b, false, true)\nn1 = ite(a, n0, false)\nroot = n1"
+        );
+        assert_eq!(actual.to_expression().to_string(), "(a & !(b))");
+    }
+
+    #[test]
+    fn test_to_factored_form_constant_ok() {
+        let actual = Bdd::<String>::mk_const(true).to_factored_form();
+
+        assert!(actual.nodes().is_empty());
+        assert_eq!(actual.root(), FactoredOperand::Constant(true));
+        assert_eq!(
+            actual.to_expression(),
+            ExpressionNode::Constant(true).into()
+        );
+    }
+}
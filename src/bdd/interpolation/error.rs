@@ -0,0 +1,22 @@
+use std::num::TryFromIntError;
+
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InterpolationError {
+    #[error("An interpolant only exists if the conjunction of both functions is unsatisfiable")]
+    Satisfiable,
+    #[error(
+        "Cannot convert a function with more than {} variables to a Bdd",
+        u16::MAX
+    )]
+    TooManyVariables(#[from] TryFromIntError),
+}
+
+#[cfg(feature = "python")]
+impl From<InterpolationError> for PyErr {
+    fn from(err: InterpolationError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use crate::bdd::Bdd;
+use crate::traits::BooleanFunction;
+
+pub use error::InterpolationError;
+
+mod error;
+
+/// Selects one of the interpolants computed by [Bdd::interpolant_with_kind].
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum InterpolantKind {
+    /// The interpolant which implies every other interpolant: `A` with all variables that
+    /// are not shared with `B` existentially quantified.
+    #[default]
+    Strongest,
+    /// The interpolant which is implied by every other interpolant: the negation of `B`
+    /// with all variables that are not shared with `A` universally quantified.
+    Weakest,
+}
+
+impl<T: Debug + Clone + Ord + 'static> Bdd<T> {
+    /// Computes the strongest Craig interpolant of `a` and `b`, see
+    /// [Bdd::interpolant_with_kind].
+    pub fn interpolant(a: &Bdd<T>, b: &Bdd<T>) -> Result<Bdd<T>, InterpolationError> {
+        Self::interpolant_with_kind(a, b, InterpolantKind::default())
+    }
+
+    /// Computes a Craig interpolant of `a` and `b`, i.e. a function `I` such that `a => I`,
+    /// `I & b` is unsatisfiable, and `I` only depends on the inputs shared by `a` and `b`.
+    ///
+    /// The inputs of the result are exactly the shared inputs. Returns an `Err` if `a & b`
+    /// is satisfiable, in which case no interpolant exists.
+    ///
+    /// ### Examples
+    ///
+    /// For `a = x & y1 & y2` and `b = z & !y1 & !y2`, the strongest interpolant is `y1 & y2`
+    /// and the weakest interpolant is `y1 | y2`.
+    pub fn interpolant_with_kind(
+        a: &Bdd<T>,
+        b: &Bdd<T>,
+        kind: InterpolantKind,
+    ) -> Result<Bdd<T>, InterpolationError> {
+        if !(a & b).bdd.is_false() {
+            return Err(InterpolationError::Satisfiable);
+        }
+
+        let only_in = |function: &Bdd<T>, other: &Bdd<T>| {
+            let other_inputs = other.inputs();
+            function
+                .inputs()
+                .into_iter()
+                .filter(|it| !other_inputs.contains(it))
+                .collect::<BTreeSet<_>>()
+        };
+        Ok(match kind {
+            InterpolantKind::Strongest => a.existential_quantification(only_in(a, b)),
+            InterpolantKind::Weakest => !b.existential_quantification(only_in(b, a)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+
+    fn assert_is_interpolant(a: &Bdd<String>, b: &Bdd<String>, interpolant: &Bdd<String>) {
+        assert!(interpolant.is_implied_by(a));
+        assert!((interpolant & b).sat_point().is_none());
+
+        let shared = a
+            .inputs()
+            .intersection(&b.inputs())
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        assert_eq!(interpolant.inputs(), shared);
+    }
+
+    #[test]
+    fn test_interpolant_ok() {
+        let a = Bdd::try_from(var("x") & var("y1") & var("y2")).unwrap();
+        let b = Bdd::try_from(var("z") & !var("y1") & !var("y2")).unwrap();
+
+        let strongest = Bdd::interpolant(&a, &b).unwrap();
+        assert_is_interpolant(&a, &b, &strongest);
+        assert!(strongest.is_equivalent(&Bdd::try_from(var("y1") & var("y2")).unwrap()));
+
+        let weakest = Bdd::interpolant_with_kind(&a, &b, InterpolantKind::Weakest).unwrap();
+        assert_is_interpolant(&a, &b, &weakest);
+        assert!(weakest.is_equivalent(&Bdd::try_from(var("y1") | var("y2")).unwrap()));
+        assert!(weakest.is_implied_by(&strongest));
+    }
+
+    #[test]
+    fn test_interpolant_without_shared_inputs_ok() {
+        let a = Bdd::try_from(var("x") & !var("x")).unwrap();
+        let b = Bdd::try_from(var("y")).unwrap();
+
+        let strongest = Bdd::interpolant(&a, &b).unwrap();
+        assert!(strongest.is_equivalent(&Bdd::mk_const(false)));
+        let weakest = Bdd::interpolant_with_kind(&a, &b, InterpolantKind::Weakest).unwrap();
+        assert!(weakest.is_equivalent(&Bdd::mk_const(false)));
+        assert!(weakest.inputs().is_empty());
+    }
+
+    #[test]
+    fn test_interpolant_satisfiable_err() {
+        let a = Bdd::try_from(var("x") & var("y")).unwrap();
+        let b = Bdd::try_from(var("y") | var("z")).unwrap();
+
+        assert_eq!(
+            Bdd::interpolant(&a, &b),
+            Err(InterpolationError::Satisfiable)
+        );
+    }
+}
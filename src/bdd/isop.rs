@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddVariable, BddVariableSet};
+
+use crate::bdd::Bdd;
+use crate::expressions::{Expression, ExpressionNode};
+use crate::traits::BooleanValuation;
+
+/// A product term over the `lib-bdd` variables, as (variable, value) pairs.
+type InnerCube = Vec<(BddVariable, bool)>;
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// Computes an irredundant sum-of-products cover of this function using the
+    /// Minato–Morreale ISOP algorithm.
+    ///
+    /// Each cube of the result is a partial valuation of the inputs. The cover is irredundant:
+    /// no cube can be removed and no literal can be removed from a cube without changing the
+    /// covered function. It is not guaranteed to be a minimum cover.
+    ///
+    /// If a `care_set` is given, the cover only has to agree with this function on the points
+    /// of the `care_set`; other points are treated as don't-cares. The cubes can then also
+    /// mention inputs of the `care_set` that are not inputs of `self`.
+    pub fn isop_cubes(&self, care_set: Option<&Bdd<T>>) -> Vec<BooleanValuation<T>> {
+        let (lower, upper, inputs) = match care_set {
+            None => (self.bdd.clone(), self.bdd.clone(), self.inputs.clone()),
+            Some(care_set) => {
+                let (self_lifted, care_lifted, inputs) = self.union_and_extend(care_set);
+                let lower = self_lifted.bdd.and(&care_lifted.bdd);
+                let upper = self_lifted.bdd.or(&care_lifted.bdd.not());
+                (lower, upper, inputs)
+            }
+        };
+
+        let mut cache = HashMap::new();
+        let (cubes, _cover) = isop(&lower, &upper, &mut cache);
+
+        cubes
+            .into_iter()
+            .map(|cube| {
+                cube.into_iter()
+                    .map(|(var, value)| (inputs[var.to_index()].clone(), value))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The irredundant sum-of-products cover of [Bdd::isop_cubes] as an `Expression`.
+    pub fn to_isop_expression(&self, care_set: Option<&Bdd<T>>) -> Expression<T> {
+        cubes_to_expression(self.isop_cubes(care_set))
+    }
+}
+
+/// Converts a list of cubes to a disjunction of conjunctions of literals.
+///
+/// The empty cover is the constant `false` and a cover with an empty cube is the constant `true`.
+pub(crate) fn cubes_to_expression<T: Debug + Clone + Ord>(
+    cubes: Vec<BooleanValuation<T>>,
+) -> Expression<T> {
+    if cubes.is_empty() {
+        return ExpressionNode::Constant(false).into();
+    } else if cubes.iter().any(|cube| cube.is_empty()) {
+        return ExpressionNode::Constant(true).into();
+    }
+
+    let terms = cubes
+        .into_iter()
+        .map(|cube| {
+            let literals = cube
+                .into_iter()
+                .map(|(var, value)| {
+                    let literal: Expression<T> = ExpressionNode::Literal(var).into();
+                    if value {
+                        literal
+                    } else {
+                        Expression::negate(&literal)
+                    }
+                })
+                .collect::<Vec<_>>();
+            Expression::n_ary_and(&literals)
+        })
+        .collect::<Vec<_>>();
+
+    Expression::n_ary_or(&terms)
+}
+
+/// Computes an irredundant cover `f` such that `lower => f => upper`, returning both its
+/// cubes and its `InnerBdd`.
+fn isop(
+    lower: &InnerBdd,
+    upper: &InnerBdd,
+    cache: &mut HashMap<(InnerBdd, InnerBdd), (Vec<InnerCube>, InnerBdd)>,
+) -> (Vec<InnerCube>, InnerBdd) {
+    if lower.is_false() {
+        return (Vec::new(), lower.clone());
+    }
+    if upper.is_true() {
+        return (vec![Vec::new()], upper.clone());
+    }
+
+    let key = (lower.clone(), upper.clone());
+    if let Some(result) = cache.get(&key) {
+        return result.clone();
+    }
+
+    let var = top_variable(lower).min(top_variable(upper));
+    let (lower_0, lower_1) = (
+        lower.var_restrict(var, false),
+        lower.var_restrict(var, true),
+    );
+    let (upper_0, upper_1) = (
+        upper.var_restrict(var, false),
+        upper.var_restrict(var, true),
+    );
+
+    // Cubes which must contain `!var` (resp. `var`), because they cover points that
+    // cannot be covered when `var` is removed.
+    let (cubes_0, cover_0) = isop(&lower_0.and_not(&upper_1), &upper_0, cache);
+    let (cubes_1, cover_1) = isop(&lower_1.and_not(&upper_0), &upper_1, cache);
+
+    // Cubes independent of `var` for the remaining points.
+    let remaining = lower_0.and_not(&cover_0).or(&lower_1.and_not(&cover_1));
+    let (cubes_d, cover_d) = isop(&remaining, &upper_0.and(&upper_1), cache);
+
+    let literal = mk_var_like(lower, var);
+    let cover = literal
+        .not()
+        .and(&cover_0)
+        .or(&literal.and(&cover_1))
+        .or(&cover_d);
+
+    let with_literal = |cubes: Vec<InnerCube>, value: bool| {
+        cubes.into_iter().map(move |mut cube| {
+            cube.insert(0, (var, value));
+            cube
+        })
+    };
+    let cubes = with_literal(cubes_0, false)
+        .chain(with_literal(cubes_1, true))
+        .chain(cubes_d)
+        .collect::<Vec<_>>();
+
+    let result = (cubes, cover);
+    cache.insert(key, result.clone());
+    result
+}
+
+/// The variable of the root node, or the number of variables for a constant.
+fn top_variable(bdd: &InnerBdd) -> BddVariable {
+    bdd.var_of(bdd.root_pointer())
+}
+
+/// Creates the literal `var` over the same number of variables as `template`.
+fn mk_var_like(template: &InnerBdd, var: BddVariable) -> InnerBdd {
+    BddVariableSet::new_anonymous(template.num_vars()).mk_var(var)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{bool, var};
+    use crate::traits::{BooleanFunction, Evaluate};
+    use std::str::FromStr;
+
+    fn bdd(expression: &str) -> Bdd<String> {
+        Bdd::try_from(Expression::from_str(expression).unwrap()).unwrap()
+    }
+
+    /// Checks that no cube and no literal of the cover can be removed.
+    fn assert_irredundant(function: &Bdd<String>, cubes: &[BooleanValuation<String>]) {
+        let cover = |cubes: &[BooleanValuation<String>]| {
+            Bdd::try_from(cubes_to_expression(cubes.to_vec())).unwrap()
+        };
+
+        for index in 0..cubes.len() {
+            let mut without_cube = cubes.to_vec();
+            without_cube.remove(index);
+            assert!(!cover(&without_cube).is_equivalent(function));
+
+            for var in cubes[index].keys() {
+                let mut without_literal = cubes.to_vec();
+                without_literal[index].remove(var);
+                assert!(!cover(&without_literal).is_equivalent(function));
+            }
+        }
+    }
+
+    #[test]
+    fn test_isop_cubes_ok() {
+        let input = bdd("(a & b) | (!a & c) | (b & c)");
+
+        let actual = input.isop_cubes(None);
+
+        assert_eq!(actual.len(), 2);
+        assert!(input.is_equivalent(&Bdd::try_from(cubes_to_expression(actual.clone())).unwrap()));
+        assert_irredundant(&input, &actual);
+    }
+
+    #[test]
+    fn test_isop_constants_ok() {
+        assert!(bdd("a & !a").isop_cubes(None).is_empty());
+        assert_eq!(
+            bdd("a | !a").isop_cubes(None),
+            vec![BooleanValuation::new()]
+        );
+        assert_eq!(bdd("a | !a").to_isop_expression(None), bool(true));
+        assert_eq!(bdd("a & !a").to_isop_expression(None), bool(false));
+    }
+
+    #[test]
+    fn test_isop_irredundant_ok() {
+        let inputs = [
+            "(a & !b) | (!a & b) | (c & d)",
+            "(a | b | c) & (!a | !b | !c)",
+            "(a & !b & c) | (!a & b & !c) | (a & b & d) | (!c & !d)",
+        ];
+
+        for input in inputs {
+            let function = bdd(input);
+            let cubes = function.isop_cubes(None);
+
+            let cover = Bdd::try_from(cubes_to_expression(cubes.clone())).unwrap();
+            assert!(cover.is_equivalent(&function), "{input}");
+            assert_irredundant(&function, &cubes);
+        }
+    }
+
+    #[test]
+    fn test_isop_with_care_set_ok() {
+        // Only the points where `c` holds matter, hence `a` is enough.
+        let input = bdd("a & (b | !c)");
+        let care_set = bdd("c & b");
+
+        let actual = input.to_isop_expression(Some(&care_set));
+
+        assert_eq!(
+            actual,
+            Expression::n_ary_or(&[Expression::n_ary_and(&[var("a")])])
+        );
+        for point in care_set.support() {
+            let valuation = ["b", "c"].map(String::from).into_iter().zip(point);
+            let mut valuation = valuation.collect::<BooleanValuation<_>>();
+            for value in [false, true] {
+                valuation.insert("a".to_string(), value);
+                assert_eq!(actual.evaluate(&valuation), input.evaluate(&valuation));
+            }
+        }
+    }
+}
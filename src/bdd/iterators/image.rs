@@ -5,13 +5,16 @@ use biodivine_lib_bdd::BddValuation;
 pub struct ImageIterator {
     domain_iterator: DomainIterator,
     bdd: Bdd,
+    /// See `Bdd::sorted_positions`.
+    sorted_positions: Option<Vec<usize>>,
 }
 
 impl ImageIterator {
-    pub(crate) fn new(input_count: usize, bdd: &Bdd) -> Self {
+    pub(crate) fn new(input_count: usize, bdd: &Bdd, sorted_positions: Option<Vec<usize>>) -> Self {
         Self {
             domain_iterator: DomainIterator::from_count(input_count),
             bdd: bdd.clone(),
+            sorted_positions,
         }
     }
 }
@@ -20,9 +23,13 @@ impl Iterator for ImageIterator {
     type Item = bool;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.domain_iterator
-            .next()
-            .map(|it| self.bdd.eval_in(&BddValuation::new(it)))
+        self.domain_iterator.next().map(|point| {
+            let point = match &self.sorted_positions {
+                None => point,
+                Some(positions) => positions.iter().map(|index| point[*index]).collect(),
+            };
+            self.bdd.eval_in(&BddValuation::new(point))
+        })
     }
 }
 
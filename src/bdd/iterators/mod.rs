@@ -2,4 +2,4 @@ pub use image::ImageIterator;
 pub use support::SupportIterator;
 
 mod image;
-mod support;
+pub(crate) mod support;
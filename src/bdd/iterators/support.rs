@@ -2,12 +2,15 @@ use biodivine_lib_bdd::{Bdd, OwnedBddSatisfyingValuations};
 
 pub struct SupportIterator {
     iterator: OwnedBddSatisfyingValuations,
+    /// See `Bdd::sorted_positions`.
+    sorted_positions: Option<Vec<usize>>,
 }
 
 impl SupportIterator {
-    pub(crate) fn new(bdd: &Bdd) -> Self {
+    pub(crate) fn new(bdd: &Bdd, sorted_positions: Option<Vec<usize>>) -> Self {
         Self {
             iterator: bdd.clone().into_sat_valuations(),
+            sorted_positions,
         }
     }
 }
@@ -16,10 +19,22 @@ impl Iterator for SupportIterator {
     type Item = Vec<bool>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iterator.next().map(|it| it.vector())
+        self.iterator.next().map(|it| match &self.sorted_positions {
+            None => it.into_vector(),
+            Some(positions) => unpermute_point(&it.into_vector(), positions),
+        })
     }
 }
 
+/// Converts a point in the variable order of a BDD into a point in the sorted order.
+pub(crate) fn unpermute_point(point: &[bool], sorted_positions: &[usize]) -> Vec<bool> {
+    let mut result = vec![false; point.len()];
+    for (inner_index, value) in point.iter().enumerate() {
+        result[sorted_positions[inner_index]] = *value;
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bdd::Bdd;
@@ -4,10 +4,22 @@ use std::num::TryFromIntError;
 
 use biodivine_lib_bdd::{Bdd as InnerBdd, BddVariable, BddVariableSet};
 
-use crate::bdd::utils::{extend_bdd_variables, prune_bdd_variables};
+use crate::bdd::utils::{
+    extend_bdd_variables, is_subsequence, merge_variable_orders, prune_bdd_variables,
+    reorder_bdd_variables,
+};
 
+pub mod decomposition;
+pub mod factored_form;
+pub mod interpolation;
+mod isop;
 pub mod iterators;
+pub mod ordering;
+pub mod relation;
+pub mod serialization;
+pub mod substitution;
 mod traits;
+pub mod universe;
 mod utils;
 
 /*
@@ -27,17 +39,40 @@ mod utils;
 
 */
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+/// Two `Bdd` objects are equal if they represent the same function over the same inputs,
+/// regardless of their variable order. Comparing two `Bdd` objects with different variable
+/// orders requires rebuilding one of them in the order of the other.
+#[derive(Debug, Clone)]
 pub struct Bdd<TLiteral>
 where
     TLiteral: Debug + Clone + Eq + Ord,
 {
-    /// Always-sorted vector of no more than 65k variables (see `lib-bdd`).
+    /// Vector of no more than 65k unique variables (see `lib-bdd`), ordered by the variable
+    /// order of the BDD, i.e. `inputs[i]` is the variable of `BddVariable` with index `i`.
+    /// Unless an explicit order is requested (see the `ordering` module), it is sorted.
     inputs: Vec<TLiteral>,
+    /// The `BddVariable` of every input, sorted by the inputs, or `None` if the `inputs`
+    /// are already sorted. Allows finding the `BddVariable` of an input by binary search.
+    sorted_index: Option<Vec<BddVariable>>,
     /// Holds the `lib_bdd` representation.
     bdd: InnerBdd,
 }
 
+impl<TLiteral: Debug + Clone + Eq + Ord> PartialEq for Bdd<TLiteral> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.inputs == other.inputs {
+            return self.bdd == other.bdd;
+        }
+        if self.inputs.len() != other.inputs.len() || self.sorted_inputs().ne(other.sorted_inputs())
+        {
+            return false;
+        }
+        reorder_bdd_variables(other, &self.inputs).bdd == self.bdd
+    }
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord> Eq for Bdd<TLiteral> {}
+
 impl<TLiteral: Debug + Clone + Eq + Ord + Display> Bdd<TLiteral> {
     /// Creates the String-based `BddVariableSet` from `self` that `lib-bdd` operates with to map BDD node indexes to variables with names.
     pub fn make_variable_set(&self) -> BddVariableSet {
@@ -69,7 +104,20 @@ impl<TLiteral: Debug + Clone + Eq + Ord> Bdd<TLiteral> {
 
 impl<TLiteral: Debug + Clone + Eq + Ord> Bdd<TLiteral> {
     pub(crate) fn new(inner: InnerBdd, inputs: Vec<TLiteral>) -> Self {
-        Self { bdd: inner, inputs }
+        let sorted_index = if inputs.windows(2).all(|pair| pair[0] < pair[1]) {
+            None
+        } else {
+            let mut sorted = (0..inputs.len())
+                .map(BddVariable::from_index)
+                .collect::<Vec<_>>();
+            sorted.sort_by(|x, y| inputs[x.to_index()].cmp(&inputs[y.to_index()]));
+            Some(sorted)
+        };
+        Self {
+            bdd: inner,
+            inputs,
+            sorted_index,
+        }
     }
 
     /// Converts a literal representation as a generic user struct
@@ -77,10 +125,39 @@ impl<TLiteral: Debug + Clone + Eq + Ord> Bdd<TLiteral> {
     ///
     /// If such a variable isn't used in this `Bdd`, the method returns `None`.
     fn map_var_outer_to_inner(&self, variable: &TLiteral) -> Option<BddVariable> {
-        self.inputs
-            .binary_search(variable)
-            .ok()
-            .map(BddVariable::from_index)
+        match &self.sorted_index {
+            None => self
+                .inputs
+                .binary_search(variable)
+                .ok()
+                .map(BddVariable::from_index),
+            Some(sorted_index) => sorted_index
+                .binary_search_by(|it| self.inputs[it.to_index()].cmp(variable))
+                .ok()
+                .map(|position| sorted_index[position]),
+        }
+    }
+
+    /// The inputs of this `Bdd` in sorted order.
+    fn sorted_inputs(&self) -> impl Iterator<Item = &TLiteral> + '_ {
+        (0..self.inputs.len()).map(|position| match &self.sorted_index {
+            None => &self.inputs[position],
+            Some(sorted_index) => &self.inputs[sorted_index[position].to_index()],
+        })
+    }
+
+    /// For each `BddVariable` index, the position of its variable in the sorted `inputs`,
+    /// or `None` if the `inputs` are already sorted.
+    ///
+    /// Points returned to (or given by) the user always follow the sorted order of inputs,
+    /// while `lib_bdd` valuations follow the variable order of the BDD.
+    fn sorted_positions(&self) -> Option<Vec<usize>> {
+        let sorted_index = self.sorted_index.as_ref()?;
+        let mut positions = vec![0; sorted_index.len()];
+        for (sorted_position, variable) in sorted_index.iter().enumerate() {
+            positions[variable.to_index()] = sorted_position;
+        }
+        Some(positions)
     }
 
     /// Converts a `BddVariable` used by `lib_bdd::Bdd` into a literal representation
@@ -106,6 +183,21 @@ impl<TLiteral: Debug + Clone + Eq + Ord> Bdd<TLiteral> {
         &self.bdd
     }
 
+    /// Replaces every input `v` of this `Bdd` with `mapping(v)`, keeping the variable order.
+    ///
+    /// The `mapping` must be injective on the inputs of this `Bdd`.
+    pub(crate) fn map_inputs<U: Debug + Clone + Ord, F: Fn(&TLiteral) -> U>(
+        &self,
+        mapping: F,
+    ) -> Bdd<U> {
+        let inputs = self.inputs.iter().map(mapping).collect::<Vec<_>>();
+        debug_assert_eq!(
+            inputs.iter().collect::<BTreeSet<_>>().len(),
+            self.inputs.len()
+        );
+        Bdd::new(self.bdd.clone(), inputs)
+    }
+
     fn restrict_and_prune_map<TValue>(
         &self,
         valuation: &BTreeMap<TLiteral, TValue>,
@@ -141,41 +233,27 @@ impl<TLiteral: Debug + Clone + Eq + Ord> Bdd<TLiteral> {
         &self,
         other: &Bdd<TLiteral>,
     ) -> (Bdd<TLiteral>, Bdd<TLiteral>, Vec<TLiteral>) {
-        let mut common_inputs = self.inputs.clone();
-        for other in &other.inputs {
-            if !common_inputs.contains(other) {
-                common_inputs.push(other.clone());
-            }
-        }
-        common_inputs.sort();
+        let common_inputs = merge_variable_orders(&self.inputs, &other.inputs);
 
-        let self_lifted = extend_bdd_variables(self, &common_inputs);
-        let other_lifted = extend_bdd_variables(other, &common_inputs);
+        let self_lifted = self.lift_to(&common_inputs);
+        let other_lifted = other.lift_to(&common_inputs);
 
         (self_lifted, other_lifted, common_inputs)
     }
 
-    fn union_and_extend_n_ary(
-        &self,
-        others: &mut BTreeMap<TLiteral, Bdd<TLiteral>>,
-    ) -> (Bdd<TLiteral>, Vec<TLiteral>) {
-        let mut common_inputs = self.inputs.clone();
-
-        for other in others.values() {
-            for input in &other.inputs {
-                if !common_inputs.contains(input) {
-                    common_inputs.push(input.clone());
-                }
-            }
-        }
-
-        common_inputs.sort();
-
-        let self_lifted = extend_bdd_variables(self, &common_inputs);
-        for other in others.values_mut() {
-            *other = extend_bdd_variables(other, &common_inputs);
+    /// Extends this `Bdd` to the variables of `order` (a superset of `self.inputs`), first
+    /// reordering it if its variable order does not agree with `order`.
+    fn lift_to(&self, order: &[TLiteral]) -> Bdd<TLiteral> {
+        if is_subsequence(&self.inputs, order) {
+            return extend_bdd_variables(self, order);
         }
 
-        (self_lifted, common_inputs)
+        let own_inputs = self.inputs.iter().collect::<BTreeSet<_>>();
+        let own_order = order
+            .iter()
+            .filter(|it| own_inputs.contains(it))
+            .cloned()
+            .collect::<Vec<_>>();
+        extend_bdd_variables(&reorder_bdd_variables(self, &own_order), order)
     }
 }
@@ -0,0 +1,23 @@
+use std::num::TryFromIntError;
+
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BddOrderingError {
+    #[error("Variable {name} appears multiple times in the variable order")]
+    DuplicateVariable { name: String },
+    #[error("Variable {name} is missing in the variable order")]
+    MissingVariable { name: String },
+    #[error("Variable {name} in the variable order is not an input of the function")]
+    UnknownVariable { name: String },
+    #[error("A variable order can contain at most {} variables", u16::MAX)]
+    TooManyVariables(#[from] TryFromIntError),
+}
+
+#[cfg(feature = "python")]
+impl From<BddOrderingError> for PyErr {
+    fn from(err: BddOrderingError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use crate::expressions::{Expression, ExpressionNode};
+use crate::traits::GatherLiterals;
+
+/// The maximal number of improvement rounds of [OrderingHeuristic::Force].
+const FORCE_MAX_ITERATIONS: usize = 32;
+
+/// Static variable ordering heuristics for building a `Bdd` from an `Expression`.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum OrderingHeuristic {
+    /// Variables are ordered by their `Ord` implementation.
+    #[default]
+    Sorted,
+    /// Variables are ordered by their first occurrence in a depth-first, left-to-right
+    /// traversal of the expression. Variables which appear close to each other in the
+    /// expression thus stay close in the order.
+    DepthFirst,
+    /// The FORCE heuristic (Aloul, Markov and Sakallah, 2003). Each operator of the expression
+    /// connects the variables it depends on and the variables are repeatedly moved to the
+    /// average "center of gravity" of their connections, starting from the
+    /// [OrderingHeuristic::DepthFirst] order.
+    Force,
+}
+
+/// Computes the variable order of the literals in `expression` using the given `heuristic`.
+pub fn static_variable_order<T: Debug + Clone + Ord>(
+    expression: &Expression<T>,
+    heuristic: OrderingHeuristic,
+) -> Vec<T> {
+    match heuristic {
+        OrderingHeuristic::Sorted => expression.gather_literals().into_iter().collect(),
+        OrderingHeuristic::DepthFirst => depth_first_order(expression),
+        OrderingHeuristic::Force => force_order(expression),
+    }
+}
+
+fn depth_first_order<T: Debug + Clone + Ord>(expression: &Expression<T>) -> Vec<T> {
+    fn rec<T: Debug + Clone + Ord>(
+        expression: &Expression<T>,
+        seen: &mut BTreeSet<T>,
+        order: &mut Vec<T>,
+    ) {
+        match expression.node() {
+            ExpressionNode::Literal(name) => {
+                if seen.insert(name.clone()) {
+                    order.push(name.clone());
+                }
+            }
+            ExpressionNode::Constant(_) => (),
+            ExpressionNode::Not(inner) => rec(inner, seen, order),
+            ExpressionNode::And(es) | ExpressionNode::Or(es) => {
+                es.iter().for_each(|e| rec(e, seen, order))
+            }
+        }
+    }
+
+    let mut order = vec![];
+    rec(expression, &mut BTreeSet::new(), &mut order);
+    order
+}
+
+fn force_order<T: Debug + Clone + Ord>(expression: &Expression<T>) -> Vec<T> {
+    let initial_order = depth_first_order(expression);
+    let indices = initial_order
+        .iter()
+        .enumerate()
+        .map(|(index, var)| (var.clone(), index))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut edges = vec![];
+    collect_edges(expression, &indices, &mut edges);
+
+    // `position[v]` is the position of the `v`-th variable of the initial order.
+    let mut position = (0..initial_order.len()).collect::<Vec<_>>();
+    for _ in 0..FORCE_MAX_ITERATIONS {
+        let mut force_sum = vec![0.0; position.len()];
+        let mut force_count = vec![0usize; position.len()];
+        for edge in &edges {
+            let center = edge.iter().map(|v| position[*v] as f64).sum::<f64>() / edge.len() as f64;
+            for v in edge {
+                force_sum[*v] += center;
+                force_count[*v] += 1;
+            }
+        }
+
+        let target = (0..position.len())
+            .map(|v| match force_count[v] {
+                0 => position[v] as f64,
+                count => force_sum[v] / count as f64,
+            })
+            .collect::<Vec<_>>();
+
+        let mut sorted = (0..position.len()).collect::<Vec<_>>();
+        sorted.sort_by(|x, y| {
+            target[*x]
+                .total_cmp(&target[*y])
+                .then(position[*x].cmp(&position[*y]))
+        });
+        let mut next_position = vec![0; position.len()];
+        for (new_position, v) in sorted.into_iter().enumerate() {
+            next_position[v] = new_position;
+        }
+
+        if next_position == position {
+            break;
+        }
+        position = next_position;
+    }
+
+    let mut order = initial_order.into_iter().zip(position).collect::<Vec<_>>();
+    order.sort_by_key(|(_var, position)| *position);
+    order.into_iter().map(|(var, _position)| var).collect()
+}
+
+/// Collects the sets of variables (indices) that each operator depends on, returning
+/// the variables of the whole `expression`.
+fn collect_edges<T: Debug + Clone + Ord>(
+    expression: &Expression<T>,
+    indices: &BTreeMap<T, usize>,
+    edges: &mut Vec<BTreeSet<usize>>,
+) -> BTreeSet<usize> {
+    let variables = match expression.node() {
+        ExpressionNode::Literal(name) => return BTreeSet::from([indices[name]]),
+        ExpressionNode::Constant(_) => return BTreeSet::new(),
+        ExpressionNode::Not(inner) => return collect_edges(inner, indices, edges),
+        ExpressionNode::And(es) | ExpressionNode::Or(es) => es
+            .iter()
+            .flat_map(|e| collect_edges(e, indices, edges))
+            .collect::<BTreeSet<_>>(),
+    };
+
+    if variables.len() > 1 {
+        edges.push(variables.clone());
+    }
+    variables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_depth_first_order_ok() {
+        let input = (var("c") & !var("a")) | (var("b") ^ var("c")) | var("d");
+
+        assert_eq!(
+            static_variable_order(&input, OrderingHeuristic::DepthFirst),
+            names(&["c", "a", "b", "d"])
+        );
+        assert_eq!(
+            static_variable_order(&input, OrderingHeuristic::Sorted),
+            names(&["a", "b", "c", "d"])
+        );
+    }
+
+    #[test]
+    fn test_force_order_ok() {
+        // The variables form a chain `y - x - b - a`, but the depth-first order is `x, y, a, b`.
+        let input = Expression::n_ary_and(&[
+            var("x") | var("y"),
+            var("a") | var("b"),
+            var("b") | var("x"),
+        ]);
+        let total_span = |order: &[String]| {
+            let position = |name: &str| order.iter().position(|it| it == name).unwrap();
+            [("x", "y"), ("a", "b"), ("b", "x")]
+                .iter()
+                .map(|(x, y)| position(x).abs_diff(position(y)))
+                .sum::<usize>()
+        };
+
+        let depth_first = static_variable_order(&input, OrderingHeuristic::DepthFirst);
+        let actual = static_variable_order(&input, OrderingHeuristic::Force);
+
+        assert_eq!(depth_first, names(&["x", "y", "a", "b"]));
+        assert_eq!(actual, names(&["y", "x", "b", "a"]));
+        assert!(total_span(&actual) < total_span(&depth_first));
+    }
+}
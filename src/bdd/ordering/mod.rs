@@ -0,0 +1,278 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use biodivine_lib_bdd::{BddPartialValuation, BddVariable, BddVariableSet};
+
+use crate::bdd::traits::from_expression::try_from_rec;
+use crate::bdd::utils::reorder_bdd_variables;
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::table::TruthTable;
+use crate::traits::{BooleanFunction, GatherLiterals};
+
+pub use error::BddOrderingError;
+pub use heuristics::{static_variable_order, OrderingHeuristic};
+
+mod error;
+mod heuristics;
+mod sifting;
+
+/*
+   The variable order of a `Bdd` is the order of its `inputs`. By default, the inputs are
+   sorted. A different order only changes the shape (and size) of the diagram, not the
+   function it represents: `inputs()`, `evaluate`, the iterators, `sat_point` etc. all still
+   use the sorted order of variables. Operations on two `Bdd` objects with different orders
+   are allowed, the result follows the order of the left operand (see `merge_variable_orders`).
+*/
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// The current variable order of this `Bdd`, starting with the variable at the root.
+    pub fn variable_order(&self) -> &[T] {
+        &self.inputs
+    }
+
+    /// Builds a `Bdd` from `expression` using the variable `order`.
+    ///
+    /// The `order` must contain every variable of `expression` exactly once; additional
+    /// variables are not allowed.
+    pub fn from_expression_with_order(
+        expression: &Expression<T>,
+        order: &[T],
+    ) -> Result<Bdd<T>, BddOrderingError> {
+        check_order(&expression.gather_literals(), order)?;
+
+        let mapping = order
+            .iter()
+            .enumerate()
+            .map(|(index, var)| (var.clone(), index))
+            .collect::<BTreeMap<_, _>>();
+        let literal_set = BddVariableSet::new_anonymous(u16::try_from(order.len())?);
+
+        Ok(Bdd::new(
+            try_from_rec(expression, &literal_set, &mapping),
+            order.to_vec(),
+        ))
+    }
+
+    /// Builds a `Bdd` from `expression` using the variable order computed by the
+    /// static ordering `heuristic`.
+    pub fn from_expression_with_heuristic(
+        expression: &Expression<T>,
+        heuristic: OrderingHeuristic,
+    ) -> Result<Bdd<T>, BddOrderingError> {
+        let order = static_variable_order(expression, heuristic);
+        Self::from_expression_with_order(expression, &order)
+    }
+
+    /// Builds a `Bdd` from `table` using the variable `order`.
+    ///
+    /// The `order` must contain every input of `table` exactly once.
+    pub fn from_table_with_order(
+        table: &TruthTable<T>,
+        order: &[T],
+    ) -> Result<Bdd<T>, BddOrderingError> {
+        let inputs = table.inputs();
+        check_order(&inputs, order)?;
+        let literal_set = BddVariableSet::new_anonymous(u16::try_from(order.len())?);
+
+        // The points of the table follow the sorted order of its inputs.
+        let positions = inputs
+            .iter()
+            .map(|var| order.iter().position(|it| it == var).unwrap())
+            .collect::<Vec<_>>();
+        let valuations = table
+            .support()
+            .map(|point| {
+                let values = point
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, value)| (BddVariable::from_index(positions[index]), value))
+                    .collect::<Vec<_>>();
+                BddPartialValuation::from_values(&values)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Bdd::new(literal_set.mk_dnf(&valuations), order.to_vec()))
+    }
+
+    /// Creates an equivalent `Bdd` with the variable `order`.
+    ///
+    /// The `order` must contain every input of `self` exactly once.
+    pub fn reorder(&self, order: &[T]) -> Result<Bdd<T>, BddOrderingError> {
+        check_order(&self.inputs(), order)?;
+        Ok(reorder_bdd_variables(self, order))
+    }
+}
+
+/// Checks that `order` is a permutation of `variables`.
+fn check_order<T: Debug + Clone + Ord>(
+    variables: &BTreeSet<T>,
+    order: &[T],
+) -> Result<(), BddOrderingError> {
+    let mut seen = BTreeSet::new();
+    for var in order {
+        if !variables.contains(var) {
+            return Err(BddOrderingError::UnknownVariable {
+                name: format!("{var:?}"),
+            });
+        }
+        if !seen.insert(var) {
+            return Err(BddOrderingError::DuplicateVariable {
+                name: format!("{var:?}"),
+            });
+        }
+    }
+
+    match variables.iter().find(|var| !seen.contains(var)) {
+        Some(var) => Err(BddOrderingError::MissingVariable {
+            name: format!("{var:?}"),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::traits::Evaluate;
+    use std::str::FromStr;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_expression_with_order_same_function_ok() {
+        let input = Expression::from_str("(a & !b) | (c & !d) | (!c & d) | (a & d)").unwrap();
+        let sorted = Bdd::try_from(input.clone()).unwrap();
+
+        let actual = Bdd::from_expression_with_order(&input, &names(&["d", "b", "a", "c"]))
+            .expect("Should not panic here");
+
+        assert_eq!(actual.variable_order(), names(&["d", "b", "a", "c"]));
+        assert_eq!(actual.inputs(), sorted.inputs());
+        assert!(actual.is_equivalent(&sorted));
+        assert_eq!(
+            actual.relation().collect::<Vec<_>>(),
+            sorted.relation().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            actual.support().collect::<BTreeSet<_>>(),
+            sorted.support().collect::<BTreeSet<_>>()
+        );
+        let sat_point = actual.sat_point().expect("Function should be satisfiable");
+        assert!(sorted.support().any(|point| point == sat_point));
+        assert_eq!(TruthTable::from(actual), TruthTable::from(sorted));
+    }
+
+    #[test]
+    fn test_eq_ignores_variable_order() {
+        let input = Expression::from_str("(a | b) & (c | !d)").unwrap();
+        let sorted = Bdd::try_from(input.clone()).unwrap();
+        let order = names(&["c", "a", "d", "b"]);
+
+        let actual = Bdd::from_expression_with_order(&input, &order).unwrap();
+        assert_eq!(actual, sorted);
+        assert_eq!(sorted, actual);
+
+        let other = Bdd::from_expression_with_order(&(var("a") | var("b")), &names(&["b", "a"]));
+        assert_ne!(actual, other.unwrap());
+        let other = Expression::from_str("(a | b) & (c | d)").unwrap();
+        let other = Bdd::from_expression_with_order(&other, &order).unwrap();
+        assert_ne!(actual, other);
+    }
+
+    #[test]
+    fn test_from_expression_with_order_nok() {
+        let input = var("a") & var("b");
+
+        assert_eq!(
+            Bdd::from_expression_with_order(&input, &names(&["a"])),
+            Err(BddOrderingError::MissingVariable {
+                name: "\"b\"".to_string()
+            })
+        );
+        assert_eq!(
+            Bdd::from_expression_with_order(&input, &names(&["a", "b", "c"])),
+            Err(BddOrderingError::UnknownVariable {
+                name: "\"c\"".to_string()
+            })
+        );
+        assert_eq!(
+            Bdd::from_expression_with_order(&input, &names(&["b", "a", "b"])),
+            Err(BddOrderingError::DuplicateVariable {
+                name: "\"b\"".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_table_with_order_ok() {
+        let input = Expression::from_str("(a & !b) | (c & a) | !c").unwrap();
+        let table = TruthTable::from(input.clone());
+        let order = names(&["c", "a", "b"]);
+
+        let actual = Bdd::from_table_with_order(&table, &order).expect("Should not panic here");
+        let expected = Bdd::from_expression_with_order(&input, &order).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_reorder_ok() {
+        let input = Bdd::try_from(Expression::from_str("(a | b) & (c | !d)").unwrap()).unwrap();
+
+        let actual = input
+            .reorder(&names(&["c", "a", "d", "b"]))
+            .expect("Should not panic here");
+
+        assert_eq!(actual.variable_order(), names(&["c", "a", "d", "b"]));
+        assert!(actual.is_equivalent(&input));
+        let back = actual.reorder(&names(&["a", "b", "c", "d"])).unwrap();
+        assert_eq!(back, input);
+        assert!(input.reorder(&names(&["a", "b", "c"])).is_err());
+    }
+
+    #[test]
+    fn test_operations_with_different_orders_ok() {
+        let lhs =
+            Bdd::from_expression_with_order(&(var("a") | !var("c")), &names(&["c", "a"])).unwrap();
+        let rhs =
+            Bdd::from_expression_with_order(&(var("b") & var("a")), &names(&["b", "a"])).unwrap();
+        let expected = Bdd::try_from((var("a") | !var("c")) ^ (var("b") & var("a"))).unwrap();
+
+        let actual = &lhs ^ &rhs;
+
+        let position = |name: &str| actual.variable_order().iter().position(|it| it == name);
+        assert!(position("c") < position("a"));
+        assert!(actual.is_equivalent(&expected));
+        for point in actual.domain() {
+            let valuation = names(&["a", "b", "c"])
+                .into_iter()
+                .zip(point)
+                .collect::<BTreeMap<_, _>>();
+            assert_eq!(actual.evaluate(&valuation), expected.evaluate(&valuation));
+        }
+        let restricted = actual.restrict(&BTreeMap::from([("c".to_string(), true)]));
+        assert!(restricted
+            .is_equivalent(&expected.restrict(&BTreeMap::from([("c".to_string(), true)]))));
+    }
+
+    #[test]
+    fn test_from_expression_with_heuristic_ok() {
+        let input = Expression::from_str("(b & z) | (a & y) | (c & x)").unwrap();
+
+        let actual = Bdd::from_expression_with_heuristic(&input, OrderingHeuristic::DepthFirst)
+            .expect("Should not panic here");
+        let sorted = Bdd::from_expression_with_heuristic(&input, OrderingHeuristic::Sorted)
+            .expect("Should not panic here");
+
+        assert_eq!(
+            actual.variable_order(),
+            names(&["b", "z", "a", "y", "c", "x"])
+        );
+        assert!(actual.is_equivalent(&sorted));
+        assert!(actual.node_count() < sorted.node_count());
+    }
+}
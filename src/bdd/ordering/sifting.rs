@@ -0,0 +1,345 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddNode, BddPointer, BddVariable, BddVariableSet};
+
+use crate::bdd::Bdd;
+
+/// Sifting stops moving a variable in one direction once the diagram grows above
+/// this multiple of the smallest size seen so far.
+const SIFTING_MAX_GROWTH: usize = 2;
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// Improves the variable order of this `Bdd` using Rudell's sifting.
+    ///
+    /// Variables are processed from the one with the most nodes. Each variable is moved
+    /// through all levels of the order by swapping it with its neighbours (first down, then
+    /// up, while the diagram does not grow too much) and then moved back to the level where
+    /// the diagram is the smallest. The result is never larger than `self`.
+    ///
+    /// A swap of two adjacent levels only rebuilds the nodes of these two levels. The diagram
+    /// is converted into a separate node table for the swaps and back into a `Bdd` at the end.
+    pub fn sift(&self) -> Bdd<T> {
+        if self.inputs.len() < 2 {
+            return self.clone();
+        }
+
+        let mut table = SiftingTable::new(&self.bdd);
+        let mut variables = (0..self.inputs.len()).collect::<Vec<_>>();
+        variables.sort_by_key(|level| std::cmp::Reverse(table.unique[*level].len()));
+
+        for variable in variables {
+            table.sift_variable(variable);
+        }
+
+        let inputs = table
+            .variables
+            .iter()
+            .map(|variable| self.inputs[*variable].clone())
+            .collect();
+        Bdd::new(table.to_inner(), inputs)
+    }
+}
+
+/// A reference-counted node table with one unique table per level, which supports
+/// swapping adjacent levels in place.
+///
+/// Nodes `0` and `1` are the terminals, their level is the number of variables.
+struct SiftingTable {
+    nodes: Vec<SiftingNode>,
+    ref_counts: Vec<usize>,
+    free: Vec<usize>,
+    /// For every level, maps the children of a node to its index.
+    unique: Vec<HashMap<(usize, usize), usize>>,
+    /// For every level, the index of its variable in the original `Bdd`.
+    variables: Vec<usize>,
+    root: usize,
+}
+
+#[derive(Clone, Copy)]
+struct SiftingNode {
+    level: usize,
+    low: usize,
+    high: usize,
+}
+
+impl SiftingTable {
+    fn new(bdd: &InnerBdd) -> SiftingTable {
+        let variable_count = usize::from(bdd.num_vars());
+        let terminal = SiftingNode {
+            level: variable_count,
+            low: 0,
+            high: 0,
+        };
+        let mut table = SiftingTable {
+            nodes: vec![terminal; 2],
+            ref_counts: vec![1; 2],
+            free: Vec::new(),
+            unique: vec![HashMap::new(); variable_count],
+            variables: (0..variable_count).collect(),
+            root: 0,
+        };
+
+        // `lib_bdd` stores nodes in DFS post-order, so the children are always created first.
+        let mut pointers = vec![0, 1];
+        for index in 2..bdd.size() {
+            let pointer = BddPointer::from_index(index);
+            let node = table.mk_node(
+                bdd.var_of(pointer).to_index(),
+                pointers[bdd.low_link_of(pointer).to_index()],
+                pointers[bdd.high_link_of(pointer).to_index()],
+            );
+            pointers.push(node);
+        }
+        table.root = pointers[bdd.root_pointer().to_index()];
+        table.ref_counts[table.root] += 1;
+        table
+    }
+
+    fn size(&self) -> usize {
+        self.unique.iter().map(HashMap::len).sum()
+    }
+
+    /// Moves the variable through all levels and leaves it at the level where
+    /// the table is the smallest.
+    fn sift_variable(&mut self, variable: usize) {
+        let start = self
+            .variables
+            .iter()
+            .position(|it| *it == variable)
+            .unwrap();
+        let (mut level, mut best_size, mut best_level) = (start, self.size(), start);
+
+        while level + 1 < self.variables.len() {
+            self.swap(level);
+            level += 1;
+            let size = self.size();
+            if size < best_size {
+                (best_size, best_level) = (size, level);
+            } else if size > SIFTING_MAX_GROWTH * best_size {
+                break;
+            }
+        }
+        while level > 0 {
+            self.swap(level - 1);
+            level -= 1;
+            let size = self.size();
+            if size < best_size {
+                (best_size, best_level) = (size, level);
+            } else if size > SIFTING_MAX_GROWTH * best_size && level < start {
+                break;
+            }
+        }
+        while level < best_level {
+            self.swap(level);
+            level += 1;
+        }
+        while level > best_level {
+            self.swap(level - 1);
+            level -= 1;
+        }
+    }
+
+    /// Swaps the variables of `level` and `level + 1`.
+    ///
+    /// The nodes of `level` keep their indices, so the references from the upper levels
+    /// stay valid: a node which does not depend on the lower variable only moves one level
+    /// down, every other node is rebuilt as a node of the lower variable with new children.
+    fn swap(&mut self, level: usize) {
+        let upper = std::mem::take(&mut self.unique[level]);
+        let lower = std::mem::take(&mut self.unique[level + 1]);
+
+        let cofactors = |child: usize| {
+            let node = self.nodes[child];
+            if node.level == level + 1 {
+                (node.low, node.high)
+            } else {
+                (child, child)
+            }
+        };
+        let (independent, dependent): (Vec<_>, Vec<_>) = upper
+            .into_values()
+            .map(|index| {
+                let node = self.nodes[index];
+                (index, cofactors(node.low), cofactors(node.high))
+            })
+            .partition(|(index, _, _)| {
+                let node = self.nodes[*index];
+                self.nodes[node.low].level != level + 1 && self.nodes[node.high].level != level + 1
+            });
+
+        for (key, index) in lower {
+            self.nodes[index].level = level;
+            self.unique[level].insert(key, index);
+        }
+        for (index, _, _) in independent {
+            let node = &mut self.nodes[index];
+            node.level = level + 1;
+            self.unique[level + 1].insert((node.low, node.high), index);
+        }
+        for (index, (f00, f01), (f10, f11)) in dependent {
+            let low = self.mk_node(level + 1, f00, f10);
+            let high = self.mk_node(level + 1, f01, f11);
+            self.ref_counts[low] += 1;
+            self.ref_counts[high] += 1;
+
+            let old = self.nodes[index];
+            self.nodes[index] = SiftingNode { level, low, high };
+            self.unique[level].insert((low, high), index);
+            self.release(old.low);
+            self.release(old.high);
+        }
+
+        self.variables.swap(level, level + 1);
+    }
+
+    /// Returns the node with the given children, creating it if necessary. The node is
+    /// not referenced by the caller until its reference count is increased.
+    fn mk_node(&mut self, level: usize, low: usize, high: usize) -> usize {
+        if low == high {
+            return low;
+        }
+        if let Some(index) = self.unique[level].get(&(low, high)) {
+            return *index;
+        }
+
+        self.ref_counts[low] += 1;
+        self.ref_counts[high] += 1;
+        let node = SiftingNode { level, low, high };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                self.ref_counts[index] = 0;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.ref_counts.push(0);
+                self.nodes.len() - 1
+            }
+        };
+        self.unique[level].insert((low, high), index);
+        index
+    }
+
+    /// Removes one reference to the node, deleting the nodes which are no longer referenced.
+    fn release(&mut self, index: usize) {
+        let mut stack = vec![index];
+        while let Some(index) = stack.pop() {
+            self.ref_counts[index] -= 1;
+            if index > 1 && self.ref_counts[index] == 0 {
+                let node = self.nodes[index];
+                self.unique[node.level].remove(&(node.low, node.high));
+                self.free.push(index);
+                stack.push(node.low);
+                stack.push(node.high);
+            }
+        }
+    }
+
+    /// Converts the table into a `lib_bdd` diagram whose variable `i` is the variable
+    /// of level `i`.
+    fn to_inner(&self) -> InnerBdd {
+        let variable_count =
+            u16::try_from(self.variables.len()).expect("Bdd variables fit into u16");
+        let variables = BddVariableSet::new_anonymous(variable_count);
+        if self.root <= 1 {
+            return if self.root == 1 {
+                variables.mk_true()
+            } else {
+                variables.mk_false()
+            };
+        }
+
+        // Any order where children precede their parents is accepted by `from_nodes`.
+        let mut nodes = vec![
+            BddNode::mk_zero(variable_count),
+            BddNode::mk_one(variable_count),
+        ];
+        let mut pointers = HashMap::from([(0, 0), (1, 1)]);
+        let mut stack = vec![self.root];
+        while let Some(&index) = stack.last() {
+            let node = self.nodes[index];
+            let children = [node.low, node.high].map(|it| pointers.get(&it).copied());
+            match children {
+                [Some(low), Some(high)] => {
+                    stack.pop();
+                    if let Entry::Vacant(entry) = pointers.entry(index) {
+                        entry.insert(nodes.len());
+                        nodes.push(BddNode::mk_node(
+                            BddVariable::from_index(node.level),
+                            BddPointer::from_index(low),
+                            BddPointer::from_index(high),
+                        ));
+                    }
+                }
+                [low, high] => {
+                    if low.is_none() {
+                        stack.push(node.low);
+                    }
+                    if high.is_none() {
+                        stack.push(node.high);
+                    }
+                }
+            }
+        }
+
+        // The `lib_bdd` operations rebuild the diagram in their canonical node order.
+        let inner = InnerBdd::from_nodes(&nodes).expect("The table is a valid BDD");
+        inner.and(&variables.mk_true())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SiftingTable;
+    use crate::bdd::Bdd;
+    use crate::expressions::Expression;
+    use crate::traits::BooleanFunction;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_sift_interleaves_pairs_ok() {
+        let input = Expression::from_str("(a1 & b1) | (a2 & b2) | (a3 & b3) | (a4 & b4)").unwrap();
+        let sorted = Bdd::try_from(input.clone()).unwrap();
+        let interleaved = Bdd::from_expression_with_order(
+            &input,
+            &["a1", "b1", "a2", "b2", "a3", "b3", "a4", "b4"].map(String::from),
+        )
+        .unwrap();
+
+        let actual = sorted.sift();
+
+        assert!(actual.is_equivalent(&sorted));
+        assert_eq!(actual.inputs(), sorted.inputs());
+        assert_eq!(actual.node_count(), interleaved.node_count());
+        assert!(actual.node_count() < sorted.node_count());
+    }
+
+    #[test]
+    fn test_sift_constant_ok() {
+        let input = Bdd::<String>::mk_const(true);
+
+        assert_eq!(input.sift(), input);
+    }
+
+    #[test]
+    fn test_swap_matches_reorder_ok() {
+        let input = Expression::from_str("(a & !b) | (b & c & !d) | (!a & d) | (c & e)").unwrap();
+        let bdd = Bdd::try_from(input).unwrap();
+        let mut table = SiftingTable::new(&bdd.bdd);
+        let mut order = bdd.inputs.clone();
+
+        // Moves the first variable to the bottom and back to the top.
+        let levels = (0..order.len() - 1).chain((0..order.len() - 1).rev());
+        for level in levels {
+            table.swap(level);
+            order.swap(level, level + 1);
+
+            let expected = bdd.reorder(&order).unwrap();
+            assert_eq!(Bdd::new(table.to_inner(), order.clone()), expected);
+            assert_eq!(table.size() + 2, expected.node_count());
+        }
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TransitionRelationError {
+    #[error("Variable {name} is the primed copy of multiple variables")]
+    DuplicatePrimedVariable { name: String },
+    #[error("Variable {name} is used both as a current and as a primed variable")]
+    OverlappingVariable { name: String },
+}
+
+#[cfg(feature = "python")]
+impl From<TransitionRelationError> for PyErr {
+    fn from(err: TransitionRelationError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
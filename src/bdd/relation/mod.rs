@@ -0,0 +1,258 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use biodivine_lib_bdd::{op_function, Bdd as InnerBdd};
+
+use crate::bdd::Bdd;
+
+pub use error::TransitionRelationError;
+
+mod error;
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// Computes `∃ variables: self & other` without building the (typically much larger)
+    /// conjunction first.
+    ///
+    /// The inputs of the result are the inputs of both operands, except for the quantified
+    /// `variables`. Same as in [crate::traits::BooleanFunction::existential_quantification],
+    /// quantifying a variable that is not an input of either operand has no effect.
+    pub fn relational_product(&self, other: &Bdd<T>, variables: &BTreeSet<T>) -> Bdd<T> {
+        let (self_lifted, other_lifted, common_inputs) = self.union_and_extend(other);
+        let lib_bdd_variables = variables
+            .iter()
+            .filter_map(|it| self_lifted.map_var_outer_to_inner(it))
+            .collect::<Vec<_>>();
+        let product = InnerBdd::binary_op_with_exists(
+            &self_lifted.bdd,
+            &other_lifted.bdd,
+            op_function::and,
+            &lib_bdd_variables,
+        );
+
+        self_lifted.restrict_and_prune_set(variables, &Bdd::new(product, common_inputs))
+    }
+
+    /// Renames the inputs of this `Bdd` according to `mapping`. The variables that are
+    /// renamed to must not be inputs of `self` (unless they are renamed as well).
+    fn rename_variables(&self, mapping: &BTreeMap<T, T>) -> Bdd<T> {
+        // If no renamed variable collides with an existing one and the renaming preserves
+        // the variable order, the BDD itself does not change.
+        let renamed = self
+            .inputs
+            .iter()
+            .map(|it| mapping.get(it).unwrap_or(it))
+            .collect::<Vec<_>>();
+        let is_collision_free = mapping
+            .values()
+            .all(|it| !self.inputs.contains(it) || mapping.contains_key(it));
+        let is_order_preserving = (1..renamed.len())
+            .all(|i| self.inputs[i - 1].cmp(&self.inputs[i]) == renamed[i - 1].cmp(renamed[i]));
+        if is_collision_free && is_order_preserving {
+            return self.map_inputs(|it| mapping.get(it).unwrap_or(it).clone());
+        }
+
+        let mapping = mapping
+            .iter()
+            .map(|(from, to)| (from.clone(), Bdd::mk_literal(to.clone(), true)))
+            .collect::<BTreeMap<_, _>>();
+        // Renaming never increases the number of inputs.
+        self.try_substitute(&mapping)
+            .expect("Renaming should not increase the number of inputs")
+    }
+}
+
+/// A transition relation of a discrete system, encoded as a [Bdd] over the *current*
+/// variables `x` and their *primed* copies `x'`, which hold the values after the transition.
+///
+/// The relation can also depend on variables that are neither current nor primed
+/// (e.g. parameters of the system). These are preserved by [TransitionRelation::post] and
+/// [TransitionRelation::pre], i.e. each parameter valuation is treated as a separate system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionRelation<T: Debug + Clone + Ord> {
+    relation: Bdd<T>,
+    /// Maps every current variable to its primed copy.
+    primed: BTreeMap<T, T>,
+    /// The inverse of `primed`.
+    unprimed: BTreeMap<T, T>,
+}
+
+impl<T: Debug + Clone + Ord> TransitionRelation<T> {
+    /// Creates a transition relation where `primed` maps every current variable
+    /// to its primed copy.
+    ///
+    /// Returns an `Err` if two variables share the same primed copy, or if a primed
+    /// copy is also a current variable.
+    pub fn new(
+        relation: Bdd<T>,
+        primed: BTreeMap<T, T>,
+    ) -> Result<TransitionRelation<T>, TransitionRelationError> {
+        let mut unprimed = BTreeMap::new();
+        for (current, next) in &primed {
+            if primed.contains_key(next) {
+                return Err(TransitionRelationError::OverlappingVariable {
+                    name: format!("{next:?}"),
+                });
+            }
+            if unprimed.insert(next.clone(), current.clone()).is_some() {
+                return Err(TransitionRelationError::DuplicatePrimedVariable {
+                    name: format!("{next:?}"),
+                });
+            }
+        }
+
+        Ok(TransitionRelation {
+            relation,
+            primed,
+            unprimed,
+        })
+    }
+
+    /// The underlying relation over the current and primed variables.
+    pub fn relation(&self) -> &Bdd<T> {
+        &self.relation
+    }
+
+    /// The mapping of every current variable to its primed copy.
+    pub fn primed_variables(&self) -> &BTreeMap<T, T> {
+        &self.primed
+    }
+
+    /// The set of successors of the given `states`, i.e. `∃ x: states(x) & R(x, x')`
+    /// with `x'` renamed back to `x`.
+    ///
+    /// The `states` should only depend on current variables (and parameters).
+    pub fn post(&self, states: &Bdd<T>) -> Bdd<T> {
+        let current = self.primed.keys().cloned().collect::<BTreeSet<_>>();
+        states
+            .relational_product(&self.relation, &current)
+            .rename_variables(&self.unprimed)
+    }
+
+    /// The set of predecessors of the given `states`, i.e. `∃ x': states(x') & R(x, x')`.
+    ///
+    /// The `states` should only depend on current variables (and parameters).
+    pub fn pre(&self, states: &Bdd<T>) -> Bdd<T> {
+        let primed = self.unprimed.keys().cloned().collect::<BTreeSet<_>>();
+        states
+            .rename_variables(&self.primed)
+            .relational_product(&self.relation, &primed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{var, Expression};
+    use crate::traits::BooleanFunction;
+    use std::str::FromStr;
+
+    fn bdd(expression: &str) -> Bdd<String> {
+        Bdd::try_from(Expression::from_str(expression).unwrap()).unwrap()
+    }
+
+    fn iff(left: Expression<String>, right: Expression<String>) -> Expression<String> {
+        !(left ^ right)
+    }
+
+    fn primed(variables: &[&str]) -> BTreeMap<String, String> {
+        variables
+            .iter()
+            .map(|it| (it.to_string(), format!("{it}_p")))
+            .collect()
+    }
+
+    #[test]
+    fn test_relational_product_ok() {
+        let left = bdd("a & b | c");
+        let right = bdd("!b & d");
+        let variables = BTreeSet::from(["b".to_string()]);
+
+        let actual = left.relational_product(&right, &variables);
+        let expected = (&left & &right).existential_quantification(variables);
+
+        assert!(actual.is_equivalent(&expected));
+        assert_eq!(actual.inputs(), expected.inputs());
+        assert!(actual.is_equivalent(&bdd("c & d")));
+    }
+
+    #[test]
+    fn test_relational_product_reordered_ok() {
+        let left = Bdd::try_from(var("a") ^ var("b"))
+            .unwrap()
+            .reorder(&["b", "a"].map(String::from))
+            .unwrap();
+        let right = bdd("a & c");
+        let variables = BTreeSet::from(["a".to_string(), "x".to_string()]);
+
+        let actual = left.relational_product(&right, &variables);
+
+        assert!(actual.is_equivalent(&bdd("!b & c")));
+    }
+
+    #[test]
+    fn test_transition_relation_post_pre_ok() {
+        // A two-bit counter: 00 -> 01 -> 10 -> 11 -> 00.
+        let relation = iff(var("b_p"), !var("b")) & iff(var("a_p"), var("a") ^ var("b"));
+        let relation = Bdd::try_from(relation).unwrap();
+        let relation = TransitionRelation::new(relation, primed(&["a", "b"])).unwrap();
+
+        let initial = bdd("!a & !b");
+        let next = relation.post(&initial);
+        assert!(next.is_equivalent(&bdd("!a & b")));
+        assert_eq!(next.inputs(), BTreeSet::from(["a", "b"].map(String::from)));
+        assert!(relation.post(&next).is_equivalent(&bdd("a & !b")));
+
+        assert!(relation.pre(&initial).is_equivalent(&bdd("a & b")));
+        assert!(relation.pre(&bdd("a | b")).is_equivalent(&bdd("!(a & b)")));
+    }
+
+    #[test]
+    fn test_transition_relation_parameters_ok() {
+        // If `p` holds, `a` is set, otherwise it is reset.
+        let relation = Bdd::try_from(iff(var("a_p"), var("p"))).unwrap();
+        let relation = TransitionRelation::new(relation, primed(&["a"])).unwrap();
+
+        let expected = Bdd::try_from(iff(var("a"), var("p"))).unwrap();
+        assert!(relation.post(&bdd("a")).is_equivalent(&expected));
+        assert!(relation.pre(&bdd("a")).is_equivalent(&bdd("p")));
+    }
+
+    #[test]
+    fn test_transition_relation_order_changing_names_ok() {
+        // Renaming `a` to `z` moves it behind the parameter `p` in the variable order.
+        let relation = Bdd::try_from(iff(var("z"), var("a") & var("p"))).unwrap();
+        let mapping = BTreeMap::from([("a".to_string(), "z".to_string())]);
+        let relation = TransitionRelation::new(relation, mapping).unwrap();
+
+        assert!(relation.post(&bdd("a & !p")).is_equivalent(&bdd("!a & !p")));
+        assert!(relation.pre(&bdd("a & p")).is_equivalent(&bdd("a & p")));
+        assert!(relation.pre(&bdd("!a")).is_equivalent(&bdd("!a | !p")));
+    }
+
+    #[test]
+    fn test_transition_relation_invalid_mapping_err() {
+        let relation = bdd("a_p & b_p");
+
+        let mapping = BTreeMap::from([
+            ("a".to_string(), "a_p".to_string()),
+            ("b".to_string(), "a_p".to_string()),
+        ]);
+        assert_eq!(
+            TransitionRelation::new(relation.clone(), mapping),
+            Err(TransitionRelationError::DuplicatePrimedVariable {
+                name: "\"a_p\"".to_string()
+            })
+        );
+
+        let mapping = BTreeMap::from([
+            ("a".to_string(), "b".to_string()),
+            ("b".to_string(), "b_p".to_string()),
+        ]);
+        assert_eq!(
+            TransitionRelation::new(relation, mapping),
+            Err(TransitionRelationError::OverlappingVariable {
+                name: "\"b\"".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use biodivine_lib_bdd::{BddNode, BddPointer, BddVariable};
+
+use crate::bdd::serialization::{parse_variables, BddDeserializationError, FORMAT_VERSION};
+use crate::bdd::Bdd;
+
+const MAGIC: &[u8] = b"BBFBDD";
+
+impl<T: Debug + Clone + Ord + Display> Bdd<T> {
+    /// Serializes this `Bdd` (including its variable names and order) into a compact
+    /// binary format. See [Bdd::from_bytes].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = MAGIC.to_vec();
+        result.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+
+        result.extend_from_slice(&len_to_u32(self.inputs.len()).to_le_bytes());
+        for input in &self.inputs {
+            let name = input.to_string();
+            result.extend_from_slice(&len_to_u32(name.len()).to_le_bytes());
+            result.extend_from_slice(name.as_bytes());
+        }
+
+        let nodes = self.bdd.clone().to_nodes();
+        result.extend_from_slice(&len_to_u32(nodes.len()).to_le_bytes());
+        for node in nodes {
+            let var = u16::try_from(node.var.to_index()).expect("Bdd variables fit into u16");
+            result.extend_from_slice(&var.to_le_bytes());
+            result.extend_from_slice(&len_to_u32(node.low_link.to_index()).to_le_bytes());
+            result.extend_from_slice(&len_to_u32(node.high_link.to_index()).to_le_bytes());
+        }
+
+        result
+    }
+}
+
+impl<T: Debug + Clone + Ord + FromStr> Bdd<T> {
+    /// Deserializes a `Bdd` written by [Bdd::to_bytes], validating the data in the process.
+    pub fn from_bytes(data: &[u8]) -> Result<Bdd<T>, BddDeserializationError> {
+        let mut reader = ByteReader { data };
+
+        if reader.take(MAGIC.len()).ok() != Some(MAGIC) {
+            return Err(BddDeserializationError::InvalidHeader);
+        }
+        let version = u16::from_le_bytes(reader.take_array()?);
+        if version == 0 || version > FORMAT_VERSION {
+            return Err(BddDeserializationError::UnsupportedVersion {
+                version: u32::from(version),
+            });
+        }
+
+        let variable_count = reader.take_len()?;
+        let mut names = Vec::new();
+        for _ in 0..variable_count {
+            let length = reader.take_len()?;
+            let bytes = reader.take(length)?;
+            let name = String::from_utf8(bytes.to_vec()).map_err(|e| {
+                BddDeserializationError::InvalidVariableName {
+                    name: String::from_utf8_lossy(e.as_bytes()).into_owned(),
+                }
+            })?;
+            names.push(name);
+        }
+        let inputs = parse_variables(names)?;
+
+        let node_count = reader.take_len()?;
+        let mut nodes = Vec::new();
+        for _ in 0..node_count {
+            let var = u16::from_le_bytes(reader.take_array()?);
+            let low = reader.take_len()?;
+            let high = reader.take_len()?;
+            nodes.push(BddNode::mk_node(
+                BddVariable::from_index(usize::from(var)),
+                BddPointer::from_index(low),
+                BddPointer::from_index(high),
+            ));
+        }
+
+        if !reader.data.is_empty() {
+            return Err(BddDeserializationError::TrailingData);
+        }
+
+        Bdd::from_parts(inputs, &nodes)
+    }
+}
+
+fn len_to_u32(length: usize) -> u32 {
+    u32::try_from(length).expect("Bdd data should be smaller than 4GiB")
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], BddDeserializationError> {
+        if self.data.len() < count {
+            return Err(BddDeserializationError::UnexpectedEof);
+        }
+        let (result, rest) = self.data.split_at(count);
+        self.data = rest;
+        Ok(result)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], BddDeserializationError> {
+        Ok(self
+            .take(N)?
+            .try_into()
+            .expect("Slice should have the requested length"))
+    }
+
+    fn take_len(&mut self) -> Result<usize, BddDeserializationError> {
+        Ok(u32::from_le_bytes(self.take_array()?) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdd::serialization::tests::{node, sample_bdds};
+    use crate::expressions::var;
+
+    #[test]
+    fn test_bytes_round_trip_ok() {
+        for bdd in sample_bdds() {
+            let actual = Bdd::<String>::from_bytes(&bdd.to_bytes()).expect("Should not panic here");
+
+            assert_eq!(actual, bdd);
+            assert_eq!(actual.variable_order(), bdd.variable_order());
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_nok() {
+        let bytes = sample_bdds()[3].to_bytes();
+
+        assert_eq!(
+            Bdd::<String>::from_bytes(b"not a bdd"),
+            Err(BddDeserializationError::InvalidHeader)
+        );
+        assert_eq!(
+            Bdd::<String>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(BddDeserializationError::UnexpectedEof)
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            Bdd::<String>::from_bytes(&trailing),
+            Err(BddDeserializationError::TrailingData)
+        );
+
+        let mut future = bytes;
+        future[MAGIC.len()..MAGIC.len() + 2].copy_from_slice(&2u16.to_le_bytes());
+        assert_eq!(
+            Bdd::<String>::from_bytes(&future),
+            Err(BddDeserializationError::UnsupportedVersion { version: 2 })
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_not_post_order_nok() {
+        // `a & b & c` with the node of `b` (index 2) referring to the node of `c` (index 3).
+        let mut bytes = Bdd::<String>::try_from(var("a") & var("b") & var("c"))
+            .unwrap()
+            .to_bytes();
+        let nodes = [node(1, 0, 3), node(2, 0, 1), node(0, 0, 2)];
+        bytes.truncate(bytes.len() - nodes.len() * 10);
+        for node in nodes {
+            let var = u16::try_from(node.var.to_index()).unwrap();
+            bytes.extend_from_slice(&var.to_le_bytes());
+            bytes.extend_from_slice(&len_to_u32(node.low_link.to_index()).to_le_bytes());
+            bytes.extend_from_slice(&len_to_u32(node.high_link.to_index()).to_le_bytes());
+        }
+
+        assert_eq!(
+            Bdd::<String>::from_bytes(&bytes),
+            Err(BddDeserializationError::InvalidNodeTable {
+                reason: "node 2 refers to node 3, which is not below it".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BddDeserializationError {
+    #[error("Data does not start with a serialized Bdd header")]
+    InvalidHeader,
+    #[error(
+        "Unsupported serialization format version {version}, expected at most {}",
+        super::FORMAT_VERSION
+    )]
+    UnsupportedVersion { version: u32 },
+    #[error("Reached the end of data before reading the whole Bdd")]
+    UnexpectedEof,
+    #[error("Unexpected line {line_index}: '{line}'")]
+    UnexpectedLine { line_index: usize, line: String },
+    #[error("Found unexpected data after the end of the serialized Bdd")]
+    TrailingData,
+    #[error("Invalid variable name '{name}'")]
+    InvalidVariableName { name: String },
+    #[error("Variable {name} appears multiple times")]
+    DuplicateVariable { name: String },
+    #[error("A Bdd can contain at most {} variables, found {count}", u16::MAX)]
+    TooManyVariables { count: usize },
+    #[error("Invalid Bdd node table: {reason}")]
+    InvalidNodeTable { reason: String },
+}
+
+#[cfg(feature = "python")]
+impl From<BddDeserializationError> for PyErr {
+    fn from(err: BddDeserializationError) -> PyErr {
+        use pyo3::exceptions::{PyEOFError, PyValueError};
+
+        match err {
+            e @ BddDeserializationError::UnexpectedEof => PyEOFError::new_err(e.to_string()),
+            e => PyValueError::new_err(e.to_string()),
+        }
+    }
+}
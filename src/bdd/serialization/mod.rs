@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddNode, BddVariableSet};
+
+use crate::bdd::Bdd;
+
+pub use error::BddDeserializationError;
+
+mod binary;
+mod error;
+mod text;
+
+/*
+   Both formats store the variable names (in the variable order of the BDD) followed by the
+   `lib-bdd` node table (including the two terminal nodes), starting with a header that
+   carries the format version.
+
+   Binary (all integers little-endian):
+       b"BBFBDD" | version: u16 | variable count: u32 | for each variable: (byte length: u32,
+       UTF-8 name) | node count: u32 | for each node: (variable: u16, low: u32, high: u32)
+
+   Text:
+       bbf-bdd <version>
+       variables <count>
+       <one name per line, with `\`, line feed and carriage return escaped as `\\`, `\n`, `\r`>
+       nodes <count>
+       <one node per line as `<variable> <low> <high>`>
+
+   The deserializers accept every version up to `FORMAT_VERSION` and validate that the node
+   table is a reduced BDD over the stored variables, in which every node only refers to
+   nodes with smaller indices.
+*/
+
+/// The newest version of the serialization formats, written by the serializers.
+pub const FORMAT_VERSION: u16 = 1;
+
+/// Converts the parsed variable names into `T` and checks that they are unique.
+fn parse_variables<T: Debug + Clone + Ord + FromStr>(
+    names: Vec<String>,
+) -> Result<Vec<T>, BddDeserializationError> {
+    if names.len() > usize::from(u16::MAX) {
+        return Err(BddDeserializationError::TooManyVariables { count: names.len() });
+    }
+
+    let mut seen = HashSet::new();
+    names
+        .into_iter()
+        .map(|name| {
+            if !seen.insert(name.clone()) {
+                return Err(BddDeserializationError::DuplicateVariable { name });
+            }
+            T::from_str(&name).map_err(|_| BddDeserializationError::InvalidVariableName { name })
+        })
+        .collect()
+}
+
+/// Checks that `nodes` form a reduced `lib-bdd` node table over `variable_count` variables,
+/// in which every node only refers to nodes below it.
+///
+/// The other operations on `Bdd` objects expect the nodes in the DFS post-order produced by
+/// `lib-bdd`, hence the nodes are rebuilt in this order.
+fn build_inner_bdd(
+    variable_count: usize,
+    nodes: &[BddNode],
+) -> Result<InnerBdd, BddDeserializationError> {
+    let invalid = |reason: String| BddDeserializationError::InvalidNodeTable { reason };
+
+    match nodes.first() {
+        Some(zero) if zero.var.to_index() != variable_count => {
+            return Err(invalid(format!(
+                "terminal nodes use {} variables, expected {variable_count}",
+                zero.var.to_index()
+            )))
+        }
+        _ => (),
+    }
+
+    for (index, node) in nodes.iter().enumerate().skip(2) {
+        let child = node.low_link.to_index().max(node.high_link.to_index());
+        if child >= index {
+            return Err(invalid(format!(
+                "node {index} refers to node {child}, which is not below it"
+            )));
+        }
+    }
+
+    let inner = InnerBdd::from_nodes(nodes).map_err(invalid)?;
+    inner.validate().map_err(invalid)?;
+
+    let mut unique = HashSet::new();
+    for (index, node) in nodes.iter().enumerate().skip(2) {
+        if node.low_link == node.high_link {
+            return Err(invalid(format!("node {index} is redundant")));
+        }
+        if !unique.insert((node.var, node.low_link, node.high_link)) {
+            return Err(invalid(format!("node {index} is a duplicate")));
+        }
+    }
+
+    // The `lib-bdd` operations build their results in DFS post-order.
+    let all = BddVariableSet::new_anonymous(inner.num_vars()).mk_true();
+    Ok(inner.and(&all))
+}
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    fn from_parts(inputs: Vec<T>, nodes: &[BddNode]) -> Result<Bdd<T>, BddDeserializationError> {
+        let inner = build_inner_bdd(inputs.len(), nodes)?;
+        Ok(Bdd::new(inner, inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdd::ordering::OrderingHeuristic;
+    use crate::expressions::{var, Expression};
+    use biodivine_lib_bdd::{BddPointer, BddVariable};
+
+    /// A node of `variable` with the given `low` and `high` child indices.
+    pub(super) fn node(variable: usize, low: usize, high: usize) -> BddNode {
+        BddNode::mk_node(
+            BddVariable::from_index(variable),
+            BddPointer::from_index(low),
+            BddPointer::from_index(high),
+        )
+    }
+
+    pub(super) fn sample_bdds() -> Vec<Bdd<String>> {
+        let expression = Expression::from_str("(a & !b) | (c & !d) | (!c & d) | (a & d)").unwrap();
+        vec![
+            Bdd::mk_const(false),
+            Bdd::mk_const(true),
+            Bdd::mk_literal("x".to_string(), false),
+            Bdd::try_from(expression.clone()).unwrap(),
+            Bdd::from_expression_with_heuristic(&expression, OrderingHeuristic::DepthFirst)
+                .unwrap(),
+            Bdd::try_from(var("weird name") & !var("with\\escaped\nlines\r")).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_build_inner_bdd_nok() {
+        let valid = Bdd::<String>::try_from(Expression::from_str("a & !b").unwrap()).unwrap();
+        let nodes = valid.inner().clone().to_nodes();
+
+        assert!(build_inner_bdd(2, &nodes).is_ok());
+        assert!(build_inner_bdd(3, &nodes).is_err());
+
+        let mut redundant = nodes.clone();
+        let last = redundant.len() - 1;
+        redundant[last].high_link = redundant[last].low_link;
+        assert!(build_inner_bdd(2, &redundant).is_err());
+
+        let mut broken = nodes;
+        let last = broken.len() - 1;
+        broken[last].low_link = BddPointer::from_index(last + 1);
+        assert!(build_inner_bdd(2, &broken).is_err());
+    }
+
+    #[test]
+    fn test_build_inner_bdd_not_post_order_nok() {
+        // `a & b & c` with the node of `b` (index 2) referring to the node of `c` (index 3).
+        let nodes = [
+            BddNode::mk_zero(3),
+            BddNode::mk_one(3),
+            node(1, 0, 3),
+            node(2, 0, 1),
+            node(0, 0, 2),
+        ];
+
+        assert_eq!(
+            build_inner_bdd(3, &nodes),
+            Err(BddDeserializationError::InvalidNodeTable {
+                reason: "node 2 refers to node 3, which is not below it".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_inner_bdd_reorders_nodes_ok() {
+        // `(a & b) | (!a & c)`, with the two independent nodes swapped.
+        let nodes = [
+            BddNode::mk_zero(3),
+            BddNode::mk_one(3),
+            node(2, 0, 1),
+            node(1, 0, 1),
+            node(0, 2, 3),
+        ];
+        let expected = Bdd::<String>::try_from((var("a") & var("b")) | (!var("a") & var("c")))
+            .unwrap()
+            .inner()
+            .clone();
+
+        assert_ne!(nodes.to_vec(), expected.clone().to_nodes());
+        let actual = build_inner_bdd(3, &nodes).expect("Should not panic here");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_variables_nok() {
+        assert_eq!(
+            parse_variables::<String>(vec!["a".to_string(), "a".to_string()]),
+            Err(BddDeserializationError::DuplicateVariable {
+                name: "a".to_string()
+            })
+        );
+        assert_eq!(
+            parse_variables::<u32>(vec!["1".to_string(), "x".to_string()]),
+            Err(BddDeserializationError::InvalidVariableName {
+                name: "x".to_string()
+            })
+        );
+    }
+}
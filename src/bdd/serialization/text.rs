@@ -0,0 +1,200 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use biodivine_lib_bdd::{BddNode, BddPointer, BddVariable};
+
+use crate::bdd::serialization::{parse_variables, BddDeserializationError, FORMAT_VERSION};
+use crate::bdd::Bdd;
+
+const HEADER: &str = "bbf-bdd";
+
+impl<T: Debug + Clone + Ord + Display> Bdd<T> {
+    /// Serializes this `Bdd` (including its variable names and order) into a line-based,
+    /// human-readable text format. See [Bdd::from_text].
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![
+            format!("{HEADER} {FORMAT_VERSION}"),
+            format!("variables {}", self.inputs.len()),
+        ];
+        lines.extend(self.inputs.iter().map(|it| escape(&it.to_string())));
+
+        let nodes = self.bdd.clone().to_nodes();
+        lines.push(format!("nodes {}", nodes.len()));
+        lines.extend(nodes.into_iter().map(|node| {
+            format!(
+                "{} {} {}",
+                node.var.to_index(),
+                node.low_link.to_index(),
+                node.high_link.to_index()
+            )
+        }));
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+impl<T: Debug + Clone + Ord + FromStr> Bdd<T> {
+    /// Deserializes a `Bdd` written by [Bdd::to_text], validating the data in the process.
+    pub fn from_text(data: &str) -> Result<Bdd<T>, BddDeserializationError> {
+        let mut lines = data.trim_end_matches(['\n', '\r']).lines().enumerate();
+        let mut next_line = || lines.next().ok_or(BddDeserializationError::UnexpectedEof);
+
+        let (_, header) = next_line()?;
+        let version = header
+            .strip_prefix(HEADER)
+            .and_then(|rest| rest.strip_prefix(' '))
+            .ok_or(BddDeserializationError::InvalidHeader)?
+            .parse::<u32>()
+            .map_err(|_| BddDeserializationError::InvalidHeader)?;
+        if version == 0 || version > u32::from(FORMAT_VERSION) {
+            return Err(BddDeserializationError::UnsupportedVersion { version });
+        }
+
+        let variable_count = parse_count(next_line()?, "variables")?;
+        let mut names = Vec::new();
+        for _ in 0..variable_count {
+            let (line_index, line) = next_line()?;
+            let name = unescape(line).ok_or_else(|| unexpected_line(line_index, line))?;
+            names.push(name);
+        }
+        let inputs = parse_variables(names)?;
+
+        let node_count = parse_count(next_line()?, "nodes")?;
+        let mut nodes = Vec::new();
+        for _ in 0..node_count {
+            let (line_index, line) = next_line()?;
+            let node = parse_node(line).ok_or_else(|| unexpected_line(line_index, line))?;
+            nodes.push(node);
+        }
+
+        if let Ok((line_index, line)) = next_line() {
+            return Err(unexpected_line(line_index, line));
+        }
+
+        Bdd::from_parts(inputs, &nodes)
+    }
+}
+
+fn unexpected_line(line_index: usize, line: &str) -> BddDeserializationError {
+    BddDeserializationError::UnexpectedLine {
+        line_index,
+        line: line.to_string(),
+    }
+}
+
+fn parse_count(
+    (line_index, line): (usize, &str),
+    keyword: &str,
+) -> Result<usize, BddDeserializationError> {
+    line.strip_prefix(keyword)
+        .and_then(|rest| rest.strip_prefix(' '))
+        .and_then(|count| count.parse::<usize>().ok())
+        .ok_or_else(|| unexpected_line(line_index, line))
+}
+
+fn parse_node(line: &str) -> Option<BddNode> {
+    let mut values = line.split(' ');
+    let var = values.next()?.parse::<u16>().ok()?;
+    let low = values.next()?.parse::<u32>().ok()?;
+    let high = values.next()?.parse::<u32>().ok()?;
+    if values.next().is_some() {
+        return None;
+    }
+
+    Some(BddNode::mk_node(
+        BddVariable::from_index(usize::from(var)),
+        BddPointer::from_index(low as usize),
+        BddPointer::from_index(high as usize),
+    ))
+}
+
+fn escape(name: &str) -> String {
+    name.replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape(line: &str) -> Option<String> {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next()? {
+            '\\' => result.push('\\'),
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            _ => return None,
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdd::serialization::tests::sample_bdds;
+
+    #[test]
+    fn test_text_round_trip_ok() {
+        for bdd in sample_bdds() {
+            let actual = Bdd::<String>::from_text(&bdd.to_text()).expect("Should not panic here");
+
+            assert_eq!(actual, bdd);
+            assert_eq!(actual.variable_order(), bdd.variable_order());
+        }
+    }
+
+    #[test]
+    fn test_to_text_ok() {
+        let input = Bdd::mk_literal("a".to_string(), true);
+
+        let expected = "bbf-bdd 1\nvariables 1\na\nnodes 3\n1 0 0\n1 1 1\n0 0 1\n";
+
+        assert_eq!(input.to_text(), expected);
+    }
+
+    #[test]
+    fn test_from_text_nok() {
+        assert_eq!(
+            Bdd::<String>::from_text("bbf-bdd 7\n"),
+            Err(BddDeserializationError::UnsupportedVersion { version: 7 })
+        );
+        assert_eq!(
+            Bdd::<String>::from_text("bbf-bdd 1\nvariables 2\na\n"),
+            Err(BddDeserializationError::UnexpectedEof)
+        );
+        assert_eq!(
+            Bdd::<String>::from_text("bbf-bdd 1\nvariables 1\na\nnodes 3\n1 0 0\n1 1 1\n0 x 1\n"),
+            Err(BddDeserializationError::UnexpectedLine {
+                line_index: 6,
+                line: "0 x 1".to_string()
+            })
+        );
+        assert!(matches!(
+            Bdd::<String>::from_text("bbf-bdd 1\nvariables 1\na\nnodes 3\n1 0 0\n1 1 1\n0 1 1\n"),
+            Err(BddDeserializationError::InvalidNodeTable { .. })
+        ));
+        assert!(matches!(
+            Bdd::<String>::from_text("bbf-bdd 1\nvariables 1\na\nnodes 1\n1 0 0\nextra\n"),
+            Err(BddDeserializationError::UnexpectedLine { line_index: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_text_not_post_order_nok() {
+        // `a & b & c` with the node of `b` (index 2) referring to the node of `c` (index 3).
+        let input = "bbf-bdd 1\nvariables 3\na\nb\nc\nnodes 5\n\
+                     3 0 0\n3 1 1\n1 0 3\n2 0 1\n0 0 2\n";
+
+        assert_eq!(
+            Bdd::<String>::from_text(input),
+            Err(BddDeserializationError::InvalidNodeTable {
+                reason: "node 2 refers to node 3, which is not below it".to_string()
+            })
+        );
+    }
+}
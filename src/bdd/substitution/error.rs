@@ -0,0 +1,17 @@
+use std::num::TryFromIntError;
+
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BddSubstitutionError {
+    #[error("The substitution result would have more than {} variables", u16::MAX)]
+    TooManyVariables(#[from] TryFromIntError),
+}
+
+#[cfg(feature = "python")]
+impl From<BddSubstitutionError> for PyErr {
+    fn from(err: BddSubstitutionError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
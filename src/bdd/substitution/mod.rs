@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use biodivine_lib_bdd::{BddVariable, BddVariableSet};
+
+use crate::bdd::utils::{compose_bdd_variables, merge_variable_orders, prune_bdd_variables};
+use crate::bdd::Bdd;
+
+pub use error::BddSubstitutionError;
+
+mod error;
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// Simultaneously substitutes the variables specified by `mapping` for their supplied
+    /// functions (see [crate::traits::BooleanFunction::substitute]).
+    ///
+    /// The substituted functions can depend on any variables, including the substituted ones.
+    /// For example, substituting `{ x: x & y, y: x }` in `x & !y` produces `(x & y) & !x`.
+    ///
+    /// The inputs of the result are the inputs of `self` that are not substituted, together
+    /// with the inputs of the functions substituted for the inputs of `self`. Entries of
+    /// `mapping` for variables that are not inputs of `self` are ignored.
+    ///
+    /// Returns an `Err` if the result would have more variables than `lib-bdd` supports.
+    pub fn try_substitute(
+        &self,
+        mapping: &BTreeMap<T, Bdd<T>>,
+    ) -> Result<Bdd<T>, BddSubstitutionError> {
+        let relevant = mapping
+            .iter()
+            .filter(|(var, _value)| self.inputs.contains(var))
+            .collect::<BTreeMap<_, _>>();
+        if relevant.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let common_inputs = relevant
+            .values()
+            .fold(self.inputs.clone(), |common, value| {
+                merge_variable_orders(&common, &value.inputs)
+            });
+        let variables = BddVariableSet::new_anonymous(u16::try_from(common_inputs.len())?);
+
+        // Composing all variables at once means that every replacement is applied to
+        // the original function, never to the result of another replacement.
+        let replacements = common_inputs
+            .iter()
+            .enumerate()
+            .map(|(index, var)| match relevant.get(var) {
+                Some(value) => value.lift_to(&common_inputs).bdd,
+                None => variables.mk_var(BddVariable::from_index(index)),
+            })
+            .collect::<Vec<_>>();
+        let self_lifted = self.lift_to(&common_inputs);
+        let composed = compose_bdd_variables(&self_lifted.bdd, &variables, &replacements);
+
+        let final_inputs = common_inputs
+            .iter()
+            .filter(|var| {
+                !relevant.contains_key(var) || relevant.values().any(|it| it.inputs.contains(var))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        Ok(prune_bdd_variables(
+            &Bdd::new(composed, common_inputs),
+            &final_inputs,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{var, Expression};
+    use crate::random::{RandomExpressionBounds, RandomFunctions};
+    use crate::traits::BooleanFunction;
+    use std::str::FromStr;
+
+    fn bdd(expression: &str) -> Bdd<String> {
+        Bdd::try_from(Expression::from_str(expression).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_try_substitute_self_reference_ok() {
+        let input = bdd("x & !y");
+        let mapping =
+            BTreeMap::from([("x".to_string(), bdd("x & y")), ("y".to_string(), bdd("x"))]);
+
+        let actual = input
+            .try_substitute(&mapping)
+            .expect("Should not panic here");
+
+        assert_eq!(actual, bdd("(x & y) & !x"));
+        assert!(actual.inner().is_false());
+        assert_eq!(actual.degree(), 2);
+    }
+
+    #[test]
+    fn test_try_substitute_swap_ok() {
+        let input = bdd("a & !b | c");
+        let mapping = BTreeMap::from([("a".to_string(), bdd("b")), ("b".to_string(), bdd("a"))]);
+
+        let actual = input
+            .try_substitute(&mapping)
+            .expect("Should not panic here");
+
+        assert_eq!(actual, bdd("b & !a | c"));
+    }
+
+    #[test]
+    fn test_try_substitute_unused_variable_ignored_ok() {
+        let input = bdd("a | b");
+        let mapping = BTreeMap::from([("c".to_string(), bdd("d & e"))]);
+
+        let actual = input
+            .try_substitute(&mapping)
+            .expect("Should not panic here");
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_try_substitute_agrees_with_expression_ok() {
+        let inputs = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let bounds = RandomExpressionBounds {
+            max_size: 12,
+            max_depth: 4,
+            max_arity: 3,
+        };
+
+        for seed in 0..50 {
+            let mut random = RandomFunctions::new(inputs.clone(), seed);
+            let function = random.expression(&bounds).unwrap();
+            let mapping = inputs
+                .iter()
+                .take(seed as usize % 4 + 1)
+                .map(|name| (name.clone(), random.expression(&bounds).unwrap()))
+                .collect::<BTreeMap<_, _>>();
+            let bdd_mapping = mapping
+                .iter()
+                .map(|(name, value)| (name.clone(), Bdd::try_from(value.clone()).unwrap()))
+                .collect::<BTreeMap<_, _>>();
+
+            let expected = Bdd::try_from(function.substitute(&mapping)).unwrap();
+            let actual = Bdd::try_from(function)
+                .unwrap()
+                .try_substitute(&bdd_mapping)
+                .expect("Should not panic here");
+
+            assert_eq!(actual, expected, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_try_substitute_different_orders_ok() {
+        let input =
+            Bdd::from_expression_with_order(&(var("a") & !var("b")), &["b", "a"].map(String::from))
+                .unwrap();
+        let mapping = BTreeMap::from([("a".to_string(), bdd("b | c"))]);
+
+        let actual = input
+            .try_substitute(&mapping)
+            .expect("Should not panic here");
+
+        assert!(actual.is_equivalent(&bdd("(b | c) & !b")));
+        assert_eq!(actual.inputs(), bdd("b & c").inputs());
+    }
+}
@@ -94,7 +94,7 @@ mod tests {
         let actual = lhs | rhs;
         let expected = Bdd::try_from(TruthTable::new(
             vec!["a", "b", "c"],
-            vec![true, true, true, true, false, true, true, true],
+            vec![true, true, true, true, false, false, true, true],
         ))
         .expect("Should not panic here");
 
@@ -91,7 +91,7 @@ mod tests {
         ))
         .expect("Should not panic here");
 
-        let actual = lhs | rhs;
+        let actual = lhs ^ rhs;
         let expected = Bdd::try_from(TruthTable::new(
             vec!["a", "b", "c"],
             vec![true, false, true, false, false, true, true, false],
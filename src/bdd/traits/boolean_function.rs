@@ -1,11 +1,11 @@
+use crate::bdd::iterators::support::unpermute_point;
 use crate::bdd::iterators::{ImageIterator, SupportIterator};
 use crate::bdd::Bdd;
 use crate::iterators::DomainIterator;
 use crate::traits::{BooleanFunction, BooleanPoint, BooleanValuation};
-use biodivine_lib_bdd::Bdd as InnerBdd;
 use biodivine_lib_bdd::BddVariable;
 use num_bigint::BigUint;
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::iter::{zip, Zip};
 
@@ -39,7 +39,7 @@ impl<T: Debug + Clone + Ord> BooleanFunction<T> for Bdd<T> {
     }
 
     fn image(&self) -> Self::RangeIterator {
-        ImageIterator::new(self.inputs.len(), &self.bdd)
+        ImageIterator::new(self.inputs.len(), &self.bdd, self.sorted_positions())
     }
 
     fn relation(&self) -> Self::RelationIterator {
@@ -48,7 +48,7 @@ impl<T: Debug + Clone + Ord> BooleanFunction<T> for Bdd<T> {
     }
 
     fn support(&self) -> Self::SupportIterator {
-        SupportIterator::new(&self.bdd)
+        SupportIterator::new(&self.bdd, self.sorted_positions())
     }
 
     fn weight(&self) -> BigUint {
@@ -66,32 +66,16 @@ impl<T: Debug + Clone + Ord> BooleanFunction<T> for Bdd<T> {
     }
 
     fn substitute(&self, mapping: &BTreeMap<T, Self>) -> Self {
-        // Bdd.substitute exists, but assumes all BDDs share input variables (we need to extend)
-        // and does not eliminate the substituted variable from inputs afterward (we need to prune).
-
-        // Bdd.substitute currently assumes that the substituted functions does not depend on the
-        // substituted variables. This will be solved in lib-bdd, we can just panic for now.
-        if mapping
-            .iter()
-            .any(|(key, value_bdd)| value_bdd.inputs.contains(key))
-        {
-            panic!("Currently not allowed to have the substituted variable also appear in the substituting BDD value");
-        }
-
-        let mut extended_mapping = mapping.clone();
-        let (mut self_lifted, _common_inputs) = self.union_and_extend_n_ary(&mut extended_mapping);
-
-        for (k, v) in extended_mapping.iter() {
-            self_lifted.bdd = self_lifted
-                .bdd
-                .substitute(self_lifted.map_var_outer_to_inner(k).unwrap(), &v.bdd)
-        }
-
-        self_lifted.restrict_and_prune_map(&extended_mapping, &self_lifted)
+        self.try_substitute(mapping)
+            .expect("Substitution result should have at most 2^16 variables")
     }
 
     fn sat_point(&self) -> Option<BooleanPoint> {
-        self.bdd.sat_witness().map(|it| it.vector())
+        let point = self.bdd.sat_witness()?.into_vector();
+        match self.sorted_positions() {
+            None => Some(point),
+            Some(positions) => Some(unpermute_point(&point, &positions)),
+        }
     }
 
     fn existential_quantification(&self, variables: BTreeSet<T>) -> Self {
@@ -115,22 +99,17 @@ impl<T: Debug + Clone + Ord> BooleanFunction<T> for Bdd<T> {
     }
 
     fn derivative(&self, variables: BTreeSet<T>) -> Self {
-        let lib_bdd_variables = variables
+        // Note that `InnerBdd::binary_op_nested` cannot be used here, since it only applies
+        // the nested operation to variables that appear in the BDD, but the derivative
+        // with respect to a non-essential variable is always `false`.
+        let new_inner = variables
             .iter()
             .filter_map(|it| self.map_var_outer_to_inner(it))
-            .collect::<HashSet<_>>();
-        let trigger = |var: BddVariable| lib_bdd_variables.contains(&var);
-
-        let new_bdd = Bdd::new(
-            InnerBdd::binary_op_nested(
-                &self.bdd,
-                &self.bdd,
-                trigger,
-                biodivine_lib_bdd::op_function::and,
-                biodivine_lib_bdd::op_function::xor,
-            ),
-            self.inputs.clone(),
-        );
+            .fold(self.bdd.clone(), |bdd, var| {
+                bdd.var_restrict(var, false)
+                    .xor(&bdd.var_restrict(var, true))
+            });
+        let new_bdd = Bdd::new(new_inner, self.inputs.clone());
 
         self.restrict_and_prune_set(&variables, &new_bdd)
     }
@@ -472,7 +451,6 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_substitute_variables_same_substituted_ok() {
         let input = Bdd::try_from((var("a") | var("b")) & var("c") & !var("a") & bool(true))
             .expect("Should not panic here");
@@ -554,7 +532,6 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_substitute_variables_added_and_substituted_ok() {
         let input = Bdd::try_from((var("a") | var("b")) & var("c") & !var("a") & bool(true))
             .expect("Should not panic here");
@@ -753,6 +730,18 @@ mod tests {
         assert!(evaluated_with_true)
     }
 
+    #[test]
+    fn test_derivative_non_essential_ok() {
+        let target = "a".to_string();
+        let set = BTreeSet::from([target.clone()]);
+        let input = Bdd::try_from(var(target.clone()) | !var(target.clone()) | var("b"))
+            .expect("Should not panic here");
+
+        let actual = input.derivative(set);
+        assert!(!actual.inputs().contains(&target));
+        assert_eq!(actual.weight(), BigUint::from(0u8));
+    }
+
     #[test]
     fn test_is_implied_by_unit_ok() {
         let f = Bdd::try_from(bool(false)).expect("Should not panic here");
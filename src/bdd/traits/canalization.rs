@@ -0,0 +1,93 @@
+use crate::bdd::Bdd;
+use crate::traits::Canalization;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> Canalization<T> for Bdd<T> {
+    fn canalized_output(&self, variable: &T, value: bool) -> Option<bool> {
+        let restricted = match self.map_var_outer_to_inner(variable) {
+            Some(variable) => self.bdd.var_restrict(variable, value),
+            None => self.bdd.clone(),
+        };
+
+        if restricted.is_true() {
+            Some(true)
+        } else if restricted.is_false() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::{bool, var};
+    use crate::traits::{Canalization, CanalizingInput};
+
+    fn input(variable: &str, value: bool, output: bool) -> CanalizingInput<String> {
+        CanalizingInput {
+            variable: variable.to_string(),
+            value,
+            output,
+        }
+    }
+
+    #[test]
+    fn test_canalizing_variables_ok() {
+        let function = Bdd::try_from(var("a") & (var("b") | var("c"))).unwrap();
+        assert_eq!(
+            function.canalizing_variables(),
+            vec![input("a", false, false)]
+        );
+
+        let function = Bdd::try_from(!var("a")).unwrap();
+        assert_eq!(
+            function.canalizing_variables(),
+            vec![input("a", false, true), input("a", true, false)]
+        );
+
+        let function = Bdd::try_from(var("a") ^ var("b")).unwrap();
+        assert!(function.canalizing_variables().is_empty());
+        assert!(Bdd::<String>::mk_const(true)
+            .canalizing_variables()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_nested_canalizing_layers_ok() {
+        let function = Bdd::try_from(var("a") | (var("b") & !var("c"))).unwrap();
+
+        assert_eq!(
+            function.nested_canalizing_layers(),
+            Some(vec![
+                vec![input("a", true, true)],
+                vec![input("b", false, false), input("c", true, false)],
+            ])
+        );
+        assert!(function.is_nested_canalizing());
+        assert_eq!(function.canalizing_depth(), 3);
+
+        // Both values of a literal are canalizing, with different outputs.
+        let function = Bdd::try_from(!var("a")).unwrap();
+        assert_eq!(
+            function.nested_canalizing_layers(),
+            Some(vec![vec![input("a", false, true), input("a", true, false)]])
+        );
+        assert_eq!(function.canalizing_depth(), 1);
+    }
+
+    #[test]
+    fn test_canalizing_depth_partial_ok() {
+        // Once `a` is fixed to `true`, the core `b ^ c` is not canalizing.
+        let function = Bdd::try_from(var("a") & (var("b") ^ var("c"))).unwrap();
+
+        assert_eq!(function.nested_canalizing_layers(), None);
+        assert!(!function.is_nested_canalizing());
+        assert_eq!(function.canalizing_depth(), 1);
+
+        let constant = Bdd::try_from(var("a") & bool(false)).unwrap();
+        assert_eq!(constant.nested_canalizing_layers(), None);
+        assert_eq!(constant.canalizing_depth(), 0);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::bdd::utils::bdd_probability;
+use crate::bdd::Bdd;
+use crate::table::TruthTable;
+use crate::traits::fourier_measures::check_flip_probability;
+use crate::traits::{BooleanFunction, FourierMeasures, FourierMeasuresError};
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddVariable, BddVariableSet};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> FourierMeasures<T> for Bdd<T> {
+    fn influence(&self, variable: &T) -> BigRational {
+        if self.map_var_outer_to_inner(variable).is_none() {
+            return BigRational::zero();
+        }
+
+        // The derivative no longer depends on `variable`, so its weight is the number of
+        // (unordered) pairs of sensitive points.
+        let derivative = self.derivative(BTreeSet::from([variable.clone()]));
+        BigRational::new(
+            BigInt::from(derivative.weight()),
+            BigInt::one() << (self.inputs.len() - 1),
+        )
+    }
+
+    fn max_sensitivity(&self) -> usize {
+        // `at_least[k]` is the set of points in which at least `k` of the
+        // already processed variables are sensitive.
+        let variables = BddVariableSet::new_anonymous(self.bdd.num_vars());
+        let mut at_least = vec![variables.mk_true()];
+        for index in 0..self.inputs.len() {
+            let variable = BddVariable::from_index(index);
+            let sensitive = self
+                .bdd
+                .var_restrict(variable, false)
+                .xor(&self.bdd.var_restrict(variable, true));
+
+            at_least.push(at_least.last().unwrap().and(&sensitive));
+            for k in (1..at_least.len() - 1).rev() {
+                at_least[k] = at_least[k].or(&at_least[k - 1].and(&sensitive));
+            }
+        }
+
+        at_least
+            .iter()
+            .rposition(|points| !points.is_false())
+            .unwrap_or_default()
+    }
+
+    fn max_block_sensitivity(&self) -> usize {
+        TruthTable::from(self.clone()).max_block_sensitivity()
+    }
+
+    fn average_block_sensitivity(&self) -> BigRational {
+        TruthTable::from(self.clone()).average_block_sensitivity()
+    }
+
+    fn noise_stability(
+        &self,
+        flip_probability: &BigRational,
+    ) -> Result<BigRational, FourierMeasuresError> {
+        check_flip_probability(flip_probability)?;
+
+        // We use three copies of each variable `v`: the original point `x_v`, the perturbed
+        // point `y_v` and the flip indicator `z_v`. The copies are interleaved (`x_v`, `y_v`,
+        // `z_v` at positions `3v`, `3v+1` and `3v+2`) to keep the intermediate BDDs small.
+        let variable_count = self.inputs.len();
+        let copies_count = u16::try_from(3 * variable_count)
+            .map_err(|_| FourierMeasuresError::TooManyVariables)?;
+        let copies = BddVariableSet::new_anonymous(copies_count);
+        let x_var = |index: usize| BddVariable::from_index(3 * index);
+        let y_var = |index: usize| BddVariable::from_index(3 * index + 1);
+        let z_var = |index: usize| BddVariable::from_index(3 * index + 2);
+
+        let f_x = spread_variables(&self.bdd, copies_count, x_var);
+        let f_y = spread_variables(&self.bdd, copies_count, y_var);
+
+        // y_v <=> (x_v ^ z_v)
+        let is_perturbed = (0..variable_count)
+            .map(|index| {
+                let flipped = copies
+                    .mk_var(x_var(index))
+                    .xor(&copies.mk_var(z_var(index)));
+                copies.mk_var(y_var(index)).iff(&flipped)
+            })
+            .fold(copies.mk_true(), |acc, it| acc.and(&it));
+
+        let y_vars = (0..variable_count).map(y_var).collect::<Vec<_>>();
+        let disagreement = InnerBdd::binary_op_with_exists(
+            &f_x.xor(&f_y),
+            &is_perturbed,
+            biodivine_lib_bdd::op_function::and,
+            &y_vars,
+        );
+
+        let half = BigRational::new(BigInt::one(), BigInt::from(2));
+        let probabilities = (0..variable_count)
+            .flat_map(|_| [half.clone(), half.clone(), flip_probability.clone()])
+            .collect::<Vec<_>>();
+        let p_disagreement = bdd_probability(&disagreement, &probabilities);
+
+        Ok(BigRational::one() - BigRational::from_integer(2.into()) * p_disagreement)
+    }
+}
+
+/// Moves the variables of `bdd` into a larger variable set of `num_vars` variables such
+/// that the `i`-th variable becomes `new_variable(i)`.
+///
+/// The `new_variable` mapping must be strictly increasing.
+fn spread_variables<F: Fn(usize) -> BddVariable>(
+    bdd: &InnerBdd,
+    num_vars: u16,
+    new_variable: F,
+) -> InnerBdd {
+    let permutation = (0..usize::from(bdd.num_vars()))
+        .map(|index| (BddVariable::from_index(index), new_variable(index)))
+        .filter(|(old, new)| old != new)
+        .collect::<HashMap<_, _>>();
+
+    let mut result = bdd.clone();
+    unsafe {
+        // Same as in `extend_bdd_variables`, this is safe because the mapping
+        // preserves the variable ordering.
+        result.set_num_vars(num_vars);
+        if !permutation.is_empty() {
+            result.rename_variables(&permutation);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::{var, Expression};
+    use crate::table::TruthTable;
+    use crate::traits::FourierMeasures;
+    use num_rational::BigRational;
+    use num_traits::{One, Zero};
+
+    fn ratio(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(numerator.into(), denominator.into())
+    }
+
+    fn test_expressions() -> Vec<Expression<String>> {
+        vec![
+            var("a") & var("b"),
+            var("a") ^ var("b") ^ var("c"),
+            (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c")),
+            Expression::n_ary_or(&[var("a") & !var("d"), var("c") & var("b"), !var("e")]),
+            (var("a") & var("b") & !var("c") & !var("d"))
+                | (!var("a") & !var("b") & var("c") & var("d")),
+            var("a") | !var("a"),
+        ]
+    }
+
+    #[test]
+    fn test_influence_ok() {
+        let input = Bdd::try_from(var("a") & var("b")).unwrap();
+
+        assert_eq!(input.influence(&"a".to_string()), ratio(1, 2));
+        assert_eq!(input.influence(&"c".to_string()), BigRational::zero());
+        assert_eq!(input.total_influence(), BigRational::one());
+    }
+
+    #[test]
+    fn test_constant_ok() {
+        let input = Bdd::try_from(var("a") | !var("a")).unwrap();
+
+        assert_eq!(input.total_influence(), BigRational::zero());
+        assert_eq!(input.max_sensitivity(), 0);
+        assert_eq!(
+            input.noise_stability(&ratio(1, 3)).unwrap(),
+            BigRational::one()
+        );
+
+        let input = Bdd::<String>::mk_const(false);
+        assert_eq!(input.max_sensitivity(), 0);
+        assert_eq!(
+            input.noise_stability(&ratio(1, 3)).unwrap(),
+            BigRational::one()
+        );
+    }
+
+    #[test]
+    fn test_measures_match_table_ok() {
+        for expression in test_expressions() {
+            let bdd = Bdd::try_from(expression.clone()).unwrap();
+            let table = TruthTable::from(expression);
+
+            for variable in ["a", "b", "c", "d", "e"].map(|it| it.to_string()) {
+                assert_eq!(bdd.influence(&variable), table.influence(&variable));
+            }
+            assert_eq!(bdd.total_influence(), table.total_influence());
+            assert_eq!(bdd.max_sensitivity(), table.max_sensitivity());
+            assert_eq!(bdd.max_block_sensitivity(), table.max_block_sensitivity());
+            assert_eq!(
+                bdd.average_block_sensitivity(),
+                table.average_block_sensitivity()
+            );
+            for flip in [ratio(0, 1), ratio(1, 5), ratio(1, 2), ratio(1, 1)] {
+                assert_eq!(
+                    bdd.noise_stability(&flip).unwrap(),
+                    table.noise_stability(&flip).unwrap()
+                );
+            }
+        }
+    }
+}
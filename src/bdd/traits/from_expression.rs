@@ -28,7 +28,7 @@ impl<T: Debug + Clone + Ord> TryFrom<Expression<T>> for Bdd<T> {
     }
 }
 
-fn try_from_rec<T: Debug + Clone + Ord>(
+pub(crate) fn try_from_rec<T: Debug + Clone + Ord>(
     expression: &Expression<T>,
     literal_set: &BddVariableSet,
     literal_index_map: &BTreeMap<T, usize>,
@@ -13,7 +13,7 @@ impl<T: Debug + Clone + Ord> TryFrom<TruthTable<T>> for Bdd<T> {
         let literal_set = Self::make_inner_variable_set(literals.clone())?;
 
         let valuations = value
-            .domain()
+            .support()
             .map(|point| {
                 point
                     .into_iter()
@@ -63,4 +63,17 @@ mod tests {
 
         assert!(actual.is_equivalent(&expected));
     }
+
+    #[test]
+    fn test_bdd_from_table_not_tautology_ok() {
+        let source = Expression::from_str("(a & !b) | (b & c)").unwrap();
+        let expected = Bdd::try_from(source.clone()).unwrap();
+
+        let actual = Bdd::try_from(TruthTable::from(source)).unwrap();
+        assert!(actual.is_equivalent(&expected));
+
+        let contradiction = TruthTable::new(Vec::<String>::new(), vec![false]);
+        let actual = Bdd::try_from(contradiction).unwrap();
+        assert!(actual.is_equivalent(&Bdd::mk_const(false)));
+    }
 }
@@ -0,0 +1,84 @@
+use crate::bdd::Bdd;
+use crate::zdd::{fold_nodes, Zdd};
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddVariable, BddVariableSet};
+use std::fmt::Debug;
+use std::num::TryFromIntError;
+
+impl<T: Debug + Clone + Ord> TryFrom<Zdd<T>> for Bdd<T> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Zdd<T>) -> Result<Self, Self::Error> {
+        let inputs = value.inputs_slice().to_vec();
+        let variables = BddVariableSet::new_anonymous(u16::try_from(inputs.len())?);
+        let variable_count = inputs.len();
+
+        // The BDD of a ZDD edge from `level` to a node at `target_level` with the given `bdd`:
+        // the skipped variables must be false.
+        let edge = |bdd: &InnerBdd, level: usize, target_level: usize| {
+            (level..target_level)
+                .rev()
+                .fold(bdd.clone(), |bdd, skipped| {
+                    variables
+                        .mk_literal(BddVariable::from_index(skipped), false)
+                        .and(&bdd)
+                })
+        };
+
+        let nodes = value.nodes();
+        let (_, root) = fold_nodes(
+            nodes,
+            value.root(),
+            (variable_count, variables.mk_false()),
+            (variable_count, variables.mk_true()),
+            |node, (low_level, low), (high_level, high)| {
+                let low = edge(low, node.var + 1, *low_level);
+                let high = edge(high, node.var + 1, *high_level);
+                let var = variables.mk_var(BddVariable::from_index(node.var));
+                (node.var, InnerBdd::if_then_else(&var, &high, &low))
+            },
+        );
+        let root_level = match value.root() {
+            0 | 1 => variable_count,
+            root => nodes[root].var,
+        };
+
+        Ok(Bdd::new(edge(&root, 0, root_level), inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::zdd::Zdd;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_bdd_from_zdd_ok() {
+        let sets = [vec!["a", "c"], vec!["b"], vec![]]
+            .map(|set| set.into_iter().map(String::from).collect::<BTreeSet<_>>());
+        let zdd = Zdd::from_sets(sets);
+
+        let actual = Bdd::try_from(zdd).unwrap();
+        let expected = Bdd::try_from(
+            (var("a") & !var("b") & var("c"))
+                | (!var("a") & var("b") & !var("c"))
+                | (!var("a") & !var("b") & !var("c")),
+        )
+        .unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_bdd_from_zdd_constants_ok() {
+        assert_eq!(
+            Bdd::try_from(Zdd::<String>::mk_const(true)).unwrap(),
+            Bdd::mk_const(true)
+        );
+        assert_eq!(
+            Bdd::try_from(Zdd::mk_literal("a".to_string(), false)).unwrap(),
+            Bdd::mk_literal("a".to_string(), false)
+        );
+    }
+}
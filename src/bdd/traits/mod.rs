@@ -1,6 +1,13 @@
 mod bit;
 mod boolean_function;
+mod canalization;
 mod evaluate;
-mod from_expression;
+mod fourier_measures;
+pub(crate) mod from_expression;
 mod from_table;
+mod from_zdd;
 mod gather_literals;
+mod monotonicity;
+mod sample_support;
+mod symmetry;
+mod weighted_count;
@@ -0,0 +1,187 @@
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::traits::monotonicity::positive_terms_to_expression;
+use crate::traits::{Monotonicity, RegulationSign};
+use biodivine_lib_bdd::{BddVariable, BddVariableSet};
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> Monotonicity<T> for Bdd<T> {
+    fn regulation_sign(&self, variable: &T) -> RegulationSign {
+        let Some(variable) = self.map_var_outer_to_inner(variable) else {
+            return RegulationSign::NonObservable;
+        };
+
+        let low = self.bdd.var_restrict(variable, false);
+        let high = self.bdd.var_restrict(variable, true);
+        RegulationSign::from_effects(
+            !high.and_not(&low).is_false(),
+            !low.and_not(&high).is_false(),
+        )
+    }
+
+    fn upper_monotone_approximation(&self) -> Self {
+        // Adding `F[v = 0]` makes the function positive unate in `v` while keeping it
+        // unate in the already processed variables.
+        let bdd = self
+            .inner_variables()
+            .fold(self.bdd.clone(), |result, variable| {
+                result.or(&result.var_restrict(variable, false))
+            });
+        Bdd::new(bdd, self.inputs.clone())
+    }
+
+    fn lower_monotone_approximation(&self) -> Self {
+        let bdd = self
+            .inner_variables()
+            .fold(self.bdd.clone(), |result, variable| {
+                result.and(&result.var_restrict(variable, true))
+            });
+        Bdd::new(bdd, self.inputs.clone())
+    }
+
+    fn minimal_positive_dnf(&self) -> Option<Expression<T>> {
+        if !self.is_monotone() {
+            return None;
+        }
+
+        // The minimal points are those satisfying points which are no longer satisfying
+        // once any of their `true` variables is set to `false`.
+        let variables = BddVariableSet::new_anonymous(self.bdd.num_vars());
+        let minimal = self
+            .inner_variables()
+            .fold(self.bdd.clone(), |result, variable| {
+                let is_not_minimal = variables
+                    .mk_var(variable)
+                    .and(&self.bdd.var_restrict(variable, false));
+                result.and_not(&is_not_minimal)
+            });
+
+        let terms = minimal
+            .sat_valuations()
+            .map(|valuation| {
+                self.inner_variables()
+                    .filter(|variable| valuation[*variable])
+                    .map(|variable| self.inputs[variable.to_index()].clone())
+                    .collect::<BTreeSet<_>>()
+            })
+            .collect();
+        Some(positive_terms_to_expression(terms))
+    }
+}
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// The `lib_bdd` variables of all inputs, in the variable order.
+    fn inner_variables(&self) -> impl Iterator<Item = BddVariable> {
+        (0..self.inputs.len()).map(BddVariable::from_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::{bool, var};
+    use crate::traits::{BooleanFunction, Monotonicity, RegulationSign};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_regulation_sign_ok() {
+        let function = Bdd::try_from((var("a") & !var("b")) | (var("c") ^ var("d"))).unwrap();
+
+        assert_eq!(
+            function.regulation_sign(&"a".to_string()),
+            RegulationSign::Activating
+        );
+        assert_eq!(
+            function.regulation_sign(&"b".to_string()),
+            RegulationSign::Inhibiting
+        );
+        assert_eq!(
+            function.regulation_sign(&"c".to_string()),
+            RegulationSign::Dual
+        );
+        assert_eq!(
+            function.regulation_sign(&"x".to_string()),
+            RegulationSign::NonObservable
+        );
+    }
+
+    #[test]
+    fn test_regulation_signs_non_observable_ok() {
+        // `b` is an input, but it has no effect on the output.
+        let function = Bdd::try_from(var("a") | (var("b") & bool(false))).unwrap();
+
+        assert_eq!(
+            function.regulation_signs(),
+            BTreeMap::from([
+                ("a".to_string(), RegulationSign::Activating),
+                ("b".to_string(), RegulationSign::NonObservable),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_is_monotone_ok() {
+        let function = Bdd::try_from(var("a") & (var("b") | !var("c"))).unwrap();
+
+        assert!(!function.is_monotone());
+        assert!(function.is_positive_unate_in(&"a".to_string()));
+        assert!(function.is_negative_unate_in(&"c".to_string()));
+        assert!(!function.is_negative_unate_in(&"b".to_string()));
+        assert!(Bdd::try_from(var("a") & (var("b") | var("c")))
+            .unwrap()
+            .is_monotone());
+        assert!(Bdd::<String>::mk_const(false).is_monotone());
+    }
+
+    #[test]
+    fn test_monotone_approximations_ok() {
+        let function = Bdd::try_from(var("a") ^ var("b")).unwrap();
+
+        let upper = function.upper_monotone_approximation();
+        let lower = function.lower_monotone_approximation();
+        assert!(upper.is_equivalent(&Bdd::try_from(var("a") | var("b")).unwrap()));
+        assert!(lower.is_equivalent(&Bdd::mk_const(false)));
+        assert_eq!(upper.inputs(), function.inputs());
+        assert_eq!(function.monotone_closure(), upper);
+
+        // The function is satisfied by the all-`false` point, hence its closure is a tautology.
+        let function = Bdd::try_from(!var("a") | (var("b") & var("c"))).unwrap();
+        assert!(function
+            .upper_monotone_approximation()
+            .is_equivalent(&Bdd::mk_const(true)));
+        assert!(function
+            .lower_monotone_approximation()
+            .is_equivalent(&Bdd::try_from(var("b") & var("c")).unwrap()));
+
+        let monotone = Bdd::try_from(var("a") & (var("b") | var("c"))).unwrap();
+        assert_eq!(monotone.upper_monotone_approximation(), monotone);
+        assert_eq!(monotone.lower_monotone_approximation(), monotone);
+    }
+
+    #[test]
+    fn test_minimal_positive_dnf_ok() {
+        let function =
+            Bdd::try_from(var("a") & (var("b") | var("c")) | (var("a") & var("b") & var("d")))
+                .unwrap();
+
+        assert_eq!(
+            function.minimal_positive_dnf(),
+            Some((var("a") & var("b")) | (var("a") & var("c")))
+        );
+        assert_eq!(
+            Bdd::try_from(var("a") ^ var("b"))
+                .unwrap()
+                .minimal_positive_dnf(),
+            None
+        );
+        assert_eq!(
+            Bdd::<String>::mk_const(true).minimal_positive_dnf(),
+            Some(bool(true))
+        );
+        assert_eq!(
+            Bdd::<String>::mk_const(false).minimal_positive_dnf(),
+            Some(bool(false))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::bdd::iterators::support::unpermute_point;
+use crate::bdd::Bdd;
+use crate::traits::{BooleanPoint, BooleanValuation, SampleSupport};
+use crate::utils::random_below;
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddPointer};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> SampleSupport<T> for Bdd<T> {
+    fn sample_support_restricted(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: &BooleanValuation<T>,
+    ) -> Vec<BooleanPoint> {
+        let selection = restriction
+            .iter()
+            .filter_map(|(var, value)| self.map_var_outer_to_inner(var).map(|it| (it, *value)))
+            .collect::<Vec<_>>();
+        let bdd = self.bdd.select(&selection);
+        if bdd.is_false() {
+            return Vec::new();
+        }
+
+        let cardinalities = node_cardinalities(&bdd);
+        let root = bdd.root_pointer();
+        let total = &cardinalities[root.to_index()] << bdd.var_of(root).to_index();
+        let sorted_positions = self.sorted_positions();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| {
+                let point = unrank_point(&bdd, &cardinalities, random_below(&mut rng, &total));
+                match &sorted_positions {
+                    None => point,
+                    Some(positions) => unpermute_point(&point, positions),
+                }
+            })
+            .collect()
+    }
+}
+
+/// For every node, the number of satisfying valuations of the variables from the variable
+/// of the node to the last variable.
+fn node_cardinalities(bdd: &InnerBdd) -> Vec<BigUint> {
+    let mut result = vec![BigUint::zero(), BigUint::one()];
+    // The nodes of a `lib-bdd` BDD are sorted such that the children precede their parents.
+    for index in 2..bdd.size() {
+        let node = BddPointer::from_index(index);
+        let var = bdd.var_of(node).to_index();
+        let weight = |child: BddPointer| {
+            &result[child.to_index()] << (bdd.var_of(child).to_index() - var - 1)
+        };
+        let cardinality = weight(bdd.low_link_of(node)) + weight(bdd.high_link_of(node));
+        result.push(cardinality);
+    }
+    result
+}
+
+/// Returns the satisfying valuation of `bdd` with the given `rank`, where all valuations
+/// are numbered by a bijection onto `[0, cardinality)`. The values of the variables skipped
+/// by an edge are taken from the lowest bits of the rank.
+fn unrank_point(bdd: &InnerBdd, cardinalities: &[BigUint], mut rank: BigUint) -> Vec<bool> {
+    let mut point = vec![false; usize::from(bdd.num_vars())];
+    let mut level = 0;
+    let mut node = bdd.root_pointer();
+    loop {
+        let var = bdd.var_of(node).to_index();
+        for value in point.iter_mut().take(var).skip(level) {
+            *value = rank.bit(0);
+            rank >>= 1;
+        }
+        if node.is_terminal() {
+            return point;
+        }
+
+        let low = bdd.low_link_of(node);
+        let low_weight = &cardinalities[low.to_index()] << (bdd.var_of(low).to_index() - var - 1);
+        if rank < low_weight {
+            node = low;
+        } else {
+            point[var] = true;
+            rank -= low_weight;
+            node = bdd.high_link_of(node);
+        }
+        level = var + 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::traits::{BooleanFunction, SampleSupport};
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_sample_support_uniform_ok() {
+        // The support is unevenly spread over the BDD paths: `a` covers 8 points.
+        let expression = var("a") | (var("b") & var("c") & var("d"));
+        let bdd = Bdd::try_from(expression).unwrap();
+        let support = bdd.support().collect::<Vec<_>>();
+
+        let samples = bdd.sample_support(9000, 11);
+
+        let mut counts = HashMap::new();
+        for point in samples {
+            assert!(support.contains(&point));
+            *counts.entry(point).or_insert(0) += 1;
+        }
+        // Each of the 9 points is expected 1000 times.
+        assert_eq!(counts.len(), 9);
+        assert!(counts.values().all(|count| (850..1150).contains(count)));
+    }
+
+    #[test]
+    fn test_sample_support_reordered_ok() {
+        let expression = (var("a") & !var("c")) | (var("b") & var("d"));
+        let bdd = Bdd::try_from(expression).unwrap();
+        let reordered = bdd
+            .reorder(&["d", "a", "c", "b"].map(String::from))
+            .unwrap();
+
+        for point in reordered.sample_support(100, 5) {
+            assert!(bdd.support().any(|it| it == point));
+        }
+    }
+
+    #[test]
+    fn test_sample_support_restricted_ok() {
+        let bdd = Bdd::try_from((var("a") & var("b")) | !var("c")).unwrap();
+        let restriction = BTreeMap::from([("c".to_string(), true), ("x".to_string(), false)]);
+
+        let samples = bdd.sample_support_restricted(10, 1, &restriction);
+
+        assert_eq!(samples, vec![vec![true, true, true]; 10]);
+        assert_eq!(bdd.sample_support(10, 3), bdd.sample_support(10, 3));
+        assert!(Bdd::<String>::mk_const(false)
+            .sample_support(10, 3)
+            .is_empty());
+        assert_eq!(
+            Bdd::<String>::mk_const(true).sample_support(2, 3),
+            vec![Vec::<bool>::new(); 2]
+        );
+    }
+}
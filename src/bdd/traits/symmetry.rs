@@ -0,0 +1,90 @@
+use crate::bdd::Bdd;
+use crate::traits::Symmetry;
+use biodivine_lib_bdd::Bdd as InnerBdd;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> Symmetry<T> for Bdd<T> {
+    fn is_symmetric_in(&self, first: &T, second: &T) -> bool {
+        // Both cofactors would restrict the same variable twice, which is not a swap.
+        first == second
+            || self.cofactor(first, false, second, true)
+                == self.cofactor(first, true, second, false)
+    }
+
+    fn is_negation_symmetric_in(&self, first: &T, second: &T) -> bool {
+        self.cofactor(first, false, second, false) == self.cofactor(first, true, second, true)
+    }
+}
+
+impl<T: Debug + Clone + Ord> Bdd<T> {
+    /// The function restricted to `first = first_value` and `second = second_value`.
+    /// Variables which are not inputs of this function are ignored.
+    fn cofactor(&self, first: &T, first_value: bool, second: &T, second_value: bool) -> InnerBdd {
+        [(first, first_value), (second, second_value)]
+            .into_iter()
+            .filter_map(|(variable, value)| Some((self.map_var_outer_to_inner(variable)?, value)))
+            .fold(self.bdd.clone(), |result, (variable, value)| {
+                result.var_restrict(variable, value)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::traits::Symmetry;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn set(variables: &[&str]) -> BTreeSet<String> {
+        variables.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_symmetry_classes_ok() {
+        let function =
+            Bdd::try_from((var("a") & var("b")) | (var("c") ^ var("d")) | var("e")).unwrap();
+
+        assert!(function.is_symmetric_in(&"a".to_string(), &"b".to_string()));
+        assert!(!function.is_symmetric_in(&"a".to_string(), &"c".to_string()));
+        assert_eq!(
+            function.symmetry_classes(),
+            vec![set(&["a", "b"]), set(&["c", "d"]), set(&["e"])]
+        );
+        assert!(!function.is_totally_symmetric());
+
+        let majority = (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c"));
+        assert!(Bdd::try_from(majority).unwrap().is_totally_symmetric());
+    }
+
+    #[test]
+    fn test_negation_symmetry_classes_ok() {
+        let function = Bdd::try_from((var("a") & !var("b") & var("c")) | var("d")).unwrap();
+
+        assert!(function.is_negation_symmetric_in(&"a".to_string(), &"b".to_string()));
+        assert!(!function.is_symmetric_in(&"a".to_string(), &"b".to_string()));
+        assert_eq!(
+            function.negation_symmetry_classes(),
+            vec![
+                BTreeMap::from([
+                    ("a".to_string(), false),
+                    ("b".to_string(), true),
+                    ("c".to_string(), false),
+                ]),
+                BTreeMap::from([("d".to_string(), false)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_symmetry_with_itself_and_unknown_variables() {
+        let function = Bdd::try_from(var("a") & (var("b") | !var("b"))).unwrap();
+        let [a, b, x] = ["a", "b", "x"].map(String::from);
+
+        assert!(function.is_symmetric_in(&a, &a));
+        assert!(!function.is_negation_symmetric_in(&a, &a));
+        assert!(function.is_negation_symmetric_in(&b, &b));
+        assert!(function.is_symmetric_in(&b, &x));
+        assert!(!function.is_symmetric_in(&a, &x));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::bdd::utils::bdd_weighted_count;
+use crate::bdd::Bdd;
+use crate::traits::{Semiring, WeightedCount};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> WeightedCount<T> for Bdd<T> {
+    fn weighted_count<S: Semiring, F: Fn(&T, bool) -> S>(&self, weight: F) -> S {
+        // The `inputs` follow the variable order, i.e. the indices of `BddVariable` objects.
+        let weights = self
+            .inputs
+            .iter()
+            .map(|input| (weight(input, false), weight(input, true)))
+            .collect::<Vec<_>>();
+        bdd_weighted_count(&self.bdd, &weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::traits::{BooleanFunction, WeightedCount};
+    use num_bigint::BigUint;
+    use num_rational::BigRational;
+    use std::collections::BTreeMap;
+
+    fn ratio(numerator: i32, denominator: i32) -> BigRational {
+        BigRational::new(numerator.into(), denominator.into())
+    }
+
+    #[test]
+    fn test_probability_ok() {
+        let bdd = Bdd::try_from(var("a") & (var("b") | var("c"))).unwrap();
+        let probabilities = BTreeMap::from([
+            ("a".to_string(), ratio(1, 2)),
+            ("b".to_string(), ratio(1, 3)),
+            ("c".to_string(), ratio(1, 4)),
+        ]);
+
+        assert_eq!(bdd.probability(&probabilities), ratio(1, 4));
+
+        let floats = BTreeMap::from([("a".to_string(), 0.5), ("b".to_string(), 0.25)]);
+        // The probability of `c` defaults to 1/2: 1/2 * (1 - 3/4 * 1/2)
+        assert!((bdd.probability(&floats) - 0.3125).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_probability_reordered_ok() {
+        let bdd = Bdd::try_from(var("a") & !var("b") | var("c")).unwrap();
+        let reordered = bdd.reorder(&["c", "b", "a"].map(String::from)).unwrap();
+        let probabilities = BTreeMap::from([
+            ("a".to_string(), ratio(1, 5)),
+            ("b".to_string(), ratio(2, 3)),
+            ("c".to_string(), ratio(1, 7)),
+        ]);
+
+        assert_eq!(
+            reordered.probability(&probabilities),
+            bdd.probability(&probabilities)
+        );
+    }
+
+    #[test]
+    fn test_weighted_count_ok() {
+        let bdd = Bdd::try_from(var("a") | var("b")).unwrap();
+
+        let count = bdd.weighted_count(|_, _| BigUint::from(1u8));
+        assert_eq!(count, bdd.weight());
+
+        // Weight 2 for `true` literals: 2 * 1 + 1 * 2 + 2 * 2
+        let count = bdd.weighted_count(|_, value| BigUint::from(if value { 2u8 } else { 1u8 }));
+        assert_eq!(count, BigUint::from(8u8));
+
+        assert!(bdd.weighted_count(|_, _| true));
+        assert!(!Bdd::<String>::mk_const(false).weighted_count(|_, _| true));
+    }
+}
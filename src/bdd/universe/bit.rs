@@ -0,0 +1,70 @@
+use std::fmt::Debug;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use biodivine_lib_bdd::Bdd as InnerBdd;
+
+use crate::bdd::universe::UniverseBdd;
+
+impl<T: Debug + Clone + Ord> BitAnd for &UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, InnerBdd::and)
+    }
+}
+
+impl<T: Debug + Clone + Ord> BitAnd for UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.apply(&rhs, InnerBdd::and)
+    }
+}
+
+impl<T: Debug + Clone + Ord> BitOr for &UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, InnerBdd::or)
+    }
+}
+
+impl<T: Debug + Clone + Ord> BitOr for UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.apply(&rhs, InnerBdd::or)
+    }
+}
+
+impl<T: Debug + Clone + Ord> BitXor for &UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, InnerBdd::xor)
+    }
+}
+
+impl<T: Debug + Clone + Ord> BitXor for UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.apply(&rhs, InnerBdd::xor)
+    }
+}
+
+impl<T: Debug + Clone + Ord> Not for &UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn not(self) -> Self::Output {
+        self.universe.wrap(self.bdd.not())
+    }
+}
+
+impl<T: Debug + Clone + Ord> Not for UniverseBdd<T> {
+    type Output = UniverseBdd<T>;
+
+    fn not(self) -> Self::Output {
+        (&self).not()
+    }
+}
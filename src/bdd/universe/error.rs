@@ -0,0 +1,24 @@
+use std::num::TryFromIntError;
+
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BddUniverseError {
+    #[error("A universe can contain at most {} variables", u16::MAX)]
+    TooManyVariables(#[from] TryFromIntError),
+    #[error("Variable {name} is not part of the universe")]
+    UnknownVariable { name: String },
+}
+
+#[cfg(feature = "python")]
+impl From<BddUniverseError> for PyErr {
+    fn from(err: BddUniverseError) -> PyErr {
+        use pyo3::exceptions::{PyKeyError, PyValueError};
+
+        match err {
+            e @ BddUniverseError::TooManyVariables(_) => PyValueError::new_err(e.to_string()),
+            e @ BddUniverseError::UnknownVariable { .. } => PyKeyError::new_err(e.to_string()),
+        }
+    }
+}
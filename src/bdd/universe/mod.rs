@@ -0,0 +1,403 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use biodivine_lib_bdd::{op_function, Bdd as InnerBdd, BddVariable, BddVariableSet};
+
+use crate::bdd::universe::error::BddUniverseError;
+use crate::bdd::utils::{compose_bdd_variables, prune_bdd_variables};
+use crate::bdd::Bdd;
+
+mod bit;
+pub mod error;
+
+/// A fixed, sorted set of variables shared by a family of [UniverseBdd] objects.
+///
+/// Free-standing [Bdd] objects only track the variables they use, hence every binary
+/// operation first has to merge the inputs of both operands and extend both BDDs to the
+/// merged variable set. BDDs created in the same universe always use all the universe
+/// variables, such that they can be combined directly without any remapping.
+///
+/// Cloning a universe is cheap and the clone is the same universe.
+#[derive(Debug, Clone)]
+pub struct BddUniverse<T: Debug + Clone + Ord> {
+    data: Arc<UniverseData<T>>,
+}
+
+#[derive(Debug)]
+struct UniverseData<T> {
+    /// Always-sorted vector of unique variables.
+    variables: Vec<T>,
+    variable_set: BddVariableSet,
+}
+
+/// A BDD over all variables of a [BddUniverse].
+///
+/// The operators `&`, `|`, `^` and `!` are supported, but both operands of a binary operator
+/// must belong to the same universe (see [UniverseBdd::same_universe]), otherwise the
+/// operation panics.
+#[derive(Debug, Clone)]
+pub struct UniverseBdd<T: Debug + Clone + Ord> {
+    universe: BddUniverse<T>,
+    bdd: InnerBdd,
+}
+
+impl<T: Debug + Clone + Ord> BddUniverse<T> {
+    /// Creates a universe of the given variables. Duplicate variables are ignored.
+    ///
+    /// Same as [Bdd], a universe supports at most 2<sup>16</sup> variables.
+    pub fn new<I: IntoIterator<Item = T>>(variables: I) -> Result<Self, BddUniverseError> {
+        let mut variables = variables.into_iter().collect::<Vec<_>>();
+        variables.sort();
+        variables.dedup();
+        let variable_set = BddVariableSet::new_anonymous(u16::try_from(variables.len())?);
+
+        Ok(BddUniverse {
+            data: Arc::new(UniverseData {
+                variables,
+                variable_set,
+            }),
+        })
+    }
+
+    /// The sorted variables of this universe.
+    pub fn variables(&self) -> &[T] {
+        &self.data.variables
+    }
+
+    /// Two universes are the same if they are clones of each other or if they contain
+    /// the same variables.
+    pub fn is_same(&self, other: &BddUniverse<T>) -> bool {
+        Arc::ptr_eq(&self.data, &other.data) || self.data.variables == other.data.variables
+    }
+
+    pub fn mk_const(&self, value: bool) -> UniverseBdd<T> {
+        if value {
+            self.wrap(self.data.variable_set.mk_true())
+        } else {
+            self.wrap(self.data.variable_set.mk_false())
+        }
+    }
+
+    pub fn mk_literal(
+        &self,
+        variable: &T,
+        value: bool,
+    ) -> Result<UniverseBdd<T>, BddUniverseError> {
+        let variable = self.map_var_outer_to_inner(variable)?;
+        Ok(self.wrap(self.data.variable_set.mk_literal(variable, value)))
+    }
+
+    /// Moves a free-standing [Bdd] into this universe.
+    ///
+    /// Fails if the `bdd` uses variables which are not part of this universe.
+    pub fn import(&self, bdd: &Bdd<T>) -> Result<UniverseBdd<T>, BddUniverseError> {
+        for input in &bdd.inputs {
+            self.map_var_outer_to_inner(input)?;
+        }
+
+        Ok(self.wrap(bdd.lift_to(self.variables()).bdd))
+    }
+
+    fn wrap(&self, bdd: InnerBdd) -> UniverseBdd<T> {
+        UniverseBdd {
+            universe: self.clone(),
+            bdd,
+        }
+    }
+
+    fn map_var_outer_to_inner(&self, variable: &T) -> Result<BddVariable, BddUniverseError> {
+        self.data
+            .variables
+            .binary_search(variable)
+            .map(BddVariable::from_index)
+            .map_err(|_| BddUniverseError::UnknownVariable {
+                name: format!("{variable:?}"),
+            })
+    }
+}
+
+impl<T: Debug + Clone + Ord> UniverseBdd<T> {
+    pub fn universe(&self) -> &BddUniverse<T> {
+        &self.universe
+    }
+
+    pub fn same_universe(&self, other: &UniverseBdd<T>) -> bool {
+        self.universe.is_same(&other.universe)
+    }
+
+    pub fn inner(&self) -> &InnerBdd {
+        &self.bdd
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.bdd.size()
+    }
+
+    pub fn is_true(&self) -> bool {
+        self.bdd.is_true()
+    }
+
+    pub fn is_false(&self) -> bool {
+        self.bdd.is_false()
+    }
+
+    /// Converts this BDD into a free-standing [Bdd].
+    ///
+    /// The inputs of the result are only the variables on which this function depends
+    /// (see [crate::traits::BooleanFunction::essential_inputs]), not the whole universe.
+    pub fn to_bdd(&self) -> Bdd<T> {
+        let mut support = self
+            .bdd
+            .support_set()
+            .into_iter()
+            .map(|variable| variable.to_index())
+            .collect::<Vec<_>>();
+        support.sort();
+        let inputs = support
+            .into_iter()
+            .map(|index| self.universe.variables()[index].clone())
+            .collect::<Vec<_>>();
+
+        self.to_bdd_with_inputs(&inputs)
+    }
+
+    /// Converts this BDD into a free-standing [Bdd] with the given `inputs`, which must be
+    /// a sorted subset of the universe variables that includes all variables on which
+    /// this function depends.
+    pub(crate) fn to_bdd_with_inputs(&self, inputs: &[T]) -> Bdd<T> {
+        let full = Bdd::new(self.bdd.clone(), self.universe.variables().to_vec());
+        prune_bdd_variables(&full, inputs)
+    }
+
+    /// Computes `∃ variables: self & other` without building the conjunction first
+    /// (see [Bdd::relational_product]).
+    ///
+    /// Panics if the operands belong to different universes. Fails if some of
+    /// the `variables` are not part of the universe.
+    pub fn relational_product(
+        &self,
+        other: &UniverseBdd<T>,
+        variables: &BTreeSet<T>,
+    ) -> Result<UniverseBdd<T>, BddUniverseError> {
+        let variables = variables
+            .iter()
+            .map(|it| self.universe.map_var_outer_to_inner(it))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.apply(other, |left, right| {
+            InnerBdd::binary_op_with_exists(left, right, op_function::and, &variables)
+        }))
+    }
+
+    /// Simultaneously renames the variables of this BDD according to `mapping`.
+    ///
+    /// If a variable is renamed to a variable on which this function depends (and which
+    /// is not renamed itself), both are merged into one. Fails if some of the variables
+    /// in `mapping` are not part of the universe.
+    pub fn rename_variables(
+        &self,
+        mapping: &BTreeMap<T, T>,
+    ) -> Result<UniverseBdd<T>, BddUniverseError> {
+        let mapping = mapping
+            .iter()
+            .map(|(from, to)| {
+                Ok((
+                    self.universe.map_var_outer_to_inner(from)?,
+                    self.universe.map_var_outer_to_inner(to)?,
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, BddUniverseError>>()?;
+
+        // If the renamed variables do not collide with the support, and the renaming
+        // preserves their order, only the variables of the BDD nodes need to be changed.
+        let mut support = self.bdd.support_set().into_iter().collect::<Vec<_>>();
+        support.sort();
+        let renamed = support
+            .iter()
+            .map(|it| *mapping.get(it).unwrap_or(it))
+            .collect::<Vec<_>>();
+        let is_collision_free = mapping
+            .values()
+            .all(|it| !support.contains(it) || mapping.contains_key(it));
+        if is_collision_free && renamed.windows(2).all(|pair| pair[0] < pair[1]) {
+            let mut bdd = self.bdd.clone();
+            unsafe {
+                // The renaming is safe, since it preserves the order of the variables.
+                bdd.rename_variables(&mapping);
+            }
+            return Ok(self.universe.wrap(bdd));
+        }
+
+        let variables = &self.universe.data.variable_set;
+        let replacements = (0..self.universe.variables().len())
+            .map(BddVariable::from_index)
+            .map(|it| variables.mk_var(*mapping.get(&it).unwrap_or(&it)))
+            .collect::<Vec<_>>();
+        let bdd = compose_bdd_variables(&self.bdd, variables, &replacements);
+        Ok(self.universe.wrap(bdd))
+    }
+
+    fn apply<F: Fn(&InnerBdd, &InnerBdd) -> InnerBdd>(
+        &self,
+        other: &UniverseBdd<T>,
+        op: F,
+    ) -> UniverseBdd<T> {
+        assert!(
+            self.same_universe(other),
+            "Cannot combine BDDs from different universes."
+        );
+
+        self.universe.wrap(op(&self.bdd, &other.bdd))
+    }
+}
+
+/// Two BDDs are equal if they belong to the same universe and represent the same function.
+impl<T: Debug + Clone + Ord> PartialEq for UniverseBdd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.same_universe(other) && self.bdd == other.bdd
+    }
+}
+
+impl<T: Debug + Clone + Ord> Eq for UniverseBdd<T> {}
+
+impl<T: Debug + Clone + Ord> From<UniverseBdd<T>> for Bdd<T> {
+    fn from(value: UniverseBdd<T>) -> Self {
+        value.to_bdd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use crate::bdd::universe::error::BddUniverseError;
+    use crate::bdd::universe::BddUniverse;
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::traits::BooleanFunction;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_universe_operations_ok() {
+        let universe = BddUniverse::new(names(&["c", "a", "b", "a"])).unwrap();
+        assert_eq!(universe.variables(), names(&["a", "b", "c"]));
+
+        let a = universe.mk_literal(&"a".to_string(), true).unwrap();
+        let b = universe.mk_literal(&"b".to_string(), true).unwrap();
+        let c = universe.mk_literal(&"c".to_string(), false).unwrap();
+
+        let actual = (&a & &b) | !&c ^ universe.mk_const(false);
+        let expected = Bdd::try_from((var("a") & var("b")) | var("c")).unwrap();
+
+        assert!(actual.to_bdd().is_equivalent(&expected));
+        assert_eq!(actual.to_bdd().inputs(), expected.inputs());
+        assert!((&a | &!&a).is_true());
+        assert!((&a & &!&a).is_false());
+    }
+
+    #[test]
+    fn test_universe_import_export_ok() {
+        let universe = BddUniverse::new(names(&["a", "b", "c", "d"])).unwrap();
+        let bdd = Bdd::try_from(var("b") ^ var("d")).unwrap();
+
+        let imported = universe.import(&bdd).unwrap();
+        assert_eq!(imported.inner().num_vars(), 4);
+        assert_eq!(imported.to_bdd(), bdd);
+
+        // Non-essential inputs are dropped on export.
+        let tautology = universe
+            .import(&Bdd::try_from(var("a") | !var("a")).unwrap())
+            .unwrap();
+        assert_eq!(Bdd::from(tautology), Bdd::mk_const(true));
+    }
+
+    #[test]
+    fn test_universe_unknown_variable_nok() {
+        let universe = BddUniverse::new(names(&["a", "b"])).unwrap();
+
+        assert_eq!(
+            universe.mk_literal(&"c".to_string(), true),
+            Err(BddUniverseError::UnknownVariable {
+                name: "\"c\"".to_string()
+            })
+        );
+        assert_eq!(
+            universe.import(&Bdd::try_from(var("a") & var("c")).unwrap()),
+            Err(BddUniverseError::UnknownVariable {
+                name: "\"c\"".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_same_universe_ok() {
+        let universe = BddUniverse::new(names(&["a", "b"])).unwrap();
+        let equal = BddUniverse::new(names(&["b", "a"])).unwrap();
+        let other = BddUniverse::new(names(&["a", "b", "c"])).unwrap();
+
+        let a = universe.mk_literal(&"a".to_string(), true).unwrap();
+        assert!(a.same_universe(&equal.mk_const(true)));
+        assert!(!a.same_universe(&other.mk_const(true)));
+        assert_eq!(&a & &equal.mk_const(true), a);
+    }
+
+    #[test]
+    fn test_relational_product_ok() {
+        let universe = BddUniverse::new(names(&["a", "b", "c"])).unwrap();
+        let left = universe
+            .import(&Bdd::try_from(var("a") & var("b")).unwrap())
+            .unwrap();
+        let right = universe
+            .import(&Bdd::try_from(var("b") ^ var("c")).unwrap())
+            .unwrap();
+
+        let actual = left
+            .relational_product(&right, &BTreeSet::from(["b".to_string()]))
+            .unwrap();
+        let expected = Bdd::try_from(var("a") & !var("c")).unwrap();
+        assert!(actual.to_bdd().is_equivalent(&expected));
+
+        assert_eq!(
+            left.relational_product(&right, &BTreeSet::from(["x".to_string()])),
+            Err(BddUniverseError::UnknownVariable {
+                name: "\"x\"".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_rename_variables_ok() {
+        let universe = BddUniverse::new(names(&["a", "b", "c", "d"])).unwrap();
+        let bdd = universe
+            .import(&Bdd::try_from(var("a") & !var("c")).unwrap())
+            .unwrap();
+        let rename = |pairs: &[(&str, &str)]| {
+            let mapping = pairs
+                .iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect::<BTreeMap<_, _>>();
+            bdd.rename_variables(&mapping).unwrap().to_bdd()
+        };
+
+        // Order-preserving renaming.
+        let expected = Bdd::try_from(var("b") & !var("d")).unwrap();
+        assert_eq!(rename(&[("a", "b"), ("c", "d")]), expected);
+        // Swapping variables.
+        let expected = Bdd::try_from(var("c") & !var("a")).unwrap();
+        assert!(rename(&[("a", "c"), ("c", "a")]).is_equivalent(&expected));
+        // Merging variables.
+        assert!(rename(&[("a", "c")]).inner().is_false());
+    }
+
+    #[test]
+    #[should_panic(expected = "different universes")]
+    fn test_different_universes_nok() {
+        let universe = BddUniverse::new(names(&["a", "b"])).unwrap();
+        let other = BddUniverse::new(names(&["a", "b", "c"])).unwrap();
+
+        let _ = universe.mk_const(true) & other.mk_const(true);
+    }
+}
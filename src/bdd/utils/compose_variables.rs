@@ -0,0 +1,54 @@
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddPointer, BddVariableSet};
+
+/// Simultaneously replaces every variable `v` of `bdd` with the function `replacements[v]`.
+///
+/// All `replacements` (and the result) use the `variables` set, which must have at least
+/// as many variables as `bdd`. Each node of `bdd` is rebuilt bottom-up as
+/// `ite(replacements[var], high, low)`. Because the replacements are only ever applied to
+/// the nodes of the original `bdd`, a replacement can safely depend on any variable,
+/// including the one it replaces.
+pub fn compose_bdd_variables(
+    bdd: &InnerBdd,
+    variables: &BddVariableSet,
+    replacements: &[InnerBdd],
+) -> InnerBdd {
+    if bdd.is_false() {
+        return variables.mk_false();
+    }
+
+    // `lib_bdd` stores nodes in DFS post-order, hence the children of a node are always
+    // rebuilt before the node itself.
+    let mut results = vec![variables.mk_false(), variables.mk_true()];
+    for index in 2..bdd.size() {
+        let pointer = BddPointer::from_index(index);
+        let replacement = &replacements[bdd.var_of(pointer).to_index()];
+        let low = &results[bdd.low_link_of(pointer).to_index()];
+        let high = &results[bdd.high_link_of(pointer).to_index()];
+
+        let node = InnerBdd::if_then_else(replacement, high, low);
+        results.push(node);
+    }
+
+    results.pop().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biodivine_lib_bdd::BddVariableSet;
+
+    #[test]
+    fn test_compose_self_reference_ok() {
+        let variables = BddVariableSet::new(&["x", "y"]);
+        let input = variables.eval_expression_string("x & !y");
+        let replacements = [
+            variables.eval_expression_string("x | y"),
+            variables.eval_expression_string("x"),
+        ];
+
+        let actual = compose_bdd_variables(&input, &variables, &replacements);
+        let expected = variables.eval_expression_string("(x | y) & !x");
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -8,8 +8,8 @@ use std::fmt::Debug;
 /// Here, extends means that the input variables of the new `Bdd` are exactly `new_inputs`, but
 /// the `Bdd` still represents the same function.
 ///
-/// As such, it must hold that `new_inputs` is a superset of `bdd.inputs`. Currently, if this
-/// condition is not satisfied, the method will panic.
+/// As such, it must hold that `new_inputs` is a superset of `bdd.inputs` which also preserves
+/// their variable order. Currently, if this condition is not satisfied, the method will panic.
 pub fn extend_bdd_variables<TLiteral: Debug + Clone + Eq + Ord>(
     bdd: &Bdd<TLiteral>,
     new_inputs: &[TLiteral],
@@ -23,14 +23,16 @@ pub fn extend_bdd_variables<TLiteral: Debug + Clone + Eq + Ord>(
 
     let mut permutation = HashMap::new();
 
-    // Since both vectors are sorted, we can advance through them simultaneously.
-    // Also, since `bdd.inputs` is a subset of `new_inputs`, we know that every
-    // `bdd.inputs[old_i]` must (eventually) appear in the `new_inputs` iterator, we just
-    // need to skip enough of the new variables.
+    // Since both vectors use the same variable order, we can advance through them
+    // simultaneously. Also, since `bdd.inputs` is a subsequence of `new_inputs`, we know
+    // that every `bdd.inputs[old_i]` must (eventually) appear in the `new_inputs` iterator,
+    // we just need to skip enough of the new variables.
+    let mut new_i = 0;
     for (old_i, var) in bdd.inputs.iter().enumerate() {
-        let new_i = new_inputs
-            .binary_search(var)
-            .expect("Collection `new_inputs` is not a superset of `bdd.inputs`.");
+        new_i += new_inputs[new_i..]
+            .iter()
+            .position(|it| it == var)
+            .expect("Collection `new_inputs` is not an order-preserving superset of `bdd.inputs`.");
 
         if new_i != old_i {
             permutation.insert(
@@ -1,5 +1,11 @@
+pub use compose_variables::compose_bdd_variables;
 pub use extend_variables::extend_bdd_variables;
+pub use probability::{bdd_probability, bdd_weighted_count};
 pub use prune_variables::prune_bdd_variables;
+pub use reorder_variables::{is_subsequence, merge_variable_orders, reorder_bdd_variables};
 
+mod compose_variables;
 mod extend_variables;
+mod probability;
 mod prune_variables;
+mod reorder_variables;
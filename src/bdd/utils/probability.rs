@@ -0,0 +1,108 @@
+use crate::traits::{ProbabilityValue, Semiring};
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddPointer};
+use num_rational::BigRational;
+
+/// Computes the probability that a random valuation satisfies the given `lib_bdd::Bdd`, where
+/// each variable is independently `true` with the probability given by `probabilities`
+/// (indexed by `BddVariable::to_index`).
+///
+/// Variables that do not appear in the BDD do not influence the result, hence their
+/// probabilities can be arbitrary.
+pub fn bdd_probability(bdd: &InnerBdd, probabilities: &[BigRational]) -> BigRational {
+    let weights = probabilities
+        .iter()
+        .map(|p_true| (p_true.complement(), p_true.clone()))
+        .collect::<Vec<_>>();
+    bdd_weighted_count(bdd, &weights)
+}
+
+/// Computes the weighted model count of the given `lib_bdd::Bdd`, i.e. the sum over all
+/// satisfying valuations of the product of the variable weights, where `weights[i]` holds
+/// the weights of `false` and `true` for the variable with `BddVariable::to_index` equal to `i`.
+pub fn bdd_weighted_count<S: Semiring>(bdd: &InnerBdd, weights: &[(S, S)]) -> S {
+    if bdd.is_false() {
+        return S::zero();
+    }
+
+    // The weight of all valuations of the variables skipped by an edge from `from` to `to`.
+    let skipped = |from: usize, to: usize| {
+        weights[from..to]
+            .iter()
+            .fold(S::one(), |acc, (w_false, w_true)| {
+                acc.mul(&w_false.add(w_true))
+            })
+    };
+
+    // `lib_bdd` stores nodes in DFS post-order, hence the children of a node are always
+    // evaluated before the node itself.
+    let mut results = vec![S::zero(), S::one()];
+    for index in 2..bdd.size() {
+        let pointer = BddPointer::from_index(index);
+        let var = bdd.var_of(pointer).to_index();
+        let (w_false, w_true) = &weights[var];
+        let edge = |child: BddPointer| {
+            let child_var = bdd.var_of(child).to_index();
+            results[child.to_index()].mul(&skipped(var + 1, child_var))
+        };
+
+        let result = w_false
+            .mul(&edge(bdd.low_link_of(pointer)))
+            .add(&w_true.mul(&edge(bdd.high_link_of(pointer))));
+        results.push(result);
+    }
+
+    let root = bdd.root_pointer();
+    results[root.to_index()].mul(&skipped(0, bdd.var_of(root).to_index()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bdd_probability, bdd_weighted_count};
+    use biodivine_lib_bdd::BddVariableSet;
+    use num_bigint::BigUint;
+    use num_rational::BigRational;
+    use num_traits::{One, Zero};
+
+    #[test]
+    fn test_bdd_probability_ok() {
+        let variables = BddVariableSet::new(&["a", "b", "c"]);
+        let bdd = variables.eval_expression_string("a & (b | c)");
+        let probabilities = [(1, 2), (1, 3), (1, 4)]
+            .map(|(n, d)| BigRational::new(n.into(), d.into()))
+            .to_vec();
+
+        // 1/2 * (1 - 2/3 * 3/4)
+        let expected = BigRational::new(1.into(), 4.into());
+
+        assert_eq!(bdd_probability(&bdd, &probabilities), expected);
+        assert_eq!(
+            bdd_probability(&variables.mk_true(), &probabilities),
+            BigRational::one()
+        );
+        assert_eq!(
+            bdd_probability(&variables.mk_false(), &probabilities),
+            BigRational::zero()
+        );
+    }
+
+    #[test]
+    fn test_bdd_weighted_count_skipped_variables_ok() {
+        let variables = BddVariableSet::new(&["a", "b", "c", "d"]);
+        let bdd = variables.eval_expression_string("b & !d");
+        let weights = [(1u8, 2u8), (3, 4), (5, 6), (7, 8)]
+            .map(|(w_false, w_true)| (BigUint::from(w_false), BigUint::from(w_true)))
+            .to_vec();
+
+        // (1 + 2) * 4 * (5 + 6) * 7
+        assert_eq!(bdd_weighted_count(&bdd, &weights), BigUint::from(924u32));
+        // (1 + 2) * (3 + 4) * (5 + 6) * (7 + 8)
+        assert_eq!(
+            bdd_weighted_count(&variables.mk_true(), &weights),
+            BigUint::from(3465u32)
+        );
+        assert!(!bdd_weighted_count(
+            &variables.mk_false(),
+            &[(true, true); 4]
+        ));
+    }
+}
@@ -7,7 +7,8 @@ use std::fmt::Debug;
 /// Takes a `Bdd` object and only retains the variables given in `new_inputs`.
 ///
 /// This expects the `Bdd` to only depend on the variables in `new_inputs`, meaning that
-/// `bdd.essential_inputs` is a subset of `new_inputs`. If this is not satisfied, panic.
+/// `bdd.essential_inputs` is a subset of `new_inputs`, and `new_inputs` to preserve the
+/// variable order of `bdd.inputs`. If this is not satisfied, panic.
 pub fn prune_bdd_variables<TLiteral: Debug + Clone + Eq + Ord>(
     bdd: &Bdd<TLiteral>,
     new_inputs: &[TLiteral],
@@ -24,13 +25,14 @@ pub fn prune_bdd_variables<TLiteral: Debug + Clone + Eq + Ord>(
 
     let mut permutation = HashMap::new();
 
-    // "Inverse" of `expand_bdd_variables`. This works because both input lists are sorted
-    // and the `new_inputs` is a subset of `bdd.inputs`.
+    // "Inverse" of `expand_bdd_variables`. This works because both input lists use the same
+    // variable order and the `new_inputs` is a subsequence of `bdd.inputs`.
+    let mut old_i = 0;
     for (new_i, var) in new_inputs.iter().enumerate() {
-        let old_i = bdd
-            .inputs
-            .binary_search(var)
-            .expect("Collection `new_inputs` is not a subset of `bdd.inputs`.");
+        old_i += bdd.inputs[old_i..]
+            .iter()
+            .position(|it| it == var)
+            .expect("Collection `new_inputs` is not an order-preserving subset of `bdd.inputs`.");
 
         if new_i != old_i {
             permutation.insert(
@@ -0,0 +1,165 @@
+use crate::bdd::utils::compose_bdd_variables;
+use crate::bdd::Bdd;
+use biodivine_lib_bdd::{BddVariable, BddVariableSet};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+/// Takes a `Bdd` object and rebuilds it such that its variable order is `new_inputs`.
+///
+/// The `new_inputs` must be a permutation of `bdd.inputs`, otherwise the method panics.
+///
+/// Unlike `extend_bdd_variables` and `prune_bdd_variables`, which only rename variables,
+/// this is a full reconstruction of the BDD (see `compose_bdd_variables`), hence it takes
+/// time polynomial in the sizes of the original and the new BDD.
+pub fn reorder_bdd_variables<TLiteral: Debug + Clone + Eq + Ord>(
+    bdd: &Bdd<TLiteral>,
+    new_inputs: &[TLiteral],
+) -> Bdd<TLiteral> {
+    if bdd.inputs == new_inputs {
+        return bdd.clone();
+    }
+
+    let new_positions = new_inputs
+        .iter()
+        .enumerate()
+        .map(|(index, var)| (var, index))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(
+        new_positions.len(),
+        bdd.inputs.len(),
+        "Collection `new_inputs` is not a permutation of `bdd.inputs`."
+    );
+    let old_to_new = bdd
+        .inputs
+        .iter()
+        .map(|var| {
+            *new_positions
+                .get(var)
+                .expect("Collection `new_inputs` is not a permutation of `bdd.inputs`.")
+        })
+        .collect::<Vec<_>>();
+
+    let variables = BddVariableSet::new_anonymous(bdd.bdd.num_vars());
+    let replacements = old_to_new
+        .into_iter()
+        .map(|index| variables.mk_var(BddVariable::from_index(index)))
+        .collect::<Vec<_>>();
+    let inner = compose_bdd_variables(&bdd.bdd, &variables, &replacements);
+
+    Bdd::new(inner, new_inputs.to_vec())
+}
+
+/// Merges two variable orders into one order over the union of their variables.
+///
+/// If possible, the result contains both `first` and `second` as subsequences. When both
+/// orders are sorted, the result is the sorted union. When the orders disagree on the
+/// relative position of two common variables, the result is `first` followed by the
+/// remaining variables of `second`, i.e. only `first` is preserved.
+pub fn merge_variable_orders<TLiteral: Debug + Clone + Eq + Ord>(
+    first: &[TLiteral],
+    second: &[TLiteral],
+) -> Vec<TLiteral> {
+    let first_set = first.iter().collect::<BTreeSet<_>>();
+    let second_set = second.iter().collect::<BTreeSet<_>>();
+
+    let mut result = Vec::with_capacity(first.len() + second.len());
+    let (mut i, mut j) = (0, 0);
+    while i < first.len() && j < second.len() {
+        let (x, y) = (&first[i], &second[j]);
+        if x == y {
+            result.push(x.clone());
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        match (second_set.contains(x), first_set.contains(y)) {
+            (false, false) if x < y => {
+                result.push(x.clone());
+                i += 1;
+            }
+            (false, false) | (true, false) => {
+                result.push(y.clone());
+                j += 1;
+            }
+            (false, true) => {
+                result.push(x.clone());
+                i += 1;
+            }
+            (true, true) => {
+                // The orders are not compatible.
+                let mut result = first.to_vec();
+                result.extend(second.iter().filter(|it| !first_set.contains(it)).cloned());
+                return result;
+            }
+        }
+    }
+
+    result.extend_from_slice(&first[i..]);
+    result.extend_from_slice(&second[j..]);
+    result
+}
+
+/// Checks that `sequence` appears in `order` in the same relative order.
+pub fn is_subsequence<TLiteral: Eq>(sequence: &[TLiteral], order: &[TLiteral]) -> bool {
+    let mut order = order.iter();
+    sequence.iter().all(|var| order.any(|it| it == var))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::traits::BooleanFunction;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reorder_variables_ok() {
+        let input = Bdd::try_from((var("a") & var("b")) | (var("c") ^ var("d"))).unwrap();
+        let order = names(&["d", "b", "c", "a"]);
+
+        let actual = reorder_bdd_variables(&input, &order);
+
+        assert_eq!(actual.inputs, order);
+        assert!(actual.is_equivalent(&input));
+        assert_eq!(reorder_bdd_variables(&actual, &input.inputs), input);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reorder_variables_not_permutation_nok() {
+        let input = Bdd::try_from(var("a") & var("b")).unwrap();
+
+        let _ = reorder_bdd_variables(&input, &names(&["a", "c"]));
+    }
+
+    #[test]
+    fn test_merge_variable_orders_ok() {
+        assert_eq!(
+            merge_variable_orders(&names(&["a", "c"]), &names(&["b", "c", "d"])),
+            names(&["a", "b", "c", "d"])
+        );
+        assert_eq!(
+            merge_variable_orders(&names(&["c", "a"]), &names(&["b", "a", "d"])),
+            names(&["b", "c", "a", "d"])
+        );
+        // Incompatible orders: `first` wins.
+        assert_eq!(
+            merge_variable_orders(&names(&["c", "a"]), &names(&["a", "b", "c"])),
+            names(&["c", "a", "b"])
+        );
+    }
+
+    #[test]
+    fn test_is_subsequence_ok() {
+        let order = names(&["c", "a", "b"]);
+
+        assert!(is_subsequence(&names(&["c", "b"]), &order));
+        assert!(is_subsequence(&names(&[]), &order));
+        assert!(!is_subsequence(&names(&["b", "c"]), &order));
+        assert!(!is_subsequence(&names(&["d"]), &order));
+    }
+}
@@ -1,21 +1,33 @@
 use num_bigint::BigUint;
 use pyo3::exceptions::PyRuntimeError;
-use pyo3::{pyclass, pymethods, PyResult};
+use pyo3::prelude::PyAnyMethods;
+use pyo3::types::PyBytes;
+use pyo3::{pyclass, pymethods, Bound, IntoPy, PyAny, PyObject, PyResult, Python};
 use std::collections::{BTreeMap, BTreeSet};
 
+use crate::bdd::decomposition::DecompositionOperator;
+use crate::bdd::factored_form::FactoredOperand;
+use crate::bdd::interpolation::InterpolantKind;
+use crate::bdd::ordering::OrderingHeuristic;
 use crate::bdd::Bdd;
+use crate::bindings::canalization::into_canalizing_tuples;
+use crate::bindings::decomposition::PythonDisjointDecomposition;
 use crate::bindings::error::PythonExpressionError::UnknownVariableWhileEvaluating;
 use crate::bindings::expression::PythonExpression;
+use crate::bindings::fraction::{into_probabilities, PythonFraction};
 use crate::bindings::iterators::{
     PythonBddRangeIterator, PythonBddRelationIterator, PythonBddSupportIterator,
     PythonDomainIterator,
 };
 use crate::bindings::table::PythonTruthTable;
-use crate::expressions::Expression;
+use crate::expressions::{BddToExpressionStrategy, Expression};
 use crate::table::TruthTable;
-use crate::traits::{BooleanFunction, BooleanPoint, BooleanValuation, Evaluate};
+use crate::traits::{
+    BooleanFunction, BooleanPoint, BooleanValuation, Canalization, Evaluate, FourierMeasures,
+    Monotonicity, RegulationSign, SampleSupport, Symmetry, WeightedCount,
+};
 
-#[pyclass(frozen, name = "Bdd")]
+#[pyclass(frozen, name = "Bdd", module = "biodivine_boolean_functions")]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PythonBdd {
     root: Bdd<String>,
@@ -200,6 +212,144 @@ impl PythonBdd {
         self.root.weight()
     }
 
+    /// The probability that this function evaluates to `1` when each input is independently
+    /// `true` with the probability given in `probabilities` (a `Fraction`, `int` or `float`
+    /// between `0` and `1`). Inputs without a given probability are `true` with probability `1/2`.
+    ///
+    /// The result is an exact `Fraction`.
+    fn probability(
+        &self,
+        probabilities: BTreeMap<String, PythonFraction>,
+    ) -> PyResult<PythonFraction> {
+        let probabilities = into_probabilities(probabilities)?;
+        Ok(self.root.probability(&probabilities).into())
+    }
+
+    /// The effect of `variable` on the output of this function: `Activating`, `Inhibiting`,
+    /// `Dual` or `NonObservable` (also for variables that are not inputs).
+    fn regulation_sign(&self, variable: String) -> RegulationSign {
+        self.root.regulation_sign(&variable)
+    }
+
+    /// The `RegulationSign` of every input of this function.
+    fn regulation_signs(&self) -> BTreeMap<String, RegulationSign> {
+        self.root.regulation_signs()
+    }
+
+    /// Returns `True` if increasing `variable` never decreases the output.
+    fn is_positive_unate_in(&self, variable: String) -> bool {
+        self.root.is_positive_unate_in(&variable)
+    }
+
+    /// Returns `True` if increasing `variable` never increases the output.
+    fn is_negative_unate_in(&self, variable: String) -> bool {
+        self.root.is_negative_unate_in(&variable)
+    }
+
+    /// Returns `True` if this function is (positive) monotone, i.e. it is positive unate
+    /// in every input.
+    fn is_monotone(&self) -> bool {
+        self.root.is_monotone()
+    }
+
+    /// The least monotone function above this function.
+    fn upper_monotone_approximation(&self) -> PythonBdd {
+        self.root.upper_monotone_approximation().into()
+    }
+
+    /// The greatest monotone function below this function.
+    fn lower_monotone_approximation(&self) -> PythonBdd {
+        self.root.lower_monotone_approximation().into()
+    }
+
+    /// The monotone closure of this function, i.e. its upper monotone approximation.
+    fn monotone_closure(&self) -> PythonBdd {
+        self.root.monotone_closure().into()
+    }
+
+    /// The unique minimal DNF of a monotone function (the disjunction of all its prime
+    /// implicants), or `None` if this function is not monotone.
+    fn minimal_positive_dnf(&self) -> Option<PythonExpression> {
+        self.root.minimal_positive_dnf().map(PythonExpression::from)
+    }
+
+    /// All canalizing inputs of this function as `(variable, value, output)` tuples: setting
+    /// `variable` to `value` fixes the output of the function to `output`.
+    fn canalizing_variables(&self) -> Vec<(String, bool, bool)> {
+        into_canalizing_tuples(self.root.canalizing_variables())
+    }
+
+    /// The layers of a nested canalizing function (each a list of `(variable, value, output)`
+    /// tuples), or `None` if this function is not nested canalizing.
+    fn nested_canalizing_layers(&self) -> Option<Vec<Vec<(String, bool, bool)>>> {
+        self.root
+            .nested_canalizing_layers()
+            .map(|layers| layers.into_iter().map(into_canalizing_tuples).collect())
+    }
+
+    fn is_nested_canalizing(&self) -> bool {
+        self.root.is_nested_canalizing()
+    }
+
+    /// The number of variables which are canalizing in the nested sense.
+    fn canalizing_depth(&self) -> usize {
+        self.root.canalizing_depth()
+    }
+
+    fn is_symmetric_in(&self, first: String, second: String) -> bool {
+        self.root.is_symmetric_in(&first, &second)
+    }
+
+    fn is_negation_symmetric_in(&self, first: String, second: String) -> bool {
+        self.root.is_negation_symmetric_in(&first, &second)
+    }
+
+    /// The maximal classes of inputs in which this function is symmetric.
+    fn symmetry_classes(&self) -> Vec<BTreeSet<String>> {
+        self.root.symmetry_classes()
+    }
+
+    /// The maximal classes of inputs in which this function is symmetric when negations are
+    /// allowed. Every variable is mapped to `True` if it is negated relative to the smallest
+    /// variable of its class.
+    fn negation_symmetry_classes(&self) -> Vec<BTreeMap<String, bool>> {
+        self.root.negation_symmetry_classes()
+    }
+
+    fn is_totally_symmetric(&self) -> bool {
+        self.root.is_totally_symmetric()
+    }
+
+    /// A Craig interpolant of `a` and `b`: a function over their shared inputs which is implied
+    /// by `a` and unsatisfiable together with `b`.
+    ///
+    /// Throws a `ValueError` if `a & b` is satisfiable.
+    #[staticmethod]
+    #[pyo3(signature = (a, b, kind = InterpolantKind::Strongest))]
+    fn interpolant(a: &PythonBdd, b: &PythonBdd, kind: InterpolantKind) -> PyResult<PythonBdd> {
+        Ok(Bdd::interpolant_with_kind(&a.root, &b.root, kind)?.into())
+    }
+
+    /// The disjoint-support decomposition tree of this function.
+    fn disjoint_decomposition(&self) -> PythonDisjointDecomposition {
+        self.root.disjoint_decomposition().into()
+    }
+
+    /// Decomposes this function into `first_function operator second_function`, where
+    /// `first_function` does not depend on `second` and `second_function` does not depend
+    /// on `first`. Returns `None` if no such decomposition exists.
+    ///
+    /// Throws a `ValueError` if `first` and `second` are not disjoint.
+    fn bi_decomposition(
+        &self,
+        operator: DecompositionOperator,
+        first: BTreeSet<String>,
+        second: BTreeSet<String>,
+    ) -> PyResult<Option<(PythonExpression, PythonExpression)>> {
+        let decomposition = self.root.bi_decomposition(operator, &first, &second)?;
+        Ok(decomposition.map(|it| (it.first().clone().into(), it.second().clone().into())))
+    }
+
     /// Create a Boolean function that is a restriction of this function for the given variables.
     ///
     /// A restriction fixes all variables specified by the `valuation` to their respective
@@ -230,12 +380,15 @@ impl PythonBdd {
     /// Note that the same variable can be substituted and at the same time appear in one of the
     /// substituted functions (as in the example). Also note that this operation can increase the
     /// degree of a function if the substituted functions contain previously unused variables.
-    fn substitute(&self, mapping: BTreeMap<String, Self>) -> Self {
-        self.root
-            .substitute(&BTreeMap::from_iter(
+    ///
+    /// The substitution is simultaneous, i.e. `{ x: x & y, y: x }` is a valid mapping.
+    fn substitute(&self, mapping: BTreeMap<String, Self>) -> PyResult<Self> {
+        Ok(self
+            .root
+            .try_substitute(&BTreeMap::from_iter(
                 mapping.into_iter().map(|(k, v)| (k, v.root)),
-            ))
-            .into()
+            ))?
+            .into())
     }
 
     /// Produce one [BooleanPoint] for which this function evaluates to `1`, i.e. one of the
@@ -251,6 +404,22 @@ impl PythonBdd {
         self.root.sat_point()
     }
 
+    /// Draws `count` uniformly random points for which this function evaluates to `1`
+    /// (independently, i.e. with replacement). The same `seed` always produces the same points.
+    ///
+    /// If a `restriction` is given, only the points which agree with it are sampled. Returns
+    /// an empty list if there is no such point.
+    #[pyo3(signature = (count, seed, restriction = None))]
+    fn sample_support(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: Option<BooleanValuation<String>>,
+    ) -> Vec<BooleanPoint> {
+        self.root
+            .sample_support_restricted(count, seed, &restriction.unwrap_or_default())
+    }
+
     /// Eliminate the specified `variables` using *existential* quantification. The resulting
     /// function does not depend on any of the eliminated variables.
     ///
@@ -262,6 +431,14 @@ impl PythonBdd {
         self.root.existential_quantification(variables).into()
     }
 
+    /// Computes `∃ variables: self & other` without building the conjunction first.
+    ///
+    /// This is the relational product used to compute images and preimages of transition
+    /// relations (see also `TransitionRelation`).
+    fn relational_product(&self, other: &PythonBdd, variables: BTreeSet<String>) -> Self {
+        self.root.relational_product(&other.root, &variables).into()
+    }
+
     /// Eliminate the specified `variables` using *universal* quantification. The resulting
     /// function does not depend on any of the eliminated variables.
     ///
@@ -297,6 +474,53 @@ impl PythonBdd {
         self.root.is_implied_by(&other.root)
     }
 
+    /// The influence of the given `variable`, i.e. the probability that flipping the value
+    /// of `variable` in a uniformly random input point changes the output of the function.
+    fn influence(&self, variable: String) -> PythonFraction {
+        self.root.influence(&variable).into()
+    }
+
+    /// The sum of influences of all inputs. Same as the average sensitivity.
+    fn total_influence(&self) -> PythonFraction {
+        self.root.total_influence().into()
+    }
+
+    /// The maximal number of variables that change the output of the function when flipped
+    /// individually, taken over all input points.
+    fn max_sensitivity(&self) -> usize {
+        self.root.max_sensitivity()
+    }
+
+    /// The expected number of variables that change the output of the function when flipped
+    /// individually, taken over a uniformly random input point.
+    fn average_sensitivity(&self) -> PythonFraction {
+        self.root.average_sensitivity().into()
+    }
+
+    /// The maximal number of disjoint blocks of variables that change the output of the function
+    /// when flipped together, taken over all input points.
+    ///
+    /// The operation is exponential in the number of inputs.
+    fn max_block_sensitivity(&self) -> usize {
+        self.root.max_block_sensitivity()
+    }
+
+    /// The expected block sensitivity of a uniformly random input point.
+    ///
+    /// The operation is exponential in the number of inputs.
+    fn average_block_sensitivity(&self) -> PythonFraction {
+        self.root.average_block_sensitivity().into()
+    }
+
+    /// The noise stability of this function when each input is flipped independently with
+    /// the given `flip_probability` (a `Fraction`, `int` or `float` between `0` and `1`).
+    ///
+    /// The result is the expected value of `F(x) * F(y)` with outputs interpreted as `1` (false)
+    /// and `-1` (true).
+    fn noise_stability(&self, flip_probability: PythonFraction) -> PyResult<PythonFraction> {
+        Ok(self.root.noise_stability(&flip_probability.0)?.into())
+    }
+
     #[staticmethod]
     pub fn from_expression(expression: &PythonExpression) -> PyResult<Self> {
         let native: Expression<String> = expression.into();
@@ -319,8 +543,154 @@ impl PythonBdd {
         }
     }
 
-    pub fn to_expression(&self) -> PythonExpression {
-        PythonExpression::from_bdd(self)
+    /// Builds a `Bdd` from `expression` using the given variable `order`, which must contain
+    /// every variable of the expression exactly once.
+    #[staticmethod]
+    pub fn from_expression_with_order(
+        expression: &PythonExpression,
+        order: Vec<String>,
+    ) -> PyResult<Self> {
+        let native: Expression<String> = expression.into();
+        Ok(Self::new(Bdd::from_expression_with_order(&native, &order)?))
+    }
+
+    /// Builds a `Bdd` from `expression` using the variable order computed by the given
+    /// static ordering `heuristic`.
+    #[staticmethod]
+    pub fn from_expression_with_heuristic(
+        expression: &PythonExpression,
+        heuristic: OrderingHeuristic,
+    ) -> PyResult<Self> {
+        let native: Expression<String> = expression.into();
+        Ok(Self::new(Bdd::from_expression_with_heuristic(
+            &native, heuristic,
+        )?))
+    }
+
+    /// Builds a `Bdd` from `table` using the given variable `order`, which must contain
+    /// every input of the table exactly once.
+    #[staticmethod]
+    pub fn from_table_with_order(table: &PythonTruthTable, order: Vec<String>) -> PyResult<Self> {
+        let native: TruthTable<String> = table.into();
+        Ok(Self::new(Bdd::from_table_with_order(&native, &order)?))
+    }
+
+    /// The current variable order of this `Bdd`, starting with the variable at the root.
+    pub fn variable_order(&self) -> Vec<String> {
+        self.root.variable_order().to_vec()
+    }
+
+    /// Creates an equivalent `Bdd` with the given variable `order`, which must contain
+    /// every input of this `Bdd` exactly once.
+    pub fn reorder(&self, order: Vec<String>) -> PyResult<Self> {
+        Ok(Self::new(self.root.reorder(&order)?))
+    }
+
+    /// Creates an equivalent `Bdd` with an improved variable order found by sifting.
+    pub fn sift(&self) -> Self {
+        Self::new(self.root.sift())
+    }
+
+    /// Serializes this `Bdd` (including its variable names and order) into a compact
+    /// binary format.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.root.to_bytes())
+    }
+
+    /// Deserializes a `Bdd` created by `to_bytes`. Raises a `ValueError` if the data is invalid.
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Ok(Self::new(Bdd::from_bytes(data)?))
+    }
+
+    /// Serializes this `Bdd` (including its variable names and order) into a line-based,
+    /// human-readable text format.
+    pub fn to_text(&self) -> String {
+        self.root.to_text()
+    }
+
+    /// Deserializes a `Bdd` created by `to_text`. Raises a `ValueError` if the data is invalid.
+    #[staticmethod]
+    pub fn from_text(data: &str) -> PyResult<Self> {
+        Ok(Self::new(Bdd::from_text(data)?))
+    }
+
+    /// Writes this `Bdd` into the file at `path`, using the text format if `text` is set
+    /// and the binary format otherwise.
+    #[pyo3(signature = (path, text = false))]
+    pub fn save(&self, path: &str, text: bool) -> PyResult<()> {
+        let data = if text {
+            self.root.to_text().into_bytes()
+        } else {
+            self.root.to_bytes()
+        };
+        Ok(std::fs::write(path, data)?)
+    }
+
+    /// Reads a `Bdd` from the file at `path` written by `save` (in either format).
+    #[staticmethod]
+    pub fn load(path: &str) -> PyResult<Self> {
+        let data = std::fs::read(path)?;
+        match std::str::from_utf8(&data) {
+            Ok(text) if !text.starts_with("BBFBDD") => Self::from_text(text),
+            _ => Self::from_bytes(&data),
+        }
+    }
+
+    pub fn __reduce__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyBytes>,))> {
+        let constructor = py.get_type_bound::<PythonBdd>().getattr("from_bytes")?;
+        Ok((constructor, (self.to_bytes(py),)))
+    }
+
+    /// Converts this `Bdd` into an equivalent expression using the given conversion `strategy`.
+    ///
+    /// With `BddToExpressionStrategy.IfThenElse`, the expression can be exponentially larger
+    /// than the `Bdd`. Use `to_factored_form` for a representation linear in its size.
+    #[pyo3(signature = (strategy = BddToExpressionStrategy::OptimizedDnf))]
+    pub fn to_expression(&self, strategy: BddToExpressionStrategy) -> PythonExpression {
+        PythonExpression::from_bdd(self, strategy)
+    }
+
+    /// The if-then-else network following the nodes of this `Bdd`, as a pair `(nodes, root)`.
+    ///
+    /// Each node is a tuple `(variable, low, high)` representing `high if variable else low`.
+    /// The operands (and the `root`) are either Boolean constants or indices of other nodes.
+    /// Every node only refers to nodes with smaller indices.
+    #[allow(clippy::type_complexity)]
+    pub fn to_factored_form(&self, py: Python) -> (Vec<(String, PyObject, PyObject)>, PyObject) {
+        let operand = |operand: FactoredOperand| match operand {
+            FactoredOperand::Constant(value) => value.into_py(py),
+            FactoredOperand::Node(index) => index.into_py(py),
+        };
+
+        let factored = self.root.to_factored_form();
+        let nodes = factored
+            .nodes()
+            .iter()
+            .map(|node| (node.variable.clone(), operand(node.low), operand(node.high)))
+            .collect();
+        (nodes, operand(factored.root()))
+    }
+
+    /// An irredundant sum-of-products cover of this function (Minato–Morreale ISOP) as
+    /// a list of cubes. Each cube is a dictionary of the fixed variables.
+    ///
+    /// If a `care_set` is given, the cover only has to agree with this function on the points
+    /// of the `care_set`.
+    #[pyo3(signature = (care_set = None))]
+    pub fn isop_cubes(&self, care_set: Option<&PythonBdd>) -> Vec<BooleanValuation<String>> {
+        self.root.isop_cubes(care_set.map(|it| &it.root))
+    }
+
+    /// The irredundant sum-of-products cover of `isop_cubes` as an `Expression`.
+    #[pyo3(signature = (care_set = None))]
+    pub fn to_isop_expression(&self, care_set: Option<&PythonBdd>) -> PythonExpression {
+        self.root
+            .to_isop_expression(care_set.map(|it| &it.root))
+            .into()
     }
 
     pub fn to_table(&self) -> PythonTruthTable {
@@ -0,0 +1,9 @@
+use crate::traits::CanalizingInput;
+
+/// Converts canalizing inputs to Python `(variable, value, output)` tuples.
+pub fn into_canalizing_tuples(inputs: Vec<CanalizingInput<String>>) -> Vec<(String, bool, bool)> {
+    inputs
+        .into_iter()
+        .map(|input| (input.variable, input.value, input.output))
+        .collect()
+}
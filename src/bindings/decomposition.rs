@@ -0,0 +1,55 @@
+use pyo3::{pyclass, pymethods};
+use std::collections::BTreeMap;
+
+use crate::bdd::decomposition::{DecompositionKind, DisjointDecomposition};
+use crate::bindings::expression::PythonExpression;
+
+#[pyclass(frozen, name = "DisjointDecomposition")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonDisjointDecomposition {
+    root: DisjointDecomposition<String>,
+}
+
+impl From<DisjointDecomposition<String>> for PythonDisjointDecomposition {
+    fn from(value: DisjointDecomposition<String>) -> Self {
+        PythonDisjointDecomposition { root: value }
+    }
+}
+
+#[pymethods]
+impl PythonDisjointDecomposition {
+    pub fn kind(&self) -> DecompositionKind {
+        self.root.kind()
+    }
+
+    /// The function of this block, expressed over the representatives of its children.
+    pub fn function(&self) -> PythonExpression {
+        self.root.function().clone().into()
+    }
+
+    /// The child blocks, indexed by their representative variable.
+    pub fn children(&self) -> BTreeMap<String, PythonDisjointDecomposition> {
+        self.root
+            .children()
+            .iter()
+            .map(|(representative, child)| (representative.clone(), child.clone().into()))
+            .collect()
+    }
+
+    /// Composes the whole decomposition tree back into a single `Expression`.
+    pub fn to_expression(&self) -> PythonExpression {
+        self.root.to_expression().into()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!(
+            "DisjointDecomposition({:?}, {})",
+            self.root.kind(),
+            self.root.function()
+        )
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
@@ -2,22 +2,28 @@ use num_bigint::BigUint;
 use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
 
+use crate::bdd::interpolation::InterpolantKind;
 use crate::bdd::Bdd;
 use crate::bindings::bdd::PythonBdd;
 use pyo3::prelude::{pyclass, pyfunction, pymethods, PyAny, PyAnyMethods, PyResult};
 use pyo3::Bound;
 
+use crate::bindings::canalization::into_canalizing_tuples;
 use crate::bindings::error::PythonExpressionError;
 use crate::bindings::error::PythonExpressionError::UnknownVariableWhileEvaluating;
+use crate::bindings::fraction::{into_probabilities, PythonFraction};
 use crate::bindings::iterators::{
     PythonDomainIterator, PythonExpressionRangeIterator, PythonExpressionRelationIterator,
     PythonExpressionSupportIterator,
 };
 use crate::bindings::table::PythonTruthTable;
-use crate::expressions::{Expression as RustExpression, Expression, ExpressionNode};
+use crate::expressions::{
+    BddToExpressionStrategy, Expression as RustExpression, Expression, ExpressionNode,
+};
 use crate::table::TruthTable;
 use crate::traits::{
-    BooleanFunction, BooleanPoint, BooleanValuation, Evaluate, GatherLiterals, SemanticEq,
+    BooleanFunction, BooleanPoint, BooleanValuation, Canalization, Evaluate, GatherLiterals,
+    Monotonicity, RegulationSign, SampleSupport, SemanticEq, WeightedCount,
 };
 
 #[pyclass(frozen, name = "Expression")]
@@ -293,6 +299,90 @@ impl PythonExpression {
         self.root.weight()
     }
 
+    /// The probability that this function evaluates to `1` when each input is independently
+    /// `true` with the probability given in `probabilities` (a `Fraction`, `int` or `float`
+    /// between `0` and `1`). Inputs without a given probability are `true` with probability `1/2`.
+    ///
+    /// The result is an exact `Fraction`.
+    fn probability(
+        &self,
+        probabilities: BTreeMap<String, PythonFraction>,
+    ) -> PyResult<PythonFraction> {
+        let probabilities = into_probabilities(probabilities)?;
+        Ok(self.root.probability(&probabilities).into())
+    }
+
+    /// The effect of `variable` on the output of this function: `Activating`, `Inhibiting`,
+    /// `Dual` or `NonObservable` (also for variables that are not inputs).
+    fn regulation_sign(&self, variable: String) -> RegulationSign {
+        self.root.regulation_sign(&variable)
+    }
+
+    /// The `RegulationSign` of every input of this function.
+    fn regulation_signs(&self) -> BTreeMap<String, RegulationSign> {
+        self.root.regulation_signs()
+    }
+
+    /// Returns `True` if increasing `variable` never decreases the output.
+    fn is_positive_unate_in(&self, variable: String) -> bool {
+        self.root.is_positive_unate_in(&variable)
+    }
+
+    /// Returns `True` if increasing `variable` never increases the output.
+    fn is_negative_unate_in(&self, variable: String) -> bool {
+        self.root.is_negative_unate_in(&variable)
+    }
+
+    /// Returns `True` if this function is (positive) monotone, i.e. it is positive unate
+    /// in every input.
+    fn is_monotone(&self) -> bool {
+        self.root.is_monotone()
+    }
+
+    /// The least monotone function above this function.
+    fn upper_monotone_approximation(&self) -> PythonExpression {
+        self.root.upper_monotone_approximation().into()
+    }
+
+    /// The greatest monotone function below this function.
+    fn lower_monotone_approximation(&self) -> PythonExpression {
+        self.root.lower_monotone_approximation().into()
+    }
+
+    /// The monotone closure of this function, i.e. its upper monotone approximation.
+    fn monotone_closure(&self) -> PythonExpression {
+        self.root.monotone_closure().into()
+    }
+
+    /// The unique minimal DNF of a monotone function (the disjunction of all its prime
+    /// implicants), or `None` if this function is not monotone.
+    fn minimal_positive_dnf(&self) -> Option<PythonExpression> {
+        self.root.minimal_positive_dnf().map(PythonExpression::from)
+    }
+
+    /// All canalizing inputs of this function as `(variable, value, output)` tuples: setting
+    /// `variable` to `value` fixes the output of the function to `output`.
+    fn canalizing_variables(&self) -> Vec<(String, bool, bool)> {
+        into_canalizing_tuples(self.root.canalizing_variables())
+    }
+
+    /// The layers of a nested canalizing function (each a list of `(variable, value, output)`
+    /// tuples), or `None` if this function is not nested canalizing.
+    fn nested_canalizing_layers(&self) -> Option<Vec<Vec<(String, bool, bool)>>> {
+        self.root
+            .nested_canalizing_layers()
+            .map(|layers| layers.into_iter().map(into_canalizing_tuples).collect())
+    }
+
+    fn is_nested_canalizing(&self) -> bool {
+        self.root.is_nested_canalizing()
+    }
+
+    /// The number of variables which are canalizing in the nested sense.
+    fn canalizing_depth(&self) -> usize {
+        self.root.canalizing_depth()
+    }
+
     /// Create a Boolean function that is a restriction of this function for the given variables.
     ///
     /// A restriction fixes all variables specified by the `valuation` to their respective
@@ -344,6 +434,22 @@ impl PythonExpression {
         self.root.sat_point()
     }
 
+    /// Draws `count` uniformly random points for which this function evaluates to `1`
+    /// (independently, i.e. with replacement). The same `seed` always produces the same points.
+    ///
+    /// If a `restriction` is given, only the points which agree with it are sampled. Returns
+    /// an empty list if there is no such point.
+    #[pyo3(signature = (count, seed, restriction = None))]
+    fn sample_support(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: Option<BooleanValuation<String>>,
+    ) -> Vec<BooleanPoint> {
+        self.root
+            .sample_support_restricted(count, seed, &restriction.unwrap_or_default())
+    }
+
     /// Eliminate the specified `variables` using *existential* quantification. The resulting
     /// function does not depend on any of the eliminated variables.
     ///
@@ -390,6 +496,20 @@ impl PythonExpression {
         self.root.is_implied_by(&other.root)
     }
 
+    /// A Craig interpolant of `a` and `b`: a function over their shared inputs which is implied
+    /// by `a` and unsatisfiable together with `b`. Computed using `Bdd` objects.
+    ///
+    /// Throws a `ValueError` if `a & b` is satisfiable or if there are too many variables.
+    #[staticmethod]
+    #[pyo3(signature = (a, b, kind = InterpolantKind::Strongest))]
+    pub fn interpolant(
+        a: &PythonExpression,
+        b: &PythonExpression,
+        kind: InterpolantKind,
+    ) -> PyResult<PythonExpression> {
+        Ok(RustExpression::interpolant_with_kind(&a.root, &b.root, kind)?.into())
+    }
+
     #[staticmethod]
     pub fn from_table(table: &PythonTruthTable) -> Self {
         let rust_table: TruthTable<String> = table.into();
@@ -398,10 +518,15 @@ impl PythonExpression {
         Self::new(rust_expression)
     }
 
+    /// Converts `bdd` into an equivalent expression using the given conversion `strategy`.
+    ///
+    /// With `BddToExpressionStrategy.IfThenElse`, the expression can be exponentially larger
+    /// than the `bdd`. Use `Bdd.to_factored_form` for a representation linear in its size.
     #[staticmethod]
-    pub fn from_bdd(bdd: &PythonBdd) -> Self {
+    #[pyo3(signature = (bdd, strategy = BddToExpressionStrategy::OptimizedDnf))]
+    pub fn from_bdd(bdd: &PythonBdd, strategy: BddToExpressionStrategy) -> Self {
         let rust_table: Bdd<String> = bdd.into();
-        let rust_expression: Expression<String> = rust_table.into();
+        let rust_expression = Expression::from_bdd_with_strategy(&rust_table, strategy);
 
         Self::new(rust_expression)
     }
@@ -0,0 +1,72 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::collections::BTreeMap;
+
+/// A wrapper which converts exact rational results into Python `fractions.Fraction` objects.
+///
+/// When used as an argument, it accepts anything with integer `numerator` and `denominator`
+/// attributes (i.e. `int` and `Fraction`), or a `float`, which is converted exactly.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonFraction(pub BigRational);
+
+impl From<BigRational> for PythonFraction {
+    fn from(value: BigRational) -> Self {
+        PythonFraction(value)
+    }
+}
+
+impl IntoPy<PyObject> for PythonFraction {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let (numerator, denominator) = self.0.into();
+        py.import_bound("fractions")
+            .and_then(|module| module.getattr("Fraction"))
+            .and_then(|fraction| fraction.call1((numerator, denominator)))
+            .expect("The `fractions` module is part of the Python standard library")
+            .unbind()
+    }
+}
+
+impl<'py> FromPyObject<'py> for PythonFraction {
+    fn extract_bound(value: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let (Ok(numerator), Ok(denominator)) =
+            (value.getattr("numerator"), value.getattr("denominator"))
+        {
+            let numerator = numerator.extract::<BigInt>()?;
+            let denominator = denominator.extract::<BigInt>()?;
+            return Ok(PythonFraction(BigRational::new(numerator, denominator)));
+        }
+
+        let float = value.extract::<f64>()?;
+        BigRational::from_float(float)
+            .map(PythonFraction)
+            .ok_or_else(|| PyValueError::new_err(format!("Expected a finite number, got {float}")))
+    }
+}
+
+impl PythonFraction {
+    /// Unwraps the rational value, failing with a `ValueError` if it is not
+    /// a valid probability (i.e. it is outside of the `[0, 1]` interval).
+    pub fn into_probability(self) -> PyResult<BigRational> {
+        if self.0 < BigRational::from_integer(0.into())
+            || self.0 > BigRational::from_integer(1.into())
+        {
+            return Err(PyValueError::new_err(format!(
+                "Expected a probability between 0 and 1, got {}",
+                self.0
+            )));
+        }
+        Ok(self.0)
+    }
+}
+
+/// Unwraps a map of rational values using [PythonFraction::into_probability].
+pub fn into_probabilities<K: Ord>(
+    probabilities: BTreeMap<K, PythonFraction>,
+) -> PyResult<BTreeMap<K, BigRational>> {
+    probabilities
+        .into_iter()
+        .map(|(key, value)| Ok((key, value.into_probability()?)))
+        .collect()
+}
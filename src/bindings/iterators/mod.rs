@@ -2,20 +2,26 @@ pub use domain::PythonDomainIterator;
 pub use range_bdd::PythonBddRangeIterator;
 pub use range_expression::PythonExpressionRangeIterator;
 pub use range_table::PythonTableRangeIterator;
+pub use range_zdd::PythonZddRangeIterator;
 pub use relation_bdd::PythonBddRelationIterator;
 pub use relation_expression::PythonExpressionRelationIterator;
 pub use relation_table::PythonTableRelationIterator;
+pub use relation_zdd::PythonZddRelationIterator;
 pub use support_bdd::PythonBddSupportIterator;
 pub use support_expression::PythonExpressionSupportIterator;
 pub use support_table::PythonTableSupportIterator;
+pub use support_zdd::PythonZddSupportIterator;
 
 mod domain;
 mod range_bdd;
 mod range_expression;
 mod range_table;
+mod range_zdd;
 mod relation_bdd;
 mod relation_expression;
 mod relation_table;
+mod relation_zdd;
 mod support_bdd;
 mod support_expression;
 mod support_table;
+mod support_zdd;
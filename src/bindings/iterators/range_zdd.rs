@@ -0,0 +1,23 @@
+use crate::zdd::iterators::ImageIterator;
+use pyo3::prelude::*;
+
+#[pyclass(name = "ZddRangeIterator")]
+pub struct PythonZddRangeIterator {
+    iter: ImageIterator,
+}
+
+#[pymethods]
+impl PythonZddRangeIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<bool> {
+        slf.iter.next()
+    }
+}
+
+impl From<ImageIterator> for PythonZddRangeIterator {
+    fn from(value: ImageIterator) -> Self {
+        Self { iter: value }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::iterators::DomainIterator;
+use crate::zdd::iterators::ImageIterator;
+use pyo3::prelude::*;
+use std::iter::Zip;
+
+#[pyclass(name = "ZddRelationIterator")]
+pub struct PythonZddRelationIterator {
+    iter: Zip<DomainIterator, ImageIterator>,
+}
+
+#[pymethods]
+impl PythonZddRelationIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(Vec<bool>, bool)> {
+        slf.iter.next()
+    }
+}
+
+impl From<Zip<DomainIterator, ImageIterator>> for PythonZddRelationIterator {
+    fn from(value: Zip<DomainIterator, ImageIterator>) -> Self {
+        Self { iter: value }
+    }
+}
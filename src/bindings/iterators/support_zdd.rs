@@ -0,0 +1,24 @@
+use crate::zdd::iterators::SupportIterator;
+use pyo3::prelude::*;
+
+#[pyclass(name = "ZddSupportIterator")]
+pub struct PythonZddSupportIterator {
+    iter: SupportIterator,
+}
+
+#[pymethods]
+impl PythonZddSupportIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<Vec<bool>> {
+        slf.iter.next()
+    }
+}
+
+impl From<SupportIterator> for PythonZddSupportIterator {
+    fn from(value: SupportIterator) -> Self {
+        Self { iter: value }
+    }
+}
@@ -1,13 +1,35 @@
 mod bdd;
+mod canalization;
+mod decomposition;
 mod error;
 mod expression;
+mod fraction;
 mod iterators;
+mod network;
+mod relation;
+mod symmetric;
 mod table;
+mod threshold;
+mod universe;
+mod zdd;
 
+use crate::bdd::decomposition::{DecompositionKind, DecompositionOperator};
+use crate::bdd::interpolation::InterpolantKind;
+use crate::bdd::ordering::OrderingHeuristic;
 use crate::bindings::bdd::PythonBdd;
+use crate::bindings::decomposition::PythonDisjointDecomposition;
 use crate::bindings::expression::PythonExpression;
+use crate::bindings::network::{PythonAttractor, PythonBooleanNetwork, PythonRegulatoryGraph};
+use crate::bindings::relation::PythonTransitionRelation;
+use crate::bindings::symmetric::PythonSymmetricFunction;
 use crate::bindings::table::PythonTruthTable;
+use crate::bindings::threshold::{PythonAsummabilityWitness, PythonThresholdFunction};
+use crate::bindings::universe::{PythonBddUniverse, PythonUniverseBdd};
+use crate::bindings::zdd::PythonZdd;
+use crate::expressions::BddToExpressionStrategy;
+use crate::network::{AttractorKind, UpdateSemantics};
 use crate::table::display_formatted::{TableBooleanFormatting, TableStyle};
+use crate::traits::RegulationSign;
 use pyo3::prelude::*;
 
 /// A Python module implemented in Rust. The name of this function must match
@@ -18,9 +40,28 @@ fn biodivine_boolean_functions(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PythonExpression>()?;
     m.add_class::<PythonTruthTable>()?;
     m.add_class::<PythonBdd>()?;
+    m.add_class::<PythonBddUniverse>()?;
+    m.add_class::<PythonUniverseBdd>()?;
+    m.add_class::<PythonZdd>()?;
+    m.add_class::<PythonTransitionRelation>()?;
+    m.add_class::<PythonBooleanNetwork>()?;
+    m.add_class::<PythonAttractor>()?;
+    m.add_class::<PythonRegulatoryGraph>()?;
+    m.add_class::<PythonThresholdFunction>()?;
+    m.add_class::<PythonAsummabilityWitness>()?;
+    m.add_class::<PythonSymmetricFunction>()?;
+    m.add_class::<PythonDisjointDecomposition>()?;
 
     m.add_class::<TableStyle>()?;
     m.add_class::<TableBooleanFormatting>()?;
+    m.add_class::<OrderingHeuristic>()?;
+    m.add_class::<BddToExpressionStrategy>()?;
+    m.add_class::<UpdateSemantics>()?;
+    m.add_class::<AttractorKind>()?;
+    m.add_class::<RegulationSign>()?;
+    m.add_class::<DecompositionKind>()?;
+    m.add_class::<DecompositionOperator>()?;
+    m.add_class::<InterpolantKind>()?;
 
     m.add_function(wrap_pyfunction!(crate::bindings::expression::var, m)?)?;
     m.add_function(wrap_pyfunction!(crate::bindings::expression::vars, m)?)?;
@@ -0,0 +1,265 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::PyAnyMethods;
+use pyo3::{pyclass, pymethods, Bound, PyAny, PyResult};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::bdd::Bdd;
+use crate::bindings::bdd::PythonBdd;
+use crate::bindings::expression::PythonExpression;
+use crate::bindings::table::PythonTruthTable;
+use crate::bindings::zdd::PythonZdd;
+use crate::expressions::Expression;
+use crate::network::{
+    Attractor, AttractorKind, BooleanNetwork, BooleanNetworkError, RegulatoryGraph, UpdateSemantics,
+};
+use crate::table::TruthTable;
+use crate::traits::{BooleanFunction, RegulationSign};
+use crate::zdd::Zdd;
+
+#[pyclass(frozen, name = "BooleanNetwork")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonBooleanNetwork {
+    root: BooleanNetwork<String>,
+}
+
+impl From<BooleanNetwork<String>> for PythonBooleanNetwork {
+    fn from(value: BooleanNetwork<String>) -> Self {
+        PythonBooleanNetwork { root: value }
+    }
+}
+
+#[pyclass(frozen, name = "Attractor")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonAttractor {
+    root: Attractor<String>,
+}
+
+impl From<Attractor<String>> for PythonAttractor {
+    fn from(value: Attractor<String>) -> Self {
+        PythonAttractor { root: value }
+    }
+}
+
+#[pyclass(frozen, name = "RegulatoryGraph")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonRegulatoryGraph {
+    root: RegulatoryGraph<String>,
+}
+
+impl From<RegulatoryGraph<String>> for PythonRegulatoryGraph {
+    fn from(value: RegulatoryGraph<String>) -> Self {
+        PythonRegulatoryGraph { root: value }
+    }
+}
+
+#[pymethods]
+impl PythonBooleanNetwork {
+    /// Creates a network from a dictionary of update functions, keyed by the updated variable.
+    /// The functions can be given as an `Expression`, `Bdd`, `Table` or `Zdd`.
+    ///
+    /// Throws a `ValueError` if an update function depends on a variable that is not
+    /// a key of the dictionary.
+    #[new]
+    pub fn py_new(update_functions: BTreeMap<String, Bound<'_, PyAny>>) -> PyResult<Self> {
+        let update_functions = update_functions
+            .into_iter()
+            .map(|(variable, function)| Ok((variable, extract_update_function(&function)?)))
+            .collect::<PyResult<BTreeMap<_, _>>>()?;
+        Ok(BooleanNetwork::new(update_functions)?.into())
+    }
+
+    pub fn variables(&self) -> BTreeSet<String> {
+        self.root.variables()
+    }
+
+    /// The update function of `variable`, or `None` if it is not a network variable.
+    pub fn update_function(&self, variable: String) -> Option<PythonBdd> {
+        self.root
+            .update_function(&variable)
+            .map(|it| it.clone().into())
+    }
+
+    /// The variables that the update function of `variable` depends on, or `None` if it
+    /// is not a network variable.
+    pub fn regulators(&self, variable: String) -> Option<BTreeSet<String>> {
+        self.root.regulators(&variable)
+    }
+
+    /// The states which are not left by any transition.
+    pub fn fixed_points(&self) -> PythonBdd {
+        self.root.fixed_points().into()
+    }
+
+    /// The successors of the given `states`.
+    #[pyo3(signature = (states, semantics = UpdateSemantics::Asynchronous))]
+    pub fn post(&self, states: &PythonBdd, semantics: UpdateSemantics) -> PythonBdd {
+        self.root.post(&states.into(), semantics).into()
+    }
+
+    /// The predecessors of the given `states`.
+    #[pyo3(signature = (states, semantics = UpdateSemantics::Asynchronous))]
+    pub fn pre(&self, states: &PythonBdd, semantics: UpdateSemantics) -> PythonBdd {
+        self.root.pre(&states.into(), semantics).into()
+    }
+
+    /// The states reachable from the `initial` states (including the `initial` states).
+    #[pyo3(signature = (initial, semantics = UpdateSemantics::Asynchronous))]
+    pub fn reach_forward(&self, initial: &PythonBdd, semantics: UpdateSemantics) -> PythonBdd {
+        self.root.reach_forward(&initial.into(), semantics).into()
+    }
+
+    /// The states from which some of the `target` states are reachable
+    /// (including the `target` states).
+    #[pyo3(signature = (target, semantics = UpdateSemantics::Asynchronous))]
+    pub fn reach_backward(&self, target: &PythonBdd, semantics: UpdateSemantics) -> PythonBdd {
+        self.root.reach_backward(&target.into(), semantics).into()
+    }
+
+    /// All attractors of this network, i.e. the sets of states which are never left once
+    /// they are entered, and in which every state can reach every other state.
+    #[pyo3(signature = (semantics = UpdateSemantics::Asynchronous))]
+    pub fn attractors(&self, semantics: UpdateSemantics) -> Vec<PythonAttractor> {
+        self.root
+            .attractors(semantics)
+            .into_iter()
+            .map(PythonAttractor::from)
+            .collect()
+    }
+
+    /// The inclusion-minimal trap spaces of this network, as dictionaries of fixed variables.
+    pub fn minimal_trap_spaces(&self) -> Vec<BTreeMap<String, bool>> {
+        self.root.minimal_trap_spaces()
+    }
+
+    /// The inclusion-maximal trap spaces of this network (except for the whole state space),
+    /// as dictionaries of fixed variables.
+    pub fn maximal_trap_spaces(&self) -> Vec<BTreeMap<String, bool>> {
+        self.root.maximal_trap_spaces()
+    }
+
+    /// Checks that no transition can leave the given `subspace`.
+    pub fn is_trap_space(&self, subspace: BTreeMap<String, bool>) -> bool {
+        self.root.is_trap_space(&subspace)
+    }
+
+    /// Fixes every free variable whose update function is constant in the `subspace`,
+    /// until no more variables can be fixed.
+    pub fn percolate(&self, subspace: BTreeMap<String, bool>) -> BTreeMap<String, bool> {
+        self.root.percolate(&subspace)
+    }
+
+    /// The signed regulatory graph of the update functions of this network.
+    pub fn regulatory_graph(&self) -> PythonRegulatoryGraph {
+        self.root.regulatory_graph().into()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("BooleanNetwork({:?})", self.root.variables())
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+#[pymethods]
+impl PythonAttractor {
+    /// The states of this attractor.
+    pub fn states(&self) -> PythonBdd {
+        self.root.states().clone().into()
+    }
+
+    pub fn kind(&self) -> AttractorKind {
+        self.root.kind()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!(
+            "Attractor({:?}, {} states)",
+            self.root.kind(),
+            self.root.states().weight()
+        )
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+#[pymethods]
+impl PythonRegulatoryGraph {
+    /// Builds the signed regulatory graph of a dictionary of functions, keyed by the target
+    /// variable. The functions can be given as an `Expression`, `Bdd`, `Table` or `Zdd`.
+    #[new]
+    pub fn py_new(functions: BTreeMap<String, Bound<'_, PyAny>>) -> PyResult<Self> {
+        let functions = functions
+            .into_iter()
+            .map(|(variable, function)| Ok((variable, extract_update_function(&function)?)))
+            .collect::<PyResult<BTreeMap<_, _>>>()?;
+        Ok(RegulatoryGraph::from_functions(&functions).into())
+    }
+
+    pub fn variables(&self) -> BTreeSet<String> {
+        self.root.variables().clone()
+    }
+
+    /// The sign of the regulation `regulator -> target`, or `None` if there is no such
+    /// regulation.
+    pub fn regulation(&self, regulator: String, target: String) -> Option<RegulationSign> {
+        self.root.regulation(&regulator, &target)
+    }
+
+    /// All regulations as `(regulator, target, sign)` tuples.
+    pub fn regulations(&self) -> Vec<(String, String, RegulationSign)> {
+        self.root
+            .regulations()
+            .map(|(regulator, target, sign)| (regulator.clone(), target.clone(), sign))
+            .collect()
+    }
+
+    /// The regulators of `target` together with their signs.
+    pub fn regulators(&self, target: String) -> BTreeMap<String, RegulationSign> {
+        self.root.regulators(&target)
+    }
+
+    /// The variables regulated by `regulator` together with the signs of the regulations.
+    pub fn targets(&self, regulator: String) -> BTreeMap<String, RegulationSign> {
+        self.root.targets(&regulator)
+    }
+
+    /// Exports the graph in the Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        self.root.to_dot()
+    }
+
+    /// Exports the graph in the Simple Interaction Format (SIF).
+    pub fn to_sif(&self) -> String {
+        self.root.to_sif()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("RegulatoryGraph({:?})", self.root.variables())
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+pub(crate) fn extract_update_function(function: &Bound<'_, PyAny>) -> PyResult<Bdd<String>> {
+    let converted = if let Ok(bdd) = function.extract::<PythonBdd>() {
+        return Ok(bdd.into());
+    } else if let Ok(expression) = function.extract::<PythonExpression>() {
+        Bdd::try_from(Expression::from(expression))
+    } else if let Ok(table) = function.extract::<PythonTruthTable>() {
+        Bdd::try_from(TruthTable::from(table))
+    } else if let Ok(zdd) = function.extract::<PythonZdd>() {
+        Bdd::try_from(Zdd::from(zdd))
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "Expected an Expression, Bdd, Table or Zdd, got {}",
+            function.get_type()
+        )));
+    };
+    Ok(converted.map_err(BooleanNetworkError::from)?)
+}
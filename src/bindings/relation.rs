@@ -0,0 +1,58 @@
+use pyo3::{pyclass, pymethods, PyResult};
+use std::collections::BTreeMap;
+
+use crate::bdd::relation::TransitionRelation;
+use crate::bdd::Bdd;
+use crate::bindings::bdd::PythonBdd;
+
+#[pyclass(frozen, name = "TransitionRelation")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonTransitionRelation {
+    root: TransitionRelation<String>,
+}
+
+impl From<TransitionRelation<String>> for PythonTransitionRelation {
+    fn from(value: TransitionRelation<String>) -> Self {
+        PythonTransitionRelation { root: value }
+    }
+}
+
+#[pymethods]
+impl PythonTransitionRelation {
+    /// Creates a transition relation over the current variables and their primed copies,
+    /// where `primed` maps every current variable to its primed copy.
+    ///
+    /// Throws a `ValueError` if two variables share a primed copy, or if a primed copy
+    /// is also a current variable.
+    #[new]
+    pub fn py_new(relation: &PythonBdd, primed: BTreeMap<String, String>) -> PyResult<Self> {
+        let relation: Bdd<String> = relation.into();
+        Ok(TransitionRelation::new(relation, primed)?.into())
+    }
+
+    pub fn relation(&self) -> PythonBdd {
+        self.root.relation().clone().into()
+    }
+
+    pub fn primed_variables(&self) -> BTreeMap<String, String> {
+        self.root.primed_variables().clone()
+    }
+
+    /// The set of successors of the given `states`, expressed over the current variables.
+    pub fn post(&self, states: &PythonBdd) -> PythonBdd {
+        self.root.post(&states.into()).into()
+    }
+
+    /// The set of predecessors of the given `states`, expressed over the current variables.
+    pub fn pre(&self, states: &PythonBdd) -> PythonBdd {
+        self.root.pre(&states.into()).into()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("TransitionRelation({:?})", self.root.primed_variables())
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
@@ -0,0 +1,108 @@
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::{pyclass, pymethods, Bound, PyAny, PyResult};
+use std::collections::BTreeSet;
+
+use crate::bdd::Bdd;
+use crate::bindings::bdd::PythonBdd;
+use crate::bindings::expression::PythonExpression;
+use crate::bindings::network::extract_update_function;
+use crate::bindings::table::PythonTruthTable;
+use crate::symmetric::SymmetricFunction;
+use crate::traits::BooleanValuation;
+
+#[pyclass(frozen, name = "SymmetricFunction")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonSymmetricFunction {
+    root: SymmetricFunction<String>,
+}
+
+impl From<SymmetricFunction<String>> for PythonSymmetricFunction {
+    fn from(value: SymmetricFunction<String>) -> Self {
+        PythonSymmetricFunction { root: value }
+    }
+}
+
+#[pymethods]
+impl PythonSymmetricFunction {
+    /// Creates a symmetric function from its value vector: `values[k]` is the output when
+    /// exactly `k` of the `inputs` are `True`.
+    ///
+    /// Throws a `ValueError` if there are not exactly `len(inputs) + 1` values.
+    #[new]
+    pub fn py_new(inputs: BTreeSet<String>, values: Vec<bool>) -> PyResult<Self> {
+        Ok(SymmetricFunction::new(inputs, values)?.into())
+    }
+
+    #[staticmethod]
+    pub fn mk_at_least(inputs: BTreeSet<String>, count: usize) -> Self {
+        SymmetricFunction::mk_at_least(inputs, count).into()
+    }
+
+    #[staticmethod]
+    pub fn mk_exactly(inputs: BTreeSet<String>, count: usize) -> Self {
+        SymmetricFunction::mk_exactly(inputs, count).into()
+    }
+
+    #[staticmethod]
+    pub fn mk_majority(inputs: BTreeSet<String>) -> Self {
+        SymmetricFunction::mk_majority(inputs).into()
+    }
+
+    #[staticmethod]
+    pub fn mk_parity(inputs: BTreeSet<String>) -> Self {
+        SymmetricFunction::mk_parity(inputs).into()
+    }
+
+    /// The value vector of `function` (an `Expression`, `Bdd`, `Table` or `Zdd`), or `None`
+    /// if it is not totally symmetric.
+    #[staticmethod]
+    pub fn from_function(function: &Bound<'_, PyAny>) -> PyResult<Option<Self>> {
+        let function: Bdd<String> = extract_update_function(function)?;
+        Ok(SymmetricFunction::from_function(&function).map(Self::from))
+    }
+
+    pub fn inputs(&self) -> BTreeSet<String> {
+        self.root.inputs().clone()
+    }
+
+    pub fn values(&self) -> Vec<bool> {
+        self.root.values().to_vec()
+    }
+
+    /// Evaluates the function in the given `valuation`, missing inputs are treated as `False`.
+    pub fn evaluate(&self, valuation: BooleanValuation<String>) -> bool {
+        self.root.evaluate(&valuation)
+    }
+
+    pub fn to_bdd(&self) -> PyResult<PythonBdd> {
+        let bdd = self
+            .root
+            .to_bdd()
+            .map_err(|_e| PyRuntimeError::new_err("Conversion failed. Too many variables."))?;
+        Ok(bdd.into())
+    }
+
+    pub fn to_expression(&self) -> PyResult<PythonExpression> {
+        let expression = self
+            .root
+            .to_expression()
+            .map_err(|_e| PyRuntimeError::new_err("Conversion failed. Too many variables."))?;
+        Ok(expression.into())
+    }
+
+    pub fn to_table(&self) -> PythonTruthTable {
+        self.root.to_table().into()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!(
+            "SymmetricFunction({:?}, {:?})",
+            self.root.inputs(),
+            self.root.values()
+        )
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
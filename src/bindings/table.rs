@@ -1,12 +1,16 @@
 use num_bigint::BigUint;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::bdd::Bdd;
 use crate::bindings::bdd::PythonBdd;
-use pyo3::PyResult;
+use pyo3::prelude::{PyAnyMethods, PyResult};
+use pyo3::{Bound, PyAny};
 
+use crate::bindings::canalization::into_canalizing_tuples;
 use crate::bindings::error::PythonExpressionError::UnknownVariableWhileEvaluating;
 use crate::bindings::expression::PythonExpression;
+use crate::bindings::fraction::{into_probabilities, PythonFraction};
 use crate::bindings::iterators::{
     PythonDomainIterator, PythonTableRangeIterator, PythonTableRelationIterator,
     PythonTableSupportIterator,
@@ -15,7 +19,9 @@ use crate::expressions::Expression as RustExpression;
 use crate::table::display_formatted::{TableBooleanFormatting, TableStyle};
 use crate::table::TruthTable;
 use crate::traits::{
-    BooleanFunction, BooleanPoint, BooleanValuation, Evaluate, GatherLiterals, SemanticEq,
+    BooleanFunction, BooleanPoint, BooleanValuation, Canalization, Evaluate, FourierMeasures,
+    GatherLiterals, Monotonicity, RegulationSign, SampleSupport, SemanticEq, Symmetry,
+    WeightedCount,
 };
 
 #[pyo3::pyclass(frozen, name = "Table")]
@@ -53,6 +59,32 @@ impl PythonTruthTable {
             .to_string_formatted(style, boolean_formatting, boolean_formatting)
     }
 
+    /// Renders the table as a Karnaugh map with Gray-code axes. Supports 2 to 6 variables.
+    ///
+    /// When a `cover` in DNF is given, each cell lists the (1-based) indices of the clauses
+    /// that contain it and a legend of the clauses is appended.
+    ///
+    /// Throws a `ValueError` for unsupported variable counts or an invalid cover.
+    #[pyo3(signature = (style, boolean_formatting, cover = None))]
+    pub fn to_karnaugh_map(
+        &self,
+        style: TableStyle,
+        boolean_formatting: TableBooleanFormatting,
+        cover: Option<PythonExpression>,
+    ) -> PyResult<String> {
+        Ok(match cover {
+            None => self
+                .root
+                .to_karnaugh_map(style, boolean_formatting, boolean_formatting)?,
+            Some(cover) => self.root.to_karnaugh_map_with_cover(
+                style,
+                boolean_formatting,
+                boolean_formatting,
+                &cover.into(),
+            )?,
+        })
+    }
+
     pub fn gather_literals(&self) -> BTreeSet<String> {
         self.root.gather_literals()
     }
@@ -106,6 +138,110 @@ impl PythonTruthTable {
         self.root.to_csv()
     }
 
+    /// Parses a table in the layout of `to_string_formatted`. When no `style` is given,
+    /// it is detected from the first line of the `input`.
+    #[staticmethod]
+    #[pyo3(signature = (input, style = None))]
+    pub fn from_formatted(input: &str, style: Option<TableStyle>) -> PyResult<Self> {
+        let table = match style {
+            None => TruthTable::from_formatted(input)?,
+            Some(style) => TruthTable::from_formatted_with_style(input, style)?,
+        };
+        Ok(Self::new(table))
+    }
+
+    /// Creates a table from the given outputs, indexed with the first of the `inputs`
+    /// as the most significant bit.
+    ///
+    /// Throws a `ValueError` for duplicate inputs or a wrong number of outputs.
+    #[staticmethod]
+    pub fn from_outputs(inputs: Vec<String>, outputs: Vec<bool>) -> PyResult<Self> {
+        Ok(Self::new(TruthTable::from_outputs(inputs, outputs)?))
+    }
+
+    /// Creates a table by calling `function` with a dictionary valuation of the `inputs`
+    /// for every row.
+    ///
+    /// Throws a `ValueError` for duplicate inputs and propagates errors raised by `function`.
+    #[staticmethod]
+    pub fn from_function(inputs: Vec<String>, function: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let error = RefCell::new(None);
+        let table = TruthTable::from_fn(inputs, |valuation| {
+            if error.borrow().is_some() {
+                return false;
+            }
+            match function
+                .call1((valuation.clone(),))
+                .and_then(|result| result.is_truthy())
+            {
+                Ok(result) => result,
+                Err(e) => {
+                    error.replace(Some(e));
+                    false
+                }
+            }
+        })?;
+
+        match error.into_inner() {
+            Some(e) => Err(e),
+            None => Ok(Self::new(table)),
+        }
+    }
+
+    /// Creates a table which is `true` exactly for the given minterms, indexed with the first
+    /// of the `inputs` as the most significant bit.
+    ///
+    /// Throws a `ValueError` for duplicate inputs or minterms out of range.
+    #[staticmethod]
+    pub fn from_minterms(inputs: Vec<String>, minterms: Vec<usize>) -> PyResult<Self> {
+        Ok(Self::new(TruthTable::from_minterms(inputs, &minterms)?))
+    }
+
+    /// Creates a table which is `false` exactly for the given maxterms, indexed with the first
+    /// of the `inputs` as the most significant bit.
+    ///
+    /// Throws a `ValueError` for duplicate inputs or maxterms out of range.
+    #[staticmethod]
+    pub fn from_maxterms(inputs: Vec<String>, maxterms: Vec<usize>) -> PyResult<Self> {
+        Ok(Self::new(TruthTable::from_maxterms(inputs, &maxterms)?))
+    }
+
+    /// Creates a table from a hexadecimal truth table string in the ABC convention, i.e.
+    /// with the first of the `inputs` as the least significant bit. For example, `a & b`
+    /// is `8` and `a & !b` is `2`.
+    ///
+    /// Throws a `ValueError` for duplicate inputs or an invalid string.
+    #[staticmethod]
+    pub fn from_hex(inputs: Vec<String>, hex: &str) -> PyResult<Self> {
+        Ok(Self::new(TruthTable::from_hex(inputs, hex)?))
+    }
+
+    /// Creates a table from a binary truth table string in the ABC convention, i.e.
+    /// with the first of the `inputs` as the least significant bit. For example, `a & !b`
+    /// is `0010`.
+    ///
+    /// Throws a `ValueError` for duplicate inputs or an invalid string.
+    #[staticmethod]
+    pub fn from_binary_string(inputs: Vec<String>, binary: &str) -> PyResult<Self> {
+        Ok(Self::new(TruthTable::from_binary_string(inputs, binary)?))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.root.to_hex()
+    }
+
+    pub fn to_binary_string(&self) -> String {
+        self.root.to_binary_string()
+    }
+
+    pub fn minterms(&self) -> Vec<usize> {
+        self.root.minterms()
+    }
+
+    pub fn maxterms(&self) -> Vec<usize> {
+        self.root.maxterms()
+    }
+
     pub fn __str__(&self) -> String {
         self.root.to_string()
     }
@@ -227,6 +363,114 @@ impl PythonTruthTable {
         self.root.weight()
     }
 
+    /// The probability that this function evaluates to `1` when each input is independently
+    /// `true` with the probability given in `probabilities` (a `Fraction`, `int` or `float`
+    /// between `0` and `1`). Inputs without a given probability are `true` with probability `1/2`.
+    ///
+    /// The result is an exact `Fraction`.
+    fn probability(
+        &self,
+        probabilities: BTreeMap<String, PythonFraction>,
+    ) -> PyResult<PythonFraction> {
+        let probabilities = into_probabilities(probabilities)?;
+        Ok(self.root.probability(&probabilities).into())
+    }
+
+    /// The effect of `variable` on the output of this function: `Activating`, `Inhibiting`,
+    /// `Dual` or `NonObservable` (also for variables that are not inputs).
+    fn regulation_sign(&self, variable: String) -> RegulationSign {
+        self.root.regulation_sign(&variable)
+    }
+
+    /// The `RegulationSign` of every input of this function.
+    fn regulation_signs(&self) -> BTreeMap<String, RegulationSign> {
+        self.root.regulation_signs()
+    }
+
+    /// Returns `True` if increasing `variable` never decreases the output.
+    fn is_positive_unate_in(&self, variable: String) -> bool {
+        self.root.is_positive_unate_in(&variable)
+    }
+
+    /// Returns `True` if increasing `variable` never increases the output.
+    fn is_negative_unate_in(&self, variable: String) -> bool {
+        self.root.is_negative_unate_in(&variable)
+    }
+
+    /// Returns `True` if this function is (positive) monotone, i.e. it is positive unate
+    /// in every input.
+    fn is_monotone(&self) -> bool {
+        self.root.is_monotone()
+    }
+
+    /// The least monotone function above this function.
+    fn upper_monotone_approximation(&self) -> PythonTruthTable {
+        self.root.upper_monotone_approximation().into()
+    }
+
+    /// The greatest monotone function below this function.
+    fn lower_monotone_approximation(&self) -> PythonTruthTable {
+        self.root.lower_monotone_approximation().into()
+    }
+
+    /// The monotone closure of this function, i.e. its upper monotone approximation.
+    fn monotone_closure(&self) -> PythonTruthTable {
+        self.root.monotone_closure().into()
+    }
+
+    /// The unique minimal DNF of a monotone function (the disjunction of all its prime
+    /// implicants), or `None` if this function is not monotone.
+    fn minimal_positive_dnf(&self) -> Option<PythonExpression> {
+        self.root.minimal_positive_dnf().map(PythonExpression::from)
+    }
+
+    /// All canalizing inputs of this function as `(variable, value, output)` tuples: setting
+    /// `variable` to `value` fixes the output of the function to `output`.
+    fn canalizing_variables(&self) -> Vec<(String, bool, bool)> {
+        into_canalizing_tuples(self.root.canalizing_variables())
+    }
+
+    /// The layers of a nested canalizing function (each a list of `(variable, value, output)`
+    /// tuples), or `None` if this function is not nested canalizing.
+    fn nested_canalizing_layers(&self) -> Option<Vec<Vec<(String, bool, bool)>>> {
+        self.root
+            .nested_canalizing_layers()
+            .map(|layers| layers.into_iter().map(into_canalizing_tuples).collect())
+    }
+
+    fn is_nested_canalizing(&self) -> bool {
+        self.root.is_nested_canalizing()
+    }
+
+    /// The number of variables which are canalizing in the nested sense.
+    fn canalizing_depth(&self) -> usize {
+        self.root.canalizing_depth()
+    }
+
+    fn is_symmetric_in(&self, first: String, second: String) -> bool {
+        self.root.is_symmetric_in(&first, &second)
+    }
+
+    fn is_negation_symmetric_in(&self, first: String, second: String) -> bool {
+        self.root.is_negation_symmetric_in(&first, &second)
+    }
+
+    /// The maximal classes of inputs in which this function is symmetric.
+    fn symmetry_classes(&self) -> Vec<BTreeSet<String>> {
+        self.root.symmetry_classes()
+    }
+
+    /// The maximal classes of inputs in which this function is symmetric when negations are
+    /// allowed. Every variable is mapped to `True` if it is negated relative to the smallest
+    /// variable of its class.
+    fn negation_symmetry_classes(&self) -> Vec<BTreeMap<String, bool>> {
+        self.root.negation_symmetry_classes()
+    }
+
+    fn is_totally_symmetric(&self) -> bool {
+        self.root.is_totally_symmetric()
+    }
+
     /// Create a Boolean function that is a restriction of this function for the given variables.
     ///
     /// A restriction fixes all variables specified by the `valuation` to their respective
@@ -278,6 +522,22 @@ impl PythonTruthTable {
         self.root.sat_point()
     }
 
+    /// Draws `count` uniformly random points for which this function evaluates to `1`
+    /// (independently, i.e. with replacement). The same `seed` always produces the same points.
+    ///
+    /// If a `restriction` is given, only the points which agree with it are sampled. Returns
+    /// an empty list if there is no such point.
+    #[pyo3(signature = (count, seed, restriction = None))]
+    fn sample_support(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: Option<BooleanValuation<String>>,
+    ) -> Vec<BooleanPoint> {
+        self.root
+            .sample_support_restricted(count, seed, &restriction.unwrap_or_default())
+    }
+
     /// Eliminate the specified `variables` using *existential* quantification. The resulting
     /// function does not depend on any of the eliminated variables.
     ///
@@ -324,6 +584,53 @@ impl PythonTruthTable {
         self.root.is_implied_by(&other.root)
     }
 
+    /// The influence of the given `variable`, i.e. the probability that flipping the value
+    /// of `variable` in a uniformly random input point changes the output of the function.
+    fn influence(&self, variable: String) -> PythonFraction {
+        self.root.influence(&variable).into()
+    }
+
+    /// The sum of influences of all inputs. Same as the average sensitivity.
+    fn total_influence(&self) -> PythonFraction {
+        self.root.total_influence().into()
+    }
+
+    /// The maximal number of variables that change the output of the function when flipped
+    /// individually, taken over all input points.
+    fn max_sensitivity(&self) -> usize {
+        self.root.max_sensitivity()
+    }
+
+    /// The expected number of variables that change the output of the function when flipped
+    /// individually, taken over a uniformly random input point.
+    fn average_sensitivity(&self) -> PythonFraction {
+        self.root.average_sensitivity().into()
+    }
+
+    /// The maximal number of disjoint blocks of variables that change the output of the function
+    /// when flipped together, taken over all input points.
+    ///
+    /// The operation is exponential in the number of inputs.
+    fn max_block_sensitivity(&self) -> usize {
+        self.root.max_block_sensitivity()
+    }
+
+    /// The expected block sensitivity of a uniformly random input point.
+    ///
+    /// The operation is exponential in the number of inputs.
+    fn average_block_sensitivity(&self) -> PythonFraction {
+        self.root.average_block_sensitivity().into()
+    }
+
+    /// The noise stability of this function when each input is flipped independently with
+    /// the given `flip_probability` (a `Fraction`, `int` or `float` between `0` and `1`).
+    ///
+    /// The result is the expected value of `F(x) * F(y)` with outputs interpreted as `1` (false)
+    /// and `-1` (true).
+    fn noise_stability(&self, flip_probability: PythonFraction) -> PyResult<PythonFraction> {
+        Ok(self.root.noise_stability(&flip_probability.0)?.into())
+    }
+
     #[staticmethod]
     pub fn from_expression(expression: &PythonExpression) -> Self {
         let rust_expression: RustExpression<String> = expression.into();
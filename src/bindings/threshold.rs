@@ -0,0 +1,129 @@
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::{pyclass, pymethods, Bound, IntoPy, PyAny, PyObject, PyResult, Python};
+use std::collections::BTreeMap;
+
+use crate::bindings::bdd::PythonBdd;
+use crate::bindings::expression::PythonExpression;
+use crate::bindings::network::extract_update_function;
+use crate::threshold::{AsummabilityWitness, ThresholdFunction, THRESHOLD_RECOGNITION_MAX_INPUTS};
+use crate::traits::{BooleanFunction, BooleanValuation};
+
+#[pyclass(frozen, name = "ThresholdFunction")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonThresholdFunction {
+    root: ThresholdFunction<String>,
+}
+
+impl From<ThresholdFunction<String>> for PythonThresholdFunction {
+    fn from(value: ThresholdFunction<String>) -> Self {
+        PythonThresholdFunction { root: value }
+    }
+}
+
+#[pyclass(frozen, name = "AsummabilityWitness")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonAsummabilityWitness {
+    root: AsummabilityWitness<String>,
+}
+
+impl From<AsummabilityWitness<String>> for PythonAsummabilityWitness {
+    fn from(value: AsummabilityWitness<String>) -> Self {
+        PythonAsummabilityWitness { root: value }
+    }
+}
+
+#[pymethods]
+impl PythonThresholdFunction {
+    /// Creates the function `sum(weights[v] * v) >= threshold`.
+    #[new]
+    pub fn py_new(weights: BTreeMap<String, i64>, threshold: i64) -> Self {
+        ThresholdFunction::new(weights, threshold).into()
+    }
+
+    /// Decides whether `function` (an `Expression`, `Bdd`, `Table` or `Zdd`) is a linear
+    /// threshold function. Returns its `ThresholdFunction` if it is, and
+    /// an `AsummabilityWitness` otherwise.
+    ///
+    /// Throws a `ValueError` if the function has more than 20 inputs.
+    #[staticmethod]
+    pub fn recognize(py: Python, function: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let function = extract_update_function(function)?;
+        let input_count = function.inputs().len();
+        if input_count > THRESHOLD_RECOGNITION_MAX_INPUTS {
+            return Err(PyValueError::new_err(format!(
+                "Threshold recognition supports at most {THRESHOLD_RECOGNITION_MAX_INPUTS} inputs, found {input_count}"
+            )));
+        }
+        Ok(match ThresholdFunction::recognize(&function) {
+            Ok(result) => PythonThresholdFunction::from(result).into_py(py),
+            Err(witness) => PythonAsummabilityWitness::from(witness).into_py(py),
+        })
+    }
+
+    pub fn weights(&self) -> BTreeMap<String, i64> {
+        self.root.weights().clone()
+    }
+
+    pub fn threshold(&self) -> i64 {
+        self.root.threshold()
+    }
+
+    /// Evaluates the function in the given `valuation`, missing inputs are treated as `False`.
+    pub fn evaluate(&self, valuation: BooleanValuation<String>) -> bool {
+        self.root.evaluate(&valuation)
+    }
+
+    pub fn to_bdd(&self) -> PyResult<PythonBdd> {
+        let bdd = self
+            .root
+            .to_bdd()
+            .map_err(|_e| PyRuntimeError::new_err("Conversion failed. Too many variables."))?;
+        Ok(bdd.into())
+    }
+
+    pub fn to_expression(&self) -> PyResult<PythonExpression> {
+        let expression = self
+            .root
+            .to_expression()
+            .map_err(|_e| PyRuntimeError::new_err("Conversion failed. Too many variables."))?;
+        Ok(expression.into())
+    }
+
+    pub fn __str__(&self) -> String {
+        format!(
+            "ThresholdFunction({:?} >= {})",
+            self.root.weights(),
+            self.root.threshold()
+        )
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+#[pymethods]
+impl PythonAsummabilityWitness {
+    /// The points in which the function is satisfied.
+    pub fn true_points(&self) -> Vec<BooleanValuation<String>> {
+        self.root.true_points().to_vec()
+    }
+
+    /// The points in which the function is not satisfied. Their component-wise sum
+    /// equals the sum of the `true_points`.
+    pub fn false_points(&self) -> Vec<BooleanValuation<String>> {
+        self.root.false_points().to_vec()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!(
+            "AsummabilityWitness({} true points, {} false points)",
+            self.root.true_points().len(),
+            self.root.false_points().len()
+        )
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
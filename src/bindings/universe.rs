@@ -0,0 +1,141 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::{pyclass, pymethods, PyResult};
+
+use crate::bdd::universe::{BddUniverse, UniverseBdd};
+use crate::bdd::Bdd;
+use crate::bindings::bdd::PythonBdd;
+
+#[pyclass(frozen, name = "BddUniverse")]
+#[derive(Clone, Debug)]
+pub struct PythonBddUniverse {
+    root: BddUniverse<String>,
+}
+
+#[pyclass(frozen, name = "UniverseBdd")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonUniverseBdd {
+    root: UniverseBdd<String>,
+}
+
+#[pymethods]
+impl PythonBddUniverse {
+    /// Creates a universe with a fixed set of variables. BDDs created in the same universe
+    /// can be combined without extending their variable sets first.
+    #[new]
+    pub fn py_new(variables: Vec<String>) -> PyResult<Self> {
+        Ok(PythonBddUniverse {
+            root: BddUniverse::new(variables)?,
+        })
+    }
+
+    pub fn variables(&self) -> Vec<String> {
+        self.root.variables().to_vec()
+    }
+
+    pub fn mk_const(&self, value: bool) -> PythonUniverseBdd {
+        PythonUniverseBdd::new(self.root.mk_const(value))
+    }
+
+    /// Throws a `KeyError` when the variable is not part of the universe.
+    pub fn mk_literal(&self, variable: String, value: bool) -> PyResult<PythonUniverseBdd> {
+        Ok(PythonUniverseBdd::new(
+            self.root.mk_literal(&variable, value)?,
+        ))
+    }
+
+    /// Moves a free-standing `Bdd` into this universe.
+    ///
+    /// Throws a `KeyError` when the `Bdd` uses a variable that is not part of the universe.
+    pub fn import_bdd(&self, bdd: &PythonBdd) -> PyResult<PythonUniverseBdd> {
+        let native: Bdd<String> = bdd.into();
+        Ok(PythonUniverseBdd::new(self.root.import(&native)?))
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("BddUniverse({:?})", self.root.variables())
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+#[pymethods]
+impl PythonUniverseBdd {
+    #[staticmethod]
+    pub fn mk_not(inner: &PythonUniverseBdd) -> PythonUniverseBdd {
+        PythonUniverseBdd::new(!&inner.root)
+    }
+
+    /// Throws a `ValueError` when the operands belong to different universes.
+    #[staticmethod]
+    pub fn mk_and(left: &PythonUniverseBdd, right: &PythonUniverseBdd) -> PyResult<Self> {
+        ensure_same_universe(left, right)?;
+        Ok(PythonUniverseBdd::new(&left.root & &right.root))
+    }
+
+    /// Throws a `ValueError` when the operands belong to different universes.
+    #[staticmethod]
+    pub fn mk_or(left: &PythonUniverseBdd, right: &PythonUniverseBdd) -> PyResult<Self> {
+        ensure_same_universe(left, right)?;
+        Ok(PythonUniverseBdd::new(&left.root | &right.root))
+    }
+
+    /// Throws a `ValueError` when the operands belong to different universes.
+    #[staticmethod]
+    pub fn mk_xor(left: &PythonUniverseBdd, right: &PythonUniverseBdd) -> PyResult<Self> {
+        ensure_same_universe(left, right)?;
+        Ok(PythonUniverseBdd::new(&left.root ^ &right.root))
+    }
+
+    pub fn universe(&self) -> PythonBddUniverse {
+        PythonBddUniverse {
+            root: self.root.universe().clone(),
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.root.node_count()
+    }
+
+    pub fn is_true(&self) -> bool {
+        self.root.is_true()
+    }
+
+    pub fn is_false(&self) -> bool {
+        self.root.is_false()
+    }
+
+    /// Converts this BDD into a free-standing `Bdd` over its essential inputs.
+    pub fn to_bdd(&self) -> PythonBdd {
+        PythonBdd::new(self.root.to_bdd())
+    }
+
+    pub fn __eq__(&self, other: &PythonUniverseBdd) -> bool {
+        self.root == other.root
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("{:?}", self.root.to_bdd())
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+}
+
+impl PythonUniverseBdd {
+    pub fn new(root: UniverseBdd<String>) -> PythonUniverseBdd {
+        PythonUniverseBdd { root }
+    }
+}
+
+fn ensure_same_universe(left: &PythonUniverseBdd, right: &PythonUniverseBdd) -> PyResult<()> {
+    if left.root.same_universe(&right.root) {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(
+            "Cannot combine BDDs from different universes.",
+        ))
+    }
+}
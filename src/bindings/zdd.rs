@@ -0,0 +1,392 @@
+use num_bigint::BigUint;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::{pyclass, pymethods, PyResult};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::bdd::Bdd;
+use crate::bindings::bdd::PythonBdd;
+use crate::bindings::error::PythonExpressionError::UnknownVariableWhileEvaluating;
+use crate::bindings::expression::PythonExpression;
+use crate::bindings::fraction::{into_probabilities, PythonFraction};
+use crate::bindings::iterators::{
+    PythonDomainIterator, PythonZddRangeIterator, PythonZddRelationIterator,
+    PythonZddSupportIterator,
+};
+use crate::bindings::table::PythonTruthTable;
+use crate::expressions::Expression;
+use crate::table::TruthTable;
+use crate::traits::{
+    BooleanFunction, BooleanPoint, BooleanValuation, Evaluate, Monotonicity, RegulationSign,
+    SampleSupport, WeightedCount,
+};
+use crate::zdd::Zdd;
+
+/// A zero-suppressed decision diagram. It can be used both as a Boolean function (bit
+/// operators, `BooleanFunction` methods) and as a family of sets of its inputs (`union`,
+/// `intersection`, `difference`, `join`, `meet`, `minimal`, `maximal`).
+#[pyclass(frozen, name = "Zdd", module = "biodivine_boolean_functions")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythonZdd {
+    root: Zdd<String>,
+}
+
+impl From<Zdd<String>> for PythonZdd {
+    fn from(value: Zdd<String>) -> Self {
+        PythonZdd::new(value)
+    }
+}
+
+impl From<PythonZdd> for Zdd<String> {
+    fn from(value: PythonZdd) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&PythonZdd> for Zdd<String> {
+    fn from(value: &PythonZdd) -> Self {
+        value.root.clone()
+    }
+}
+
+#[pymethods]
+impl PythonZdd {
+    #[staticmethod]
+    pub fn mk_not(inner: &PythonZdd) -> PythonZdd {
+        PythonZdd::new(!(&inner.root))
+    }
+
+    #[staticmethod]
+    pub fn mk_and(left: &PythonZdd, right: &PythonZdd) -> PythonZdd {
+        PythonZdd::new(&left.root & &right.root)
+    }
+
+    #[staticmethod]
+    pub fn mk_or(left: &PythonZdd, right: &PythonZdd) -> PythonZdd {
+        PythonZdd::new(&left.root | &right.root)
+    }
+
+    #[staticmethod]
+    pub fn mk_xor(left: &PythonZdd, right: &PythonZdd) -> PythonZdd {
+        PythonZdd::new(&left.root ^ &right.root)
+    }
+
+    #[staticmethod]
+    pub fn mk_const(value: bool) -> PythonZdd {
+        PythonZdd::new(Zdd::mk_const(value))
+    }
+
+    #[staticmethod]
+    pub fn mk_literal(variable: &str, value: bool) -> PythonZdd {
+        PythonZdd::new(Zdd::mk_literal(variable.to_string(), value))
+    }
+
+    /// Creates the `Zdd` of the given family of `sets`. Its inputs are all the variables
+    /// that appear in some of the sets.
+    #[staticmethod]
+    pub fn from_sets(sets: Vec<BTreeSet<String>>) -> PythonZdd {
+        PythonZdd::new(Zdd::from_sets(sets))
+    }
+
+    /// The family of sets represented by this `Zdd`, i.e. for each satisfying point,
+    /// the set of variables which are `true`.
+    pub fn sets(&self) -> Vec<BTreeSet<String>> {
+        self.root.sets()
+    }
+
+    /// The number of sets in the family represented by this `Zdd`.
+    pub fn set_count(&self) -> BigUint {
+        self.root.set_count()
+    }
+
+    /// The sets which are in `self` or in `other`.
+    pub fn union(&self, other: &PythonZdd) -> PythonZdd {
+        self.root.union(&other.root).into()
+    }
+
+    /// The sets which are both in `self` and in `other`.
+    pub fn intersection(&self, other: &PythonZdd) -> PythonZdd {
+        self.root.intersection(&other.root).into()
+    }
+
+    /// The sets which are in `self` but not in `other`.
+    pub fn difference(&self, other: &PythonZdd) -> PythonZdd {
+        self.root.difference(&other.root).into()
+    }
+
+    /// The family `{ a ∪ b | a ∈ self, b ∈ other }`.
+    pub fn join(&self, other: &PythonZdd) -> PythonZdd {
+        self.root.join(&other.root).into()
+    }
+
+    /// The family `{ a ∩ b | a ∈ self, b ∈ other }`.
+    pub fn meet(&self, other: &PythonZdd) -> PythonZdd {
+        self.root.meet(&other.root).into()
+    }
+
+    /// The sets of this family which have no proper subset in this family.
+    pub fn minimal(&self) -> PythonZdd {
+        self.root.minimal().into()
+    }
+
+    /// The sets of this family which have no proper superset in this family.
+    pub fn maximal(&self) -> PythonZdd {
+        self.root.maximal().into()
+    }
+
+    /// Throws a `KeyError` when a variable is encountered that isn't found among
+    /// the given `literal_values`.
+    pub fn evaluate_checked(&self, literal_values: BTreeMap<String, bool>) -> PyResult<bool> {
+        Ok(self
+            .root
+            .evaluate_checked(&literal_values)
+            .map_err(|name| UnknownVariableWhileEvaluating { name })?)
+    }
+
+    /// Variables not in the dictionary default to false.
+    pub fn evaluate_safe(&self, literal_values: BTreeMap<String, bool>) -> bool {
+        self.root.evaluate(&literal_values)
+    }
+
+    /// Variables not in the dictionary defaults to the passed `default_value` argument.
+    pub fn evaluate_with_default(
+        &self,
+        literal_values: BTreeMap<String, bool>,
+        default_value: bool,
+    ) -> bool {
+        self.root
+            .evaluate_with_default(&literal_values, default_value)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.root.node_count()
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("{:?}", self.root)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+
+    /// A set of all the variable instances that (syntactically) appear in this Boolean
+    /// function.
+    fn inputs(&self) -> BTreeSet<String> {
+        self.root.inputs()
+    }
+
+    /// A set of all variable instances that are *essential* in this Boolean function.
+    fn essential_inputs(&self) -> BTreeSet<String> {
+        self.root.essential_inputs()
+    }
+
+    /// The number of variables that (syntactically) appear in this Boolean function.
+    fn degree(&self) -> usize {
+        self.root.degree()
+    }
+
+    /// The number of variables that are essential in this Boolean function.
+    fn essential_degree(&self) -> usize {
+        self.root.essential_degree()
+    }
+
+    /// The iterator over Boolean points that are valid as inputs for this Boolean function.
+    fn domain(&self) -> PythonDomainIterator {
+        self.root.domain().into()
+    }
+
+    /// The iterator over all the output values of this Boolean function.
+    fn image(&self) -> PythonZddRangeIterator {
+        self.root.image().into()
+    }
+
+    /// The combined iterator of all input points together with their corresponding outputs.
+    fn relation(&self) -> PythonZddRelationIterator {
+        self.root.relation().into()
+    }
+
+    /// The iterator over all Boolean points for which this function evaluates to `1`.
+    fn support(&self) -> PythonZddSupportIterator {
+        self.root.support().into()
+    }
+
+    /// The number of input points for which this function evaluates to `1`.
+    fn weight(&self) -> BigUint {
+        self.root.weight()
+    }
+
+    /// The probability that this function evaluates to `1` when each input is independently
+    /// `true` with the probability given in `probabilities` (a `Fraction`, `int` or `float`
+    /// between `0` and `1`). Inputs without a given probability are `true` with probability `1/2`.
+    ///
+    /// The result is an exact `Fraction`.
+    fn probability(
+        &self,
+        probabilities: BTreeMap<String, PythonFraction>,
+    ) -> PyResult<PythonFraction> {
+        let probabilities = into_probabilities(probabilities)?;
+        Ok(self.root.probability(&probabilities).into())
+    }
+
+    /// The effect of `variable` on the output of this function: `Activating`, `Inhibiting`,
+    /// `Dual` or `NonObservable` (also for variables that are not inputs).
+    fn regulation_sign(&self, variable: String) -> RegulationSign {
+        self.root.regulation_sign(&variable)
+    }
+
+    /// The `RegulationSign` of every input of this function.
+    fn regulation_signs(&self) -> BTreeMap<String, RegulationSign> {
+        self.root.regulation_signs()
+    }
+
+    /// Returns `True` if increasing `variable` never decreases the output.
+    fn is_positive_unate_in(&self, variable: String) -> bool {
+        self.root.is_positive_unate_in(&variable)
+    }
+
+    /// Returns `True` if increasing `variable` never increases the output.
+    fn is_negative_unate_in(&self, variable: String) -> bool {
+        self.root.is_negative_unate_in(&variable)
+    }
+
+    /// Returns `True` if this function is (positive) monotone, i.e. it is positive unate
+    /// in every input.
+    fn is_monotone(&self) -> bool {
+        self.root.is_monotone()
+    }
+
+    /// The least monotone function above this function.
+    fn upper_monotone_approximation(&self) -> PythonZdd {
+        self.root.upper_monotone_approximation().into()
+    }
+
+    /// The greatest monotone function below this function.
+    fn lower_monotone_approximation(&self) -> PythonZdd {
+        self.root.lower_monotone_approximation().into()
+    }
+
+    /// The monotone closure of this function, i.e. its upper monotone approximation.
+    fn monotone_closure(&self) -> PythonZdd {
+        self.root.monotone_closure().into()
+    }
+
+    /// The unique minimal DNF of a monotone function (the disjunction of all its prime
+    /// implicants), or `None` if this function is not monotone.
+    fn minimal_positive_dnf(&self) -> Option<PythonExpression> {
+        self.root.minimal_positive_dnf().map(PythonExpression::from)
+    }
+
+    /// Create a Boolean function that is a restriction of this function for the given variables.
+    fn restrict(&self, valuation: BooleanValuation<String>) -> Self {
+        self.root.restrict(&valuation).into()
+    }
+
+    /// Create a Boolean function in which the variables specified by `mapping` are substituted
+    /// for their supplied functions. The substitution is simultaneous.
+    fn substitute(&self, mapping: BTreeMap<String, Self>) -> PyResult<Self> {
+        let to_bdd = |zdd: Zdd<String>| {
+            Bdd::try_from(zdd)
+                .map_err(|_e| PyRuntimeError::new_err("Substitution failed. Too many variables."))
+        };
+        let bdd_mapping = mapping
+            .into_iter()
+            .map(|(var, zdd)| Ok((var, to_bdd(zdd.root)?)))
+            .collect::<PyResult<BTreeMap<_, _>>>()?;
+
+        let result = to_bdd(self.root.clone())?.try_substitute(&bdd_mapping)?;
+        Ok(Zdd::from(result).into())
+    }
+
+    /// Produce one [BooleanPoint] for which this function evaluates to `1`, or `None` if
+    /// the function is not satisfiable.
+    fn sat_point(&self) -> Option<BooleanPoint> {
+        self.root.sat_point()
+    }
+
+    /// Draws `count` uniformly random points for which this function evaluates to `1`
+    /// (independently, i.e. with replacement). The same `seed` always produces the same points.
+    ///
+    /// If a `restriction` is given, only the points which agree with it are sampled. Returns
+    /// an empty list if there is no such point.
+    #[pyo3(signature = (count, seed, restriction = None))]
+    fn sample_support(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: Option<BooleanValuation<String>>,
+    ) -> Vec<BooleanPoint> {
+        self.root
+            .sample_support_restricted(count, seed, &restriction.unwrap_or_default())
+    }
+
+    /// Eliminate the specified `variables` using *existential* quantification.
+    fn existential_quantification(&self, variables: BTreeSet<String>) -> Self {
+        self.root.existential_quantification(variables).into()
+    }
+
+    /// Eliminate the specified `variables` using *universal* quantification.
+    fn universal_quantification(&self, variables: BTreeSet<String>) -> Self {
+        self.root.universal_quantification(variables).into()
+    }
+
+    /// Computes the derivative of this function with respect to the given `variables`.
+    fn derivative(&self, variables: BTreeSet<String>) -> Self {
+        self.root.derivative(variables).into()
+    }
+
+    /// Returns `true` if the two functions are *semantically* equivalent.
+    fn is_equivalent(&self, other: &Self) -> bool {
+        self.root.is_equivalent(&other.root)
+    }
+
+    /// Returns `true` if this function is *implied* by the `other` function.
+    fn is_implied_by(&self, other: &Self) -> bool {
+        self.root.is_implied_by(&other.root)
+    }
+
+    #[staticmethod]
+    pub fn from_expression(expression: &PythonExpression) -> PyResult<Self> {
+        let native: Expression<String> = expression.into();
+        match Zdd::try_from(native) {
+            Ok(zdd) => Ok(Self::new(zdd)),
+            Err(_e) => Err(PyRuntimeError::new_err(
+                "Conversion failed. Too many variables.",
+            )),
+        }
+    }
+
+    #[staticmethod]
+    pub fn from_table(table: &PythonTruthTable) -> Self {
+        let native: TruthTable<String> = table.into();
+        Self::new(Zdd::from(native))
+    }
+
+    #[staticmethod]
+    pub fn from_bdd(bdd: &PythonBdd) -> Self {
+        let native: Bdd<String> = bdd.into();
+        Self::new(Zdd::from(native))
+    }
+
+    pub fn to_expression(&self) -> PythonExpression {
+        Expression::from(self.root.clone()).into()
+    }
+
+    pub fn to_table(&self) -> PythonTruthTable {
+        TruthTable::from(self.root.clone()).into()
+    }
+
+    pub fn to_bdd(&self) -> PyResult<PythonBdd> {
+        match Bdd::try_from(self.root.clone()) {
+            Ok(bdd) => Ok(bdd.into()),
+            Err(_e) => Err(PyRuntimeError::new_err(
+                "Conversion failed. Too many variables.",
+            )),
+        }
+    }
+}
+
+impl PythonZdd {
+    pub fn new(root: Zdd<String>) -> PythonZdd {
+        PythonZdd { root }
+    }
+}
@@ -1,4 +1,5 @@
 pub use structs::{Expression, ExpressionNode};
+pub use traits::BddToExpressionStrategy;
 
 mod iterators;
 mod structs;
@@ -0,0 +1,70 @@
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::traits::{Canalization, CanalizingInput};
+use std::fmt::Debug;
+
+/// All operations convert the expression into a `Bdd` (once per call), so they panic if
+/// the expression has more than `2^16` inputs.
+impl<T: Debug + Clone + Ord + 'static> Canalization<T> for Expression<T> {
+    fn canalized_output(&self, variable: &T, value: bool) -> Option<bool> {
+        to_bdd(self).canalized_output(variable, value)
+    }
+
+    fn canalizing_variables(&self) -> Vec<CanalizingInput<T>> {
+        to_bdd(self).canalizing_variables()
+    }
+
+    fn nested_canalizing_layers(&self) -> Option<Vec<Vec<CanalizingInput<T>>>> {
+        to_bdd(self).nested_canalizing_layers()
+    }
+
+    fn canalizing_depth(&self) -> usize {
+        to_bdd(self).canalizing_depth()
+    }
+}
+
+fn to_bdd<T: Debug + Clone + Ord + 'static>(expression: &Expression<T>) -> Bdd<T> {
+    Bdd::try_from(expression.clone()).expect("Expression should have at most 2^16 inputs")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::traits::{Canalization, CanalizingInput};
+
+    #[test]
+    fn test_canalization_ok() {
+        // `a` canalizes to `false`, then `b` and `c` canalize to `true`. The input `d` only
+        // appears in a contradictory term.
+        let function = !var("a") & (var("b") | !var("c")) | var("d") & var("a") & !var("a");
+
+        assert_eq!(
+            function.nested_canalizing_layers(),
+            Some(vec![
+                vec![CanalizingInput {
+                    variable: "a".to_string(),
+                    value: true,
+                    output: false,
+                }],
+                vec![
+                    CanalizingInput {
+                        variable: "b".to_string(),
+                        value: true,
+                        output: true,
+                    },
+                    CanalizingInput {
+                        variable: "c".to_string(),
+                        value: false,
+                        output: true,
+                    },
+                ],
+            ])
+        );
+        assert_eq!(function.canalizing_depth(), 3);
+        assert_eq!(
+            function.canalized_output(&"a".to_string(), true),
+            Some(false)
+        );
+        assert_eq!(function.canalized_output(&"a".to_string(), false), None);
+    }
+}
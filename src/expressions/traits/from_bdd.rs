@@ -2,8 +2,42 @@ use crate::bdd::Bdd;
 use crate::expressions::{Expression, ExpressionNode};
 use std::fmt::Debug;
 
+/// The algorithm used to convert a `Bdd` into an `Expression`.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum BddToExpressionStrategy {
+    /// A disjunctive normal form built from the paths of the BDD by
+    /// `lib-bdd` (`Bdd::to_optimized_dnf`).
+    #[default]
+    OptimizedDnf,
+    /// An irredundant sum-of-products computed by the Minato–Morreale algorithm
+    /// (see [Bdd::isop_cubes]).
+    Isop,
+    /// An if-then-else structure following the nodes of the BDD (see [Bdd::to_ite_expression]).
+    ///
+    /// The subterms are only shared in memory. The `Expression` itself is a tree, so it can be
+    /// exponentially larger than the BDD, and so can its printed form. Use
+    /// [Bdd::to_factored_form] for a representation that is linear in the number of BDD nodes.
+    IfThenElse,
+}
+
 impl<T: Debug + Clone + Ord> From<Bdd<T>> for Expression<T> {
     fn from(value: Bdd<T>) -> Self {
+        Expression::from_bdd_with_strategy(&value, BddToExpressionStrategy::default())
+    }
+}
+
+impl<T: Debug + Clone + Ord> Expression<T> {
+    /// Converts `bdd` into an equivalent `Expression` using the given `strategy`.
+    pub fn from_bdd_with_strategy(bdd: &Bdd<T>, strategy: BddToExpressionStrategy) -> Self {
+        match strategy {
+            BddToExpressionStrategy::OptimizedDnf => Self::from_bdd_optimized_dnf(bdd),
+            BddToExpressionStrategy::Isop => bdd.to_isop_expression(None),
+            BddToExpressionStrategy::IfThenElse => bdd.to_ite_expression(),
+        }
+    }
+
+    fn from_bdd_optimized_dnf(value: &Bdd<T>) -> Self {
         if value.inner().is_true() {
             return ExpressionNode::Constant(true).into();
         } else if value.inner().is_false() {
@@ -42,6 +76,7 @@ impl<T: Debug + Clone + Ord> From<Bdd<T>> for Expression<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::expressions::var;
     use crate::traits::{BooleanFunction, SemanticEq};
     use biodivine_lib_bdd::BddVariableSet;
     use rstest::rstest;
@@ -95,4 +130,33 @@ mod tests {
         assert!(actual.is_equivalent(&expected));
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_expression_from_bdd_isop_strategy() {
+        let exp_string = "(a & b) | (!a & c) | (b & c)".to_string();
+        let bdd = Bdd::try_from(Expression::from_str(&exp_string).unwrap()).unwrap();
+
+        let expected = Expression::from_str(&exp_string).unwrap();
+        let actual = Expression::from_bdd_with_strategy(&bdd, BddToExpressionStrategy::Isop);
+
+        assert!(
+            actual.semantic_eq(&expected),
+            "expected: `{expected}`,\nactual: `{actual}`"
+        );
+        assert!(actual.is_dnf());
+        assert_eq!(actual.to_string(), "((!(a) & c) | (a & b))");
+    }
+
+    #[test]
+    fn test_expression_from_bdd_if_then_else_strategy() {
+        let expected = (var("a") ^ var("b") ^ var("c")) | (var("a") & var("d"));
+        let bdd = Bdd::try_from(expected.clone()).unwrap();
+
+        let actual = Expression::from_bdd_with_strategy(&bdd, BddToExpressionStrategy::IfThenElse);
+
+        assert!(
+            actual.semantic_eq(&expected),
+            "expected: `{expected}`,\nactual: `{actual}`"
+        );
+    }
 }
@@ -0,0 +1,122 @@
+use crate::expressions::{Expression, ExpressionNode};
+use crate::zdd::{fold_nodes, Zdd};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> From<Zdd<T>> for Expression<T> {
+    /// Follows the nodes of the `Zdd` like [crate::bdd::Bdd::to_ite_expression], with subterms
+    /// shared between parents. A variable skipped on an edge becomes a negative literal.
+    fn from(value: Zdd<T>) -> Self {
+        let inputs = value.inputs_slice();
+        let literal = |index: usize| -> Expression<T> {
+            ExpressionNode::Literal(inputs[index].clone()).into()
+        };
+
+        // The operand of a ZDD edge from `level` to a node at `target_level`, where `Err`
+        // represents a constant.
+        let edge = |operand: &Result<Expression<T>, bool>, level: usize, target_level: usize| {
+            let mut conjuncts = (level..target_level)
+                .map(|skipped| Expression::negate(&literal(skipped)))
+                .collect::<Vec<_>>();
+            match operand {
+                Err(false) => return Err(false),
+                Err(true) if conjuncts.is_empty() => return Err(true),
+                Err(true) => (),
+                Ok(expression) => conjuncts.push(expression.clone()),
+            }
+            if conjuncts.len() == 1 {
+                Ok(conjuncts.swap_remove(0))
+            } else {
+                Ok(Expression::n_ary_and(&conjuncts))
+            }
+        };
+
+        let nodes = value.nodes();
+        let variable_count = inputs.len();
+        let (_, root) = fold_nodes(
+            nodes,
+            value.root(),
+            (variable_count, Err(false)),
+            (variable_count, Err(true)),
+            |node, (low_level, low), (high_level, high)| {
+                let literal = literal(node.var);
+                let not_literal = Expression::negate(&literal);
+
+                let expression = match (
+                    edge(low, node.var + 1, *low_level),
+                    edge(high, node.var + 1, *high_level),
+                ) {
+                    (Err(true), Err(true)) => Err(true),
+                    (Err(false), Err(true)) => Ok(literal),
+                    (Err(true), Err(false)) => Ok(not_literal),
+                    (Err(false), Ok(high)) => Ok(Expression::binary_and(&literal, &high)),
+                    (Ok(low), Err(false)) => Ok(Expression::binary_and(&not_literal, &low)),
+                    (Err(true), Ok(high)) => Ok(Expression::binary_or(&not_literal, &high)),
+                    (Ok(low), Err(true)) => Ok(Expression::binary_or(&literal, &low)),
+                    (Ok(low), Ok(high)) => Ok(Expression::binary_or(
+                        &Expression::binary_and(&literal, &high),
+                        &Expression::binary_and(&not_literal, &low),
+                    )),
+                    (Err(false), Err(false)) => {
+                        unreachable!("ZDD nodes have a non-empty high child")
+                    }
+                };
+                (node.var, expression)
+            },
+        );
+        let root_level = match value.root() {
+            0 | 1 => variable_count,
+            root => nodes[root].var,
+        };
+
+        match edge(&root, 0, root_level) {
+            Ok(expression) => expression,
+            Err(value) => ExpressionNode::Constant(value).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{var, Expression};
+    use crate::traits::{BooleanFunction, SemanticEq};
+    use crate::zdd::Zdd;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_expression_from_zdd_ok() {
+        let input = (var("a") & !var("b")) | (var("b") & var("c") & !var("d"));
+        let zdd = Zdd::try_from(input.clone()).unwrap();
+
+        let actual = Expression::from(zdd);
+
+        assert!(actual.semantic_eq(&input));
+    }
+
+    #[test]
+    fn test_expression_from_zdd_sets_ok() {
+        let sets = [vec!["a"], vec!["b", "c"]]
+            .map(|set| set.into_iter().map(String::from).collect::<BTreeSet<_>>());
+
+        let actual = Expression::from(Zdd::from_sets(sets));
+        let expected = (var("a") & !var("b") & !var("c")) | (!var("a") & var("b") & var("c"));
+
+        assert!(actual.semantic_eq(&expected));
+        assert_eq!(actual.inputs(), expected.inputs());
+    }
+
+    #[test]
+    fn test_expression_from_zdd_constants_ok() {
+        assert_eq!(
+            Expression::from(Zdd::<String>::mk_const(true)).to_string(),
+            "true"
+        );
+        assert_eq!(
+            Expression::from(Zdd::<String>::mk_const(false)).to_string(),
+            "false"
+        );
+        assert_eq!(
+            Expression::from(Zdd::mk_literal("a".to_string(), false)).to_string(),
+            "!(a)"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::bdd::interpolation::{InterpolantKind, InterpolationError};
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord + 'static> Expression<T> {
+    /// Computes the strongest Craig interpolant of `a` and `b`, see
+    /// [Expression::interpolant_with_kind].
+    pub fn interpolant(
+        a: &Expression<T>,
+        b: &Expression<T>,
+    ) -> Result<Expression<T>, InterpolationError> {
+        Self::interpolant_with_kind(a, b, InterpolantKind::default())
+    }
+
+    /// Computes a Craig interpolant of `a` and `b` by converting both expressions
+    /// to [Bdd] objects, see [Bdd::interpolant_with_kind].
+    ///
+    /// Returns an `Err` if `a & b` is satisfiable, or if the expressions have more than
+    /// 2<sup>16</sup> inputs.
+    pub fn interpolant_with_kind(
+        a: &Expression<T>,
+        b: &Expression<T>,
+        kind: InterpolantKind,
+    ) -> Result<Expression<T>, InterpolationError> {
+        let a = Bdd::try_from(a.clone())?;
+        let b = Bdd::try_from(b.clone())?;
+        Ok(Expression::from(Bdd::interpolant_with_kind(&a, &b, kind)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::interpolation::{InterpolantKind, InterpolationError};
+    use crate::expressions::{var, Expression};
+    use crate::traits::{BooleanFunction, SemanticEq};
+
+    #[test]
+    fn test_interpolant_ok() {
+        let a = (var("x") | var("y")) & !var("x");
+        let b = !var("y") & var("z");
+
+        let strongest = Expression::interpolant(&a, &b).unwrap();
+        assert!(strongest.semantic_eq(&var("y")));
+
+        let weakest = Expression::interpolant_with_kind(&a, &b, InterpolantKind::Weakest).unwrap();
+        assert!(weakest.semantic_eq(&var("y")));
+        assert!(weakest.is_implied_by(&a));
+    }
+
+    #[test]
+    fn test_interpolant_satisfiable_err() {
+        let a = var("x") & var("y");
+        let b = var("y");
+
+        assert_eq!(
+            Expression::interpolant(&a, &b),
+            Err(InterpolationError::Satisfiable)
+        );
+    }
+}
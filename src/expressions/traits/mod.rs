@@ -1,10 +1,18 @@
+pub use from_bdd::BddToExpressionStrategy;
+
 mod bit;
 mod boolean_function;
+mod canalization;
 mod display;
 mod evaluate;
 mod from_bdd;
+mod from_zdd;
 mod gather_literals;
+mod interpolation;
+mod monotonicity;
 mod operations;
 mod parse;
 mod power_set;
+mod sample_support;
 mod semantic_eq;
+mod weighted_count;
@@ -0,0 +1,91 @@
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::traits::{Monotonicity, RegulationSign};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord + 'static> Monotonicity<T> for Expression<T> {
+    /// The expression is converted into a `Bdd`, whose cofactors are then compared.
+    ///
+    /// Panics if the expression has more than `2^16` inputs.
+    fn regulation_sign(&self, variable: &T) -> RegulationSign {
+        to_bdd(self).regulation_sign(variable)
+    }
+
+    /// Same as [Monotonicity::regulation_sign], the expression is converted into
+    /// a `Bdd` (but only once for all inputs).
+    fn regulation_signs(&self) -> BTreeMap<T, RegulationSign> {
+        to_bdd(self).regulation_signs()
+    }
+
+    fn is_monotone(&self) -> bool {
+        to_bdd(self).is_monotone()
+    }
+
+    /// The expression is converted into a `Bdd`, and the approximation is converted back
+    /// into an `Expression`.
+    fn upper_monotone_approximation(&self) -> Self {
+        Expression::from(to_bdd(self).upper_monotone_approximation())
+    }
+
+    /// The expression is converted into a `Bdd`, and the approximation is converted back
+    /// into an `Expression`.
+    fn lower_monotone_approximation(&self) -> Self {
+        Expression::from(to_bdd(self).lower_monotone_approximation())
+    }
+
+    fn minimal_positive_dnf(&self) -> Option<Expression<T>> {
+        to_bdd(self).minimal_positive_dnf()
+    }
+}
+
+fn to_bdd<T: Debug + Clone + Ord + 'static>(expression: &Expression<T>) -> Bdd<T> {
+    Bdd::try_from(expression.clone()).expect("Expression should have at most 2^16 inputs")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{bool, var};
+    use crate::traits::{Monotonicity, RegulationSign, SemanticEq};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_regulation_signs_ok() {
+        let function = (!var("a") | var("b")) & (var("c") | (var("d") & bool(false)));
+
+        assert_eq!(
+            function.regulation_signs(),
+            BTreeMap::from([
+                ("a".to_string(), RegulationSign::Inhibiting),
+                ("b".to_string(), RegulationSign::Activating),
+                ("c".to_string(), RegulationSign::Activating),
+                ("d".to_string(), RegulationSign::NonObservable),
+            ])
+        );
+        assert_eq!(
+            (var("a") ^ var("b")).regulation_sign(&"a".to_string()),
+            RegulationSign::Dual
+        );
+    }
+
+    #[test]
+    fn test_monotone_ok() {
+        let function = var("a") & (var("b") | !var("c"));
+
+        assert!(!function.is_monotone());
+        assert!(function.is_positive_unate_in(&"b".to_string()));
+        assert!(function
+            .upper_monotone_approximation()
+            .semantic_eq(&var("a")));
+        assert!(function
+            .lower_monotone_approximation()
+            .semantic_eq(&(var("a") & var("b"))));
+        assert_eq!(function.minimal_positive_dnf(), None);
+
+        let monotone = (var("a") | var("b")) & (var("a") | var("c"));
+        assert_eq!(
+            monotone.minimal_positive_dnf(),
+            Some(var("a") | (var("b") & var("c")))
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::traits::{BooleanPoint, BooleanValuation, SampleSupport};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord + 'static> SampleSupport<T> for Expression<T> {
+    /// The expression is converted into a `Bdd`, which is then sampled exactly.
+    ///
+    /// Panics if the expression has more than `2^16` inputs.
+    fn sample_support_restricted(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: &BooleanValuation<T>,
+    ) -> Vec<BooleanPoint> {
+        Bdd::try_from(self.clone())
+            .expect("Expression should have at most 2^16 inputs")
+            .sample_support_restricted(count, seed, restriction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::traits::{Evaluate, SampleSupport};
+    use crate::utils::boolean_point_to_valuation;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    #[test]
+    fn test_sample_support_ok() {
+        let expression = (var("a") ^ var("b")) | (var("c") & !var("d"));
+        let bdd = Bdd::try_from(expression.clone()).unwrap();
+        let inputs: BTreeSet<_> = ["a", "b", "c", "d"].map(String::from).into_iter().collect();
+
+        let samples = expression.sample_support(50, 2);
+
+        assert_eq!(samples, bdd.sample_support(50, 2));
+        for point in samples {
+            let valuation = boolean_point_to_valuation(inputs.clone(), point).unwrap();
+            assert!(expression.evaluate(&valuation));
+        }
+
+        let restriction = BTreeMap::from([("a".to_string(), true), ("b".to_string(), true)]);
+        for point in expression.sample_support_restricted(20, 2, &restriction) {
+            assert_eq!(&point[..3], &[true, true, true]);
+            assert!(!point[3]);
+        }
+    }
+}
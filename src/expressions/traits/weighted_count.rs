@@ -0,0 +1,38 @@
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::traits::{Semiring, WeightedCount};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord + 'static> WeightedCount<T> for Expression<T> {
+    /// The expression is compiled into a `Bdd`, which is then counted exactly.
+    ///
+    /// Panics if the expression has more than `2^16` inputs.
+    fn weighted_count<S: Semiring, F: Fn(&T, bool) -> S>(&self, weight: F) -> S {
+        Bdd::try_from(self.clone())
+            .expect("Expression should have at most 2^16 inputs")
+            .weighted_count(weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::traits::WeightedCount;
+    use num_rational::BigRational;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_probability_ok() {
+        // A regulator `a` activated with probability 9/10, inhibited by `b` (1/5).
+        let expression = var("a") & !var("b");
+        let probabilities = BTreeMap::from([
+            ("a".to_string(), BigRational::new(9.into(), 10.into())),
+            ("b".to_string(), BigRational::new(1.into(), 5.into())),
+        ]);
+
+        assert_eq!(
+            expression.probability(&probabilities),
+            BigRational::new(18.into(), 25.into())
+        );
+    }
+}
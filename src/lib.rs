@@ -3,10 +3,15 @@ pub mod bdd;
 mod bindings;
 pub mod expressions;
 pub mod iterators;
+pub mod network;
 pub mod parser;
+pub mod random;
+pub mod symmetric;
 pub mod table;
+pub mod threshold;
 pub mod traits;
 mod utils;
+pub mod zdd;
 
 #[allow(clippy::single_component_path_imports)] // The use is required by the rstest_reuse crate
 #[cfg(test)]
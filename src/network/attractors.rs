@@ -0,0 +1,196 @@
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use crate::bdd::Bdd;
+use crate::network::symbolic::SymbolicTransitions;
+use crate::network::{BooleanNetwork, UpdateSemantics};
+
+/// The type of an [Attractor], based on the structure of its transitions.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AttractorKind {
+    /// A single state, which is never left.
+    FixedPoint,
+    /// Multiple states, each with exactly one successor, which form a single cycle.
+    Cycle,
+    /// Multiple states, some of which have multiple successors.
+    Complex,
+}
+
+/// An attractor of a [BooleanNetwork], i.e. a terminal strongly connected component of its
+/// state-transition graph. Once the network enters an attractor, it never leaves it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attractor<T: Debug + Clone + Ord> {
+    states: Bdd<T>,
+    kind: AttractorKind,
+}
+
+impl<T: Debug + Clone + Ord> Attractor<T> {
+    /// The states of this attractor.
+    pub fn states(&self) -> &Bdd<T> {
+        &self.states
+    }
+
+    pub fn kind(&self) -> AttractorKind {
+        self.kind
+    }
+}
+
+impl<T: Debug + Clone + Ord + 'static> BooleanNetwork<T> {
+    /// All attractors of this network under the given `semantics`.
+    ///
+    /// The attractors are computed symbolically using a variant of the Xie-Beerel algorithm.
+    /// For a pivot state, its forward and backward reachable sets are computed. If the forward
+    /// set is contained in the backward set, it is an attractor. Otherwise, the pivot is
+    /// transient and the search continues in the forward set without the backward set,
+    /// which must contain an attractor. Once an attractor is found, its whole basin
+    /// is removed from the searched states.
+    pub fn attractors(&self, semantics: UpdateSemantics) -> Vec<Attractor<T>> {
+        let transitions = SymbolicTransitions::new(self, semantics, BTreeSet::new());
+        let mut remaining = transitions.mk_const(true);
+        let mut attractors = Vec::new();
+
+        // Both `remaining` and `universe` are always closed under successors, hence
+        // the forward reachable sets are never restricted by them.
+        while let Some(mut pivot) = transitions.pick_state(&remaining) {
+            let mut universe = remaining.clone();
+            let attractor = loop {
+                let forward = transitions.reach(&pivot, &universe, true);
+                let backward = transitions.reach(&pivot, &universe, false);
+                if (&forward & &!&backward).is_false() {
+                    break forward;
+                }
+                universe = forward & !backward;
+                pivot = transitions
+                    .pick_state(&universe)
+                    .expect("The forward set of a transient state contains an attractor");
+            };
+
+            let basin = transitions.reach(&attractor, &remaining, false);
+            remaining = remaining & !basin;
+
+            let kind = if attractor == pivot {
+                AttractorKind::FixedPoint
+            } else if transitions.has_unique_successors(&attractor) {
+                AttractorKind::Cycle
+            } else {
+                AttractorKind::Complex
+            };
+            attractors.push(Attractor {
+                states: transitions.export(&attractor),
+                kind,
+            });
+        }
+
+        attractors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::network::tests::{bdd, toggle_switch};
+    use crate::traits::BooleanFunction;
+    use std::collections::BTreeMap;
+
+    fn summary(attractors: &[Attractor<String>]) -> Vec<(AttractorKind, u32)> {
+        let mut result = attractors
+            .iter()
+            .map(|it| (it.kind(), u32::try_from(it.states().weight()).unwrap()))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|(_, size)| *size);
+        result
+    }
+
+    #[test]
+    fn test_attractors_toggle_switch_ok() {
+        let network = toggle_switch();
+
+        let attractors = network.attractors(UpdateSemantics::Asynchronous);
+        assert_eq!(attractors.len(), 2);
+        assert!(attractors
+            .iter()
+            .all(|it| it.kind() == AttractorKind::FixedPoint));
+        let union = attractors
+            .iter()
+            .fold(bdd("false"), |result, it| result | it.states().clone());
+        assert!(union.is_equivalent(&network.fixed_points()));
+
+        let attractors = network.attractors(UpdateSemantics::Synchronous);
+        assert_eq!(attractors.len(), 3);
+        let cycle = attractors
+            .iter()
+            .find(|it| it.kind() == AttractorKind::Cycle)
+            .unwrap();
+        assert!(cycle
+            .states()
+            .is_equivalent(&bdd("(a & b & !c) | (!a & !b & c)")));
+    }
+
+    #[test]
+    fn test_attractors_negative_loop_ok() {
+        let network = BooleanNetwork::try_from_functions(BTreeMap::from([
+            ("a".to_string(), !var("c")),
+            ("b".to_string(), var("a")),
+            ("c".to_string(), var("b")),
+        ]))
+        .unwrap();
+
+        let attractors = network.attractors(UpdateSemantics::Asynchronous);
+        assert_eq!(summary(&attractors), vec![(AttractorKind::Cycle, 6)]);
+
+        let attractors = network.attractors(UpdateSemantics::Synchronous);
+        assert_eq!(
+            summary(&attractors),
+            vec![(AttractorKind::Cycle, 2), (AttractorKind::Cycle, 6)]
+        );
+    }
+
+    #[test]
+    fn test_attractors_complex_ok() {
+        let network = BooleanNetwork::try_from_functions(BTreeMap::from([
+            ("a".to_string(), !var("a")),
+            ("b".to_string(), !var("b")),
+            ("c".to_string(), var("c")),
+        ]))
+        .unwrap();
+
+        let attractors = network.attractors(UpdateSemantics::Asynchronous);
+        assert_eq!(
+            summary(&attractors),
+            vec![(AttractorKind::Complex, 4), (AttractorKind::Complex, 4)]
+        );
+        assert!(attractors
+            .iter()
+            .all(|it| it.states().essential_degree() == 1));
+    }
+
+    #[test]
+    fn test_attractors_large_network_ok() {
+        // A chain of 50 variables copying the (constant) value of `x00`,
+        // together with two independent oscillators `a` and `b`.
+        let mut functions = BTreeMap::from([
+            ("a".to_string(), !var("a")),
+            ("b".to_string(), !var("b")),
+            ("x00".to_string(), var("x00")),
+        ]);
+        for i in 1..50 {
+            functions.insert(format!("x{i:02}"), var(format!("x{:02}", i - 1)));
+        }
+        let network = BooleanNetwork::try_from_functions(functions).unwrap();
+
+        let attractors = network.attractors(UpdateSemantics::Asynchronous);
+        assert_eq!(attractors.len(), 2);
+        for attractor in &attractors {
+            assert_eq!(attractor.kind(), AttractorKind::Complex);
+            assert_eq!(attractor.states().weight(), 4u8.into());
+        }
+
+        let attractors = network.attractors(UpdateSemantics::Synchronous);
+        assert_eq!(attractors.len(), 4);
+        assert!(attractors
+            .iter()
+            .all(|it| it.kind() == AttractorKind::Cycle));
+    }
+}
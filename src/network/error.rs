@@ -0,0 +1,21 @@
+use std::num::TryFromIntError;
+
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BooleanNetworkError {
+    #[error(
+        "The update function of {variable} depends on {regulator}, which is not a network variable"
+    )]
+    UndeclaredRegulator { variable: String, regulator: String },
+    #[error("A network can contain at most {} variables", u16::MAX / 2)]
+    TooManyVariables(#[from] TryFromIntError),
+}
+
+#[cfg(feature = "python")]
+impl From<BooleanNetworkError> for PyErr {
+    fn from(err: BooleanNetworkError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::num::TryFromIntError;
+
+use crate::bdd::Bdd;
+use crate::traits::BooleanFunction;
+
+pub use attractors::{Attractor, AttractorKind};
+pub use error::BooleanNetworkError;
+pub use regulatory_graph::RegulatoryGraph;
+pub use semantics::UpdateSemantics;
+
+mod attractors;
+mod error;
+mod reachability;
+mod regulatory_graph;
+mod semantics;
+mod symbolic;
+mod trap_spaces;
+
+/// A Boolean network, i.e. a set of variables, each with an update function which
+/// determines the next value of the variable based on the current values of its regulators.
+///
+/// The update functions are stored as [Bdd] objects. The state space of the network is
+/// explored symbolically: sets of states are represented as [Bdd] objects over
+/// the network variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BooleanNetwork<T: Debug + Clone + Ord> {
+    update_functions: BTreeMap<T, Bdd<T>>,
+}
+
+/// A variable of the symbolic encoding of network transitions: either the current value
+/// of a network variable, or its *primed* copy, i.e. its value after the transition.
+///
+/// The primed copy is ordered right after its current variable, which is the preferable
+/// variable order for transition relations.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StateVariable<T> {
+    pub variable: T,
+    pub primed: bool,
+}
+
+impl<T> StateVariable<T> {
+    pub fn current(variable: T) -> StateVariable<T> {
+        StateVariable {
+            variable,
+            primed: false,
+        }
+    }
+
+    pub fn primed(variable: T) -> StateVariable<T> {
+        StateVariable {
+            variable,
+            primed: true,
+        }
+    }
+}
+
+impl<T: Debug + Clone + Ord + 'static> BooleanNetwork<T> {
+    /// Creates a network from the given update functions, keyed by the updated variable.
+    ///
+    /// Returns an `Err` if an update function has an input that is not a network variable.
+    /// Since transitions also use a primed copy of every variable, the network can have
+    /// at most `u16::MAX / 2` (32767) variables.
+    pub fn new(
+        update_functions: BTreeMap<T, Bdd<T>>,
+    ) -> Result<BooleanNetwork<T>, BooleanNetworkError> {
+        u16::try_from(2 * update_functions.len())?;
+        for (variable, function) in &update_functions {
+            if let Some(regulator) = function
+                .inputs()
+                .into_iter()
+                .find(|it| !update_functions.contains_key(it))
+            {
+                return Err(BooleanNetworkError::UndeclaredRegulator {
+                    variable: format!("{variable:?}"),
+                    regulator: format!("{regulator:?}"),
+                });
+            }
+        }
+
+        Ok(BooleanNetwork { update_functions })
+    }
+
+    /// Creates a network from update functions in any representation that can be converted
+    /// to a [Bdd], e.g. `Expression` or `TruthTable`. See also [BooleanNetwork::new].
+    pub fn try_from_functions<F: TryInto<Bdd<T>, Error = TryFromIntError>>(
+        update_functions: BTreeMap<T, F>,
+    ) -> Result<BooleanNetwork<T>, BooleanNetworkError> {
+        let update_functions = update_functions
+            .into_iter()
+            .map(|(variable, function)| Ok((variable, function.try_into()?)))
+            .collect::<Result<BTreeMap<_, _>, TryFromIntError>>()?;
+        BooleanNetwork::new(update_functions)
+    }
+
+    /// The variables of this network.
+    pub fn variables(&self) -> BTreeSet<T> {
+        self.update_functions.keys().cloned().collect()
+    }
+
+    /// The update function of the given `variable`, or `None` if it is not a network variable.
+    pub fn update_function(&self, variable: &T) -> Option<&Bdd<T>> {
+        self.update_functions.get(variable)
+    }
+
+    /// The variables that the update function of `variable` actually depends on
+    /// (see [BooleanFunction::essential_inputs]), or `None` if it is not a network variable.
+    pub fn regulators(&self, variable: &T) -> Option<BTreeSet<T>> {
+        self.update_functions
+            .get(variable)
+            .map(BooleanFunction::essential_inputs)
+    }
+
+    /// The states in which the update function of every variable agrees with its current
+    /// value, i.e. the states which are not left by any transition.
+    ///
+    /// Fixed points are the same for both [UpdateSemantics].
+    pub fn fixed_points(&self) -> Bdd<T> {
+        self.update_functions
+            .iter()
+            .fold(Bdd::mk_const(true), |result, (variable, function)| {
+                let literal = Bdd::mk_literal(variable.clone(), true);
+                result & !(literal ^ function.clone())
+            })
+    }
+}
+
+/// Converts a set of states into the same set over the current [StateVariable] objects.
+fn lift_states<T: Debug + Clone + Ord>(states: &Bdd<T>) -> Bdd<StateVariable<T>> {
+    states.map_inputs(|it| StateVariable::current(it.clone()))
+}
+
+/// The inverse of [lift_states]. The `states` must not depend on primed variables.
+fn unlift_states<T: Debug + Clone + Ord>(states: &Bdd<StateVariable<T>>) -> Bdd<T> {
+    states.map_inputs(|it| {
+        debug_assert!(!it.primed);
+        it.variable.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{var, Expression};
+    use std::str::FromStr;
+
+    /// A network where `a` and `b` inhibit each other, and `c` copies `a & b`.
+    pub(crate) fn toggle_switch() -> BooleanNetwork<String> {
+        BooleanNetwork::try_from_functions(BTreeMap::from([
+            ("a".to_string(), !var("b")),
+            ("b".to_string(), !var("a")),
+            ("c".to_string(), var("a") & var("b")),
+        ]))
+        .unwrap()
+    }
+
+    pub(crate) fn bdd(expression: &str) -> Bdd<String> {
+        Bdd::try_from(Expression::from_str(expression).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_new_ok() {
+        let network = toggle_switch();
+
+        assert_eq!(
+            network.variables(),
+            BTreeSet::from(["a", "b", "c"].map(String::from))
+        );
+        assert_eq!(
+            network.regulators(&"c".to_string()),
+            Some(BTreeSet::from(["a", "b"].map(String::from)))
+        );
+        assert!(network
+            .update_function(&"a".to_string())
+            .unwrap()
+            .is_equivalent(&bdd("!b")));
+        assert_eq!(network.regulators(&"x".to_string()), None);
+    }
+
+    #[test]
+    fn test_new_undeclared_regulator_err() {
+        let actual = BooleanNetwork::try_from_functions(BTreeMap::from([
+            ("a".to_string(), var("b") | var("x")),
+            ("b".to_string(), var("a")),
+        ]));
+
+        assert_eq!(
+            actual,
+            Err(BooleanNetworkError::UndeclaredRegulator {
+                variable: "\"a\"".to_string(),
+                regulator: "\"x\"".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_fixed_points_ok() {
+        let network = toggle_switch();
+
+        assert!(network
+            .fixed_points()
+            .is_equivalent(&bdd("(a & !b & !c) | (!a & b & !c)")));
+
+        let oscillator = BooleanNetwork::try_from_functions(BTreeMap::from([
+            ("a".to_string(), !var("b")),
+            ("b".to_string(), var("a")),
+        ]))
+        .unwrap();
+        assert!(oscillator.fixed_points().is_equivalent(&bdd("false")));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Debug;
+
+use crate::bdd::Bdd;
+use crate::network::symbolic::SymbolicTransitions;
+use crate::network::{BooleanNetwork, UpdateSemantics};
+
+impl<T: Debug + Clone + Ord + 'static> BooleanNetwork<T> {
+    /// The states reachable from the `initial` states (including the `initial` states).
+    ///
+    /// See [BooleanNetwork::post] for the treatment of variables which are not network
+    /// variables.
+    pub fn reach_forward(&self, initial: &Bdd<T>, semantics: UpdateSemantics) -> Bdd<T> {
+        self.reach(initial, semantics, true)
+    }
+
+    /// The states from which some of the `target` states are reachable
+    /// (including the `target` states).
+    ///
+    /// See [BooleanNetwork::post] for the treatment of variables which are not network
+    /// variables.
+    pub fn reach_backward(&self, target: &Bdd<T>, semantics: UpdateSemantics) -> Bdd<T> {
+        self.reach(target, semantics, false)
+    }
+
+    fn reach(&self, initial: &Bdd<T>, semantics: UpdateSemantics, forward: bool) -> Bdd<T> {
+        let transitions = SymbolicTransitions::new(self, semantics, self.parameters(initial));
+        let reached = transitions.reach(
+            &transitions.import(initial),
+            &transitions.mk_const(true),
+            forward,
+        );
+        transitions.export(&reached)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::network::tests::{bdd, toggle_switch};
+    use crate::network::{BooleanNetwork, UpdateSemantics};
+    use crate::traits::BooleanFunction;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_reach_toggle_switch_ok() {
+        let network = toggle_switch();
+        let initial = bdd("a & b & !c");
+
+        let forward = network.reach_forward(&initial, UpdateSemantics::Asynchronous);
+        assert!(forward.is_equivalent(&bdd("a | b")));
+
+        // Synchronously, the state oscillates between `a & b` and `!a & !b`.
+        let forward = network.reach_forward(&initial, UpdateSemantics::Synchronous);
+        assert!(forward.is_equivalent(&bdd("(a & b & !c) | (!a & !b & c)")));
+
+        let backward = network.reach_backward(&bdd("a & !b"), UpdateSemantics::Asynchronous);
+        assert!(backward.is_equivalent(&bdd("!(!a & b)")));
+    }
+
+    #[test]
+    fn test_reach_chain_ok() {
+        // A chain `x0 -> x1 -> ... -> x19` which propagates the value of `x0`.
+        let mut functions = BTreeMap::from([("x00".to_string(), var("x00"))]);
+        for i in 1..20 {
+            functions.insert(format!("x{i:02}"), var(format!("x{:02}", i - 1)));
+        }
+        let network = BooleanNetwork::try_from_functions(functions).unwrap();
+        let all_false = (0..20).fold(bdd("true"), |result, i| {
+            result & !Bdd::mk_literal(format!("x{i:02}"), true)
+        });
+        let all_true = (0..20).fold(bdd("true"), |result, i| {
+            result & Bdd::mk_literal(format!("x{i:02}"), true)
+        });
+        let initial = (1..20).fold(bdd("x00"), |result, i| {
+            result & !Bdd::mk_literal(format!("x{i:02}"), true)
+        });
+
+        for semantics in [UpdateSemantics::Asynchronous, UpdateSemantics::Synchronous] {
+            let forward = network.reach_forward(&initial, semantics);
+            assert!(forward.is_implied_by(&all_true));
+            assert!(!forward.is_implied_by(&all_false));
+            let backward = network.reach_backward(&all_true, semantics);
+            assert!(backward.is_equivalent(&bdd("x00")));
+        }
+        assert!(network
+            .fixed_points()
+            .is_equivalent(&(all_false | all_true)));
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Debug, Display, Write};
+
+use crate::network::BooleanNetwork;
+use crate::traits::{Monotonicity, RegulationSign};
+
+/// A signed directed graph of regulations between variables, where each edge `regulator ->
+/// target` is labelled by the [RegulationSign] of `regulator` in the update function
+/// of `target`.
+///
+/// Every input of an update function is a regulator, including the non-essential inputs
+/// (these are labelled [RegulationSign::NonObservable]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegulatoryGraph<T: Debug + Clone + Ord> {
+    variables: BTreeSet<T>,
+    /// Maps every target to its regulators and their signs.
+    regulations: BTreeMap<T, BTreeMap<T, RegulationSign>>,
+}
+
+impl<T: Debug + Clone + Ord> RegulatoryGraph<T> {
+    /// Builds the graph of the given update `functions`, keyed by the target variable.
+    ///
+    /// The variables of the graph are the targets together with all inputs of the
+    /// `functions`, i.e. the regulators do not need to be targets themselves.
+    pub fn from_functions<F: Monotonicity<T>>(functions: &BTreeMap<T, F>) -> RegulatoryGraph<T> {
+        let mut variables = BTreeSet::new();
+        let mut regulations = BTreeMap::new();
+        for (target, function) in functions {
+            let signs = function.regulation_signs();
+            variables.insert(target.clone());
+            variables.extend(signs.keys().cloned());
+            regulations.insert(target.clone(), signs);
+        }
+
+        RegulatoryGraph {
+            variables,
+            regulations,
+        }
+    }
+
+    /// All variables of the graph.
+    pub fn variables(&self) -> &BTreeSet<T> {
+        &self.variables
+    }
+
+    /// The sign of the regulation `regulator -> target`, or `None` if there is no such
+    /// regulation.
+    pub fn regulation(&self, regulator: &T, target: &T) -> Option<RegulationSign> {
+        self.regulations.get(target)?.get(regulator).copied()
+    }
+
+    /// All regulations of the graph as `(regulator, target, sign)` triples, ordered by target.
+    pub fn regulations(&self) -> impl Iterator<Item = (&T, &T, RegulationSign)> {
+        self.regulations.iter().flat_map(|(target, regulators)| {
+            regulators
+                .iter()
+                .map(move |(regulator, sign)| (regulator, target, *sign))
+        })
+    }
+
+    /// The regulators of `target` together with their signs (empty if `target` has
+    /// no update function).
+    pub fn regulators(&self, target: &T) -> BTreeMap<T, RegulationSign> {
+        self.regulations.get(target).cloned().unwrap_or_default()
+    }
+
+    /// The variables regulated by `regulator` together with the signs of the regulations.
+    pub fn targets(&self, regulator: &T) -> BTreeMap<T, RegulationSign> {
+        self.regulations
+            .iter()
+            .filter_map(|(target, regulators)| {
+                regulators
+                    .get(regulator)
+                    .map(|sign| (target.clone(), *sign))
+            })
+            .collect()
+    }
+}
+
+impl<T: Debug + Clone + Ord + Display> RegulatoryGraph<T> {
+    /// Exports the graph in the Graphviz DOT format.
+    ///
+    /// Activating regulations use a normal arrow head, inhibiting regulations a bar,
+    /// dual regulations a dot, and non-observable regulations are dashed. Variable names
+    /// are quoted, with quotes and backslashes escaped.
+    pub fn to_dot(&self) -> String {
+        let mut result = String::from("digraph {\n");
+        for variable in &self.variables {
+            writeln!(result, "  {};", dot_id(variable)).unwrap();
+        }
+        for (regulator, target, sign) in self.regulations() {
+            let style = match sign {
+                RegulationSign::Activating => "arrowhead=normal",
+                RegulationSign::Inhibiting => "arrowhead=tee",
+                RegulationSign::Dual => "arrowhead=dot",
+                RegulationSign::NonObservable => "arrowhead=normal, style=dashed",
+            };
+            let (regulator, target) = (dot_id(&regulator), dot_id(&target));
+            writeln!(result, "  {regulator} -> {target} [{style}];").unwrap();
+        }
+        result.push('}');
+        result
+    }
+
+    /// Exports the graph in the Simple Interaction Format (SIF), i.e. one
+    /// `regulator <tab> sign <tab> target` line per regulation, where the sign is
+    /// the [Display] form of the [RegulationSign].
+    pub fn to_sif(&self) -> String {
+        self.regulations()
+            .map(|(regulator, target, sign)| format!("{regulator}\t{sign}\t{target}\n"))
+            .collect()
+    }
+}
+
+/// A quoted DOT identifier of the given `value`, with quotes and backslashes escaped.
+fn dot_id<T: Display>(value: &T) -> String {
+    let escaped = value.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{escaped}\"")
+}
+
+impl<T: Debug + Clone + Ord + 'static> BooleanNetwork<T> {
+    /// The [RegulatoryGraph] of the update functions of this network.
+    pub fn regulatory_graph(&self) -> RegulatoryGraph<T> {
+        RegulatoryGraph::from_functions(&self.update_functions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{bool, var, Expression};
+    use crate::network::tests::toggle_switch;
+
+    fn functions() -> BTreeMap<String, Expression<String>> {
+        BTreeMap::from([
+            ("a".to_string(), !var("b")),
+            ("b".to_string(), var("a") ^ var("c")),
+            ("c".to_string(), var("c") | (var("a") & bool(false))),
+        ])
+    }
+
+    #[test]
+    fn test_regulatory_graph_ok() {
+        let graph = RegulatoryGraph::from_functions(&functions());
+        let name = |it: &str| it.to_string();
+
+        assert_eq!(graph.variables().len(), 3);
+        assert_eq!(
+            graph.regulation(&name("b"), &name("a")),
+            Some(RegulationSign::Inhibiting)
+        );
+        assert_eq!(
+            graph.regulation(&name("a"), &name("c")),
+            Some(RegulationSign::NonObservable)
+        );
+        assert_eq!(graph.regulation(&name("a"), &name("a")), None);
+        assert_eq!(
+            graph.regulators(&name("b")),
+            BTreeMap::from([
+                (name("a"), RegulationSign::Dual),
+                (name("c"), RegulationSign::Dual),
+            ])
+        );
+        assert_eq!(
+            graph.targets(&name("c")),
+            BTreeMap::from([
+                (name("b"), RegulationSign::Dual),
+                (name("c"), RegulationSign::Activating),
+            ])
+        );
+        assert_eq!(graph.regulations().count(), 5);
+    }
+
+    #[test]
+    fn test_regulatory_graph_export_ok() {
+        let graph = RegulatoryGraph::from_functions(&functions());
+
+        assert_eq!(
+            graph.to_sif(),
+            "b\tinhibiting\ta\n\
+             a\tdual\tb\n\
+             c\tdual\tb\n\
+             a\tnon-observable\tc\n\
+             c\tactivating\tc\n"
+        );
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n  \
+               \"a\";\n  \
+               \"b\";\n  \
+               \"c\";\n  \
+               \"b\" -> \"a\" [arrowhead=tee];\n  \
+               \"a\" -> \"b\" [arrowhead=dot];\n  \
+               \"c\" -> \"b\" [arrowhead=dot];\n  \
+               \"a\" -> \"c\" [arrowhead=normal, style=dashed];\n  \
+               \"c\" -> \"c\" [arrowhead=normal];\n\
+             }"
+        );
+    }
+
+    #[test]
+    fn test_regulatory_graph_dot_escaping_ok() {
+        let functions = BTreeMap::from([("say \"hi\"".to_string(), !var("back\\slash"))]);
+        let graph = RegulatoryGraph::from_functions(&functions);
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n  \
+               \"back\\\\slash\";\n  \
+               \"say \\\"hi\\\"\";\n  \
+               \"back\\\\slash\" -> \"say \\\"hi\\\"\" [arrowhead=tee];\n\
+             }"
+        );
+    }
+
+    #[test]
+    fn test_network_regulatory_graph_ok() {
+        let graph = toggle_switch().regulatory_graph();
+
+        let name = |it: &str| it.to_string();
+        assert_eq!(
+            graph.regulation(&name("a"), &name("b")),
+            Some(RegulationSign::Inhibiting)
+        );
+        assert_eq!(
+            graph.regulators(&name("c")),
+            BTreeMap::from([
+                (name("a"), RegulationSign::Activating),
+                (name("b"), RegulationSign::Activating),
+            ])
+        );
+    }
+}
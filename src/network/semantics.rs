@@ -0,0 +1,240 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use crate::bdd::relation::TransitionRelation;
+use crate::bdd::Bdd;
+use crate::network::symbolic::SymbolicTransitions;
+use crate::network::{lift_states, BooleanNetwork, StateVariable};
+use crate::traits::BooleanFunction;
+
+/// The way in which a [BooleanNetwork] updates its variables.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum UpdateSemantics {
+    /// In every transition, a single variable whose update function disagrees with
+    /// its current value is updated. States where no such variable exists have
+    /// no successors.
+    #[default]
+    Asynchronous,
+    /// In every transition, all variables are updated at once. Each state has exactly
+    /// one successor, which is the state itself for fixed points.
+    Synchronous,
+}
+
+impl<T: Debug + Clone + Ord + 'static> BooleanNetwork<T> {
+    /// The transition relation of this network under the given `semantics`, i.e. a [Bdd]
+    /// over the current and primed copies of the network variables.
+    pub fn transition_relation(
+        &self,
+        semantics: UpdateSemantics,
+    ) -> TransitionRelation<StateVariable<T>> {
+        let relation = match semantics {
+            UpdateSemantics::Synchronous => self
+                .update_functions
+                .keys()
+                .fold(Bdd::mk_const(true), |result, variable| {
+                    result & self.update_relation(variable)
+                }),
+            UpdateSemantics::Asynchronous => {
+                self.update_functions
+                    .keys()
+                    .fold(Bdd::mk_const(false), |result, variable| {
+                        let unchanged = self
+                            .update_functions
+                            .keys()
+                            .filter(|it| *it != variable)
+                            .fold(Bdd::mk_const(true), |unchanged, other| {
+                                unchanged & !flip_relation(other)
+                            });
+                        result | (self.async_update_relation(variable) & unchanged)
+                    })
+            }
+        };
+
+        TransitionRelation::new(
+            relation,
+            self.primed_variables(self.update_functions.keys()),
+        )
+        .expect("Primed copies are unique and distinct from current variables")
+    }
+
+    /// The successors of the given `states` under the given `semantics`.
+    ///
+    /// The `states` can also depend on variables which are not network variables. These are
+    /// treated as parameters, i.e. their values are not changed by the transitions.
+    pub fn post(&self, states: &Bdd<T>, semantics: UpdateSemantics) -> Bdd<T> {
+        let transitions = SymbolicTransitions::new(self, semantics, self.parameters(states));
+        transitions.export(&transitions.step(&transitions.import(states), true))
+    }
+
+    /// The predecessors of the given `states` under the given `semantics`.
+    ///
+    /// See [BooleanNetwork::post] for the treatment of variables which are not network
+    /// variables.
+    pub fn pre(&self, states: &Bdd<T>, semantics: UpdateSemantics) -> Bdd<T> {
+        let transitions = SymbolicTransitions::new(self, semantics, self.parameters(states));
+        transitions.export(&transitions.step(&transitions.import(states), false))
+    }
+
+    /// The inputs of `states` which are not network variables.
+    pub(crate) fn parameters(&self, states: &Bdd<T>) -> BTreeSet<T> {
+        states
+            .inputs()
+            .into_iter()
+            .filter(|it| !self.update_functions.contains_key(it))
+            .collect()
+    }
+
+    /// The transition relation split into parts whose union is the whole relation, each
+    /// together with the variables it updates.
+    ///
+    /// The asynchronous relation is split by the updated variable. Since each part only
+    /// updates a single variable, the remaining variables are kept unchanged without the need
+    /// to encode this explicitly in the relation.
+    pub(crate) fn relation_parts(
+        &self,
+        semantics: UpdateSemantics,
+    ) -> Vec<(Bdd<StateVariable<T>>, BTreeSet<T>)> {
+        match semantics {
+            UpdateSemantics::Synchronous => vec![(
+                self.transition_relation(semantics).relation().clone(),
+                self.variables(),
+            )],
+            UpdateSemantics::Asynchronous => self
+                .update_functions
+                .keys()
+                .map(|variable| {
+                    (
+                        self.async_update_relation(variable),
+                        BTreeSet::from([variable.clone()]),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// The relation `v' <=> f_v(x)`.
+    fn update_relation(&self, variable: &T) -> Bdd<StateVariable<T>> {
+        let function = lift_states(&self.update_functions[variable]);
+        !(Bdd::mk_literal(StateVariable::primed(variable.clone()), true) ^ function)
+    }
+
+    /// The relation `(v' <=> f_v(x)) & (v' <=> !v)`.
+    fn async_update_relation(&self, variable: &T) -> Bdd<StateVariable<T>> {
+        self.update_relation(variable) & flip_relation(variable)
+    }
+
+    fn primed_variables<'a, I: IntoIterator<Item = &'a T>>(
+        &self,
+        variables: I,
+    ) -> BTreeMap<StateVariable<T>, StateVariable<T>> {
+        variables
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|it| {
+                (
+                    StateVariable::current(it.clone()),
+                    StateVariable::primed(it.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+/// The relation `v' <=> !v`.
+fn flip_relation<T: Debug + Clone + Ord + 'static>(variable: &T) -> Bdd<StateVariable<T>> {
+    Bdd::mk_literal(StateVariable::current(variable.clone()), true)
+        ^ Bdd::mk_literal(StateVariable::primed(variable.clone()), true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iterators::DomainIterator;
+    use crate::network::tests::{bdd, toggle_switch};
+    use crate::network::unlift_states;
+    use crate::traits::{BooleanFunction, Evaluate};
+
+    /// Enumerates the transitions of `network` under `semantics` explicitly.
+    fn explicit_successors(
+        network: &BooleanNetwork<String>,
+        state: &BTreeMap<String, bool>,
+        semantics: UpdateSemantics,
+    ) -> BTreeSet<BTreeMap<String, bool>> {
+        let updated = network
+            .variables()
+            .into_iter()
+            .map(|it| {
+                let value = network.update_function(&it).unwrap().evaluate(state);
+                (it, value)
+            })
+            .collect::<BTreeMap<_, _>>();
+        match semantics {
+            UpdateSemantics::Synchronous => BTreeSet::from([updated]),
+            UpdateSemantics::Asynchronous => updated
+                .iter()
+                .filter(|(variable, value)| state[*variable] != **value)
+                .map(|(variable, value)| {
+                    let mut successor = state.clone();
+                    successor.insert(variable.clone(), *value);
+                    successor
+                })
+                .collect(),
+        }
+    }
+
+    fn state_bdd(state: &BTreeMap<String, bool>) -> Bdd<String> {
+        state
+            .iter()
+            .fold(Bdd::mk_const(true), |result, (var, value)| {
+                result & Bdd::mk_literal(var.clone(), *value)
+            })
+    }
+
+    #[test]
+    fn test_post_pre_match_explicit_ok() {
+        let network = toggle_switch();
+        let states = DomainIterator::from_count(3)
+            .map(|point| network.variables().into_iter().zip(point).collect())
+            .collect::<Vec<BTreeMap<String, bool>>>();
+
+        for semantics in [UpdateSemantics::Asynchronous, UpdateSemantics::Synchronous] {
+            let relation = network.transition_relation(semantics);
+            for state in &states {
+                let expected = explicit_successors(&network, state, semantics)
+                    .iter()
+                    .fold(Bdd::mk_const(false), |result, it| result | state_bdd(it));
+                let source = state_bdd(state);
+
+                let actual = network.post(&source, semantics);
+                assert!(actual.is_equivalent(&expected), "{state:?} {semantics:?}");
+                let actual = unlift_states(&relation.post(&lift_states(&source)));
+                assert!(actual.is_equivalent(&expected), "{state:?} {semantics:?}");
+
+                for target in &states {
+                    let is_predecessor = network.pre(&state_bdd(target), semantics).evaluate(state);
+                    assert_eq!(is_predecessor, expected.evaluate(target));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transition_relation_inputs_ok() {
+        let network = toggle_switch();
+        let relation = network.transition_relation(UpdateSemantics::Synchronous);
+
+        // No update function depends on `c`, hence only its primed copy is an input.
+        let inputs = relation.relation().inputs();
+        assert_eq!(inputs.len(), 5);
+        assert_eq!(inputs.iter().filter(|it| it.primed).count(), 3);
+        assert!(relation
+            .primed_variables()
+            .iter()
+            .all(|(current, primed)| !current.primed && primed.primed));
+        assert!(network
+            .post(&bdd("a & b & c"), UpdateSemantics::Synchronous)
+            .is_equivalent(&bdd("!a & !b & c")));
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use crate::bdd::universe::{BddUniverse, UniverseBdd};
+use crate::bdd::Bdd;
+use crate::network::{lift_states, unlift_states, BooleanNetwork, StateVariable, UpdateSemantics};
+
+/// The transitions of a [BooleanNetwork] encoded in a [BddUniverse] of the current and primed
+/// network variables. Unlike free-standing [Bdd] objects, the sets of states explored using
+/// these transitions never need to be extended to the variables of the relation.
+pub(crate) struct SymbolicTransitions<T: Debug + Clone + Ord> {
+    universe: BddUniverse<StateVariable<T>>,
+    parts: Vec<RelationPart<T>>,
+}
+
+/// A part of the transition relation, which only updates the variables in `priming`.
+struct RelationPart<T: Debug + Clone + Ord> {
+    relation: UniverseBdd<StateVariable<T>>,
+    /// Maps the updated current variables to their primed copies.
+    priming: BTreeMap<StateVariable<T>, StateVariable<T>>,
+    /// The inverse of `priming`.
+    unpriming: BTreeMap<StateVariable<T>, StateVariable<T>>,
+}
+
+impl<T: Debug + Clone + Ord + 'static> SymbolicTransitions<T> {
+    /// Encodes the transitions of the `network` under the given `semantics`. The `parameters`
+    /// are additional variables which the explored sets of states can depend on.
+    pub fn new(
+        network: &BooleanNetwork<T>,
+        semantics: UpdateSemantics,
+        parameters: BTreeSet<T>,
+    ) -> SymbolicTransitions<T> {
+        let variables = network
+            .update_functions
+            .keys()
+            .flat_map(|it| {
+                [
+                    StateVariable::current(it.clone()),
+                    StateVariable::primed(it.clone()),
+                ]
+            })
+            .chain(parameters.into_iter().map(StateVariable::current));
+        let universe = BddUniverse::new(variables)
+            .expect("The network and the parameters have less than 2^16 variables");
+
+        let parts = network
+            .relation_parts(semantics)
+            .into_iter()
+            .map(|(relation, updated)| {
+                let priming = updated
+                    .into_iter()
+                    .map(|it| {
+                        (
+                            StateVariable::current(it.clone()),
+                            StateVariable::primed(it),
+                        )
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                RelationPart {
+                    relation: universe
+                        .import(&relation)
+                        .expect("The relation only depends on network variables"),
+                    unpriming: priming
+                        .iter()
+                        .map(|(current, primed)| (primed.clone(), current.clone()))
+                        .collect(),
+                    priming,
+                }
+            })
+            .collect();
+
+        SymbolicTransitions { universe, parts }
+    }
+
+    pub fn mk_const(&self, value: bool) -> UniverseBdd<StateVariable<T>> {
+        self.universe.mk_const(value)
+    }
+
+    /// Converts a set of states into the universe of these transitions. The `states` can only
+    /// depend on the network variables and the parameters of these transitions.
+    pub fn import(&self, states: &Bdd<T>) -> UniverseBdd<StateVariable<T>> {
+        self.universe
+            .import(&lift_states(states))
+            .expect("The states only depend on the network variables and parameters")
+    }
+
+    /// Converts a set of states from the universe of these transitions. The inputs of the
+    /// result are all network variables and parameters.
+    pub fn export(&self, states: &UniverseBdd<StateVariable<T>>) -> Bdd<T> {
+        let current = self
+            .universe
+            .variables()
+            .iter()
+            .filter(|it| !it.primed)
+            .cloned()
+            .collect::<Vec<_>>();
+        unlift_states(&states.to_bdd_with_inputs(&current))
+    }
+
+    /// The successors (if `forward`) or predecessors (otherwise) of the given `states`.
+    pub fn step(
+        &self,
+        states: &UniverseBdd<StateVariable<T>>,
+        forward: bool,
+    ) -> UniverseBdd<StateVariable<T>> {
+        self.parts
+            .iter()
+            .fold(self.mk_const(false), |result, part| {
+                result | part.step(states, forward)
+            })
+    }
+
+    /// The states reachable from (or, if not `forward`, the states that can reach) the
+    /// `initial` states, only considering paths within the `within` states.
+    pub fn reach(
+        &self,
+        initial: &UniverseBdd<StateVariable<T>>,
+        within: &UniverseBdd<StateVariable<T>>,
+        forward: bool,
+    ) -> UniverseBdd<StateVariable<T>> {
+        let mut reached = initial & within;
+
+        // Saturation: the parts of the relation are tried starting from the last one, and
+        // after any part discovers new states, the search starts again from the last part.
+        // The last parts update the variables at the bottom of the BDD, which are the
+        // cheapest to update.
+        'saturation: loop {
+            for part in self.parts.iter().rev() {
+                let new = part.step(&reached, forward) & within.clone() & !&reached;
+                if !new.is_false() {
+                    reached = reached | new;
+                    continue 'saturation;
+                }
+            }
+            return reached;
+        }
+    }
+
+    /// Checks that no state of the given `states` has transitions in two parts
+    /// of the relation (each part is deterministic).
+    pub fn has_unique_successors(&self, states: &UniverseBdd<StateVariable<T>>) -> bool {
+        let mut covered = self.mk_const(false);
+        for part in &self.parts {
+            let enabled = part.step(states, false) & states.clone();
+            if !(&enabled & &covered).is_false() {
+                return false;
+            }
+            covered = covered | enabled;
+        }
+        true
+    }
+
+    /// A single state of the given `states`, i.e. a conjunction of literals of all current
+    /// variables and parameters, or `None` if the set is empty.
+    pub fn pick_state(
+        &self,
+        states: &UniverseBdd<StateVariable<T>>,
+    ) -> Option<UniverseBdd<StateVariable<T>>> {
+        let witness = states.inner().sat_witness()?.into_vector();
+        let state = self
+            .universe
+            .variables()
+            .iter()
+            .zip(witness)
+            .filter(|(variable, _value)| !variable.primed)
+            .fold(self.mk_const(true), |result, (variable, value)| {
+                let literal = self
+                    .universe
+                    .mk_literal(variable, value)
+                    .expect("The variable is part of the universe");
+                result & literal
+            });
+        Some(state)
+    }
+}
+
+impl<T: Debug + Clone + Ord> RelationPart<T> {
+    fn step(
+        &self,
+        states: &UniverseBdd<StateVariable<T>>,
+        forward: bool,
+    ) -> UniverseBdd<StateVariable<T>> {
+        let result = if forward {
+            let current = self.priming.keys().cloned().collect();
+            states
+                .relational_product(&self.relation, &current)
+                .and_then(|it| it.rename_variables(&self.unpriming))
+        } else {
+            let primed = self.unpriming.keys().cloned().collect();
+            states
+                .rename_variables(&self.priming)
+                .and_then(|it| it.relational_product(&self.relation, &primed))
+        };
+        result.expect("The relation only depends on variables of the universe")
+    }
+}
@@ -0,0 +1,418 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use crate::bdd::Bdd;
+use crate::network::BooleanNetwork;
+use crate::traits::{BooleanFunction, BooleanValuation};
+
+/// A variable of the symbolic encoding of subspaces. Each network variable `v` is encoded
+/// using two variables, which state whether `v` *can be* `false` and `true` in the subspace.
+/// That is, a fixed variable can only be one of the values, while a free variable can be both.
+///
+/// The `State` variables hold the values of the network variables in the states
+/// of the subspace.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct SpaceVariable<T> {
+    variable: T,
+    kind: SpaceVariableKind,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum SpaceVariableKind {
+    State,
+    CanBeFalse,
+    CanBeTrue,
+}
+
+impl<T: Debug + Clone + Ord + 'static> BooleanNetwork<T> {
+    /// Checks that the given `subspace` is a trap space, i.e. the update function of every
+    /// variable fixed by the `subspace` is constant and equal to its fixed value in the whole
+    /// `subspace`. Hence, no transition can leave the `subspace`.
+    ///
+    /// Entries of the `subspace` for variables that are not network variables are ignored.
+    pub fn is_trap_space(&self, subspace: &BooleanValuation<T>) -> bool {
+        subspace.iter().all(|(variable, value)| {
+            self.update_functions.get(variable).is_none_or(|function| {
+                function
+                    .restrict(subspace)
+                    .is_equivalent(&Bdd::mk_const(*value))
+            })
+        })
+    }
+
+    /// Percolates the given `subspace`: any free variable whose update function is constant
+    /// in the subspace is fixed to that constant, until no more variables can be fixed.
+    ///
+    /// The variables fixed by the original `subspace` stay fixed. If the `subspace` is a trap
+    /// space, the result is a trap space as well, which contains the same attractors.
+    pub fn percolate(&self, subspace: &BooleanValuation<T>) -> BooleanValuation<T> {
+        let mut result = subspace.clone();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (variable, function) in &self.update_functions {
+                if result.contains_key(variable) {
+                    continue;
+                }
+                let restricted = function.restrict(&result);
+                for value in [false, true] {
+                    if restricted.is_equivalent(&Bdd::mk_const(value)) {
+                        result.insert(variable.clone(), value);
+                        changed = true;
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// The inclusion-minimal trap spaces of this network, as partial valuations which only
+    /// contain the fixed variables.
+    ///
+    /// Every minimal trap space contains at least one attractor (in both [UpdateSemantics]).
+    ///
+    /// [UpdateSemantics]: crate::network::UpdateSemantics
+    pub fn minimal_trap_spaces(&self) -> Vec<BooleanValuation<T>> {
+        let trap_spaces = self.trap_spaces_symbolic();
+        let mut candidates = trap_spaces.clone();
+        let mut result = Vec::new();
+
+        while let Some(mut space) = self.pick_space(&candidates) {
+            // No trap space inside a candidate is a superspace of an already found result.
+            let smaller = |space: &BooleanValuation<T>| {
+                &trap_spaces & &self.subspaces(space) & !self.exact_space(space)
+            };
+            while let Some(subspace) = self.pick_space(&smaller(&space)) {
+                space = subspace;
+            }
+
+            candidates &= !self.superspaces(&space);
+            result.push(space);
+        }
+
+        result.sort();
+        result
+    }
+
+    /// The inclusion-maximal trap spaces of this network, as partial valuations which only
+    /// contain the fixed variables. The whole state space is a trivial trap space, and it is
+    /// not included in the result.
+    pub fn maximal_trap_spaces(&self) -> Vec<BooleanValuation<T>> {
+        let trap_spaces = self.trap_spaces_symbolic() & !self.exact_space(&BTreeMap::new());
+        let mut candidates = trap_spaces.clone();
+        let mut result = Vec::new();
+
+        while let Some(mut space) = self.pick_space(&candidates) {
+            // No trap space around a candidate is a subspace of an already found result.
+            let larger = |space: &BooleanValuation<T>| {
+                &trap_spaces & &self.superspaces(space) & !self.exact_space(space)
+            };
+            while let Some(superspace) = self.pick_space(&larger(&space)) {
+                space = superspace;
+            }
+
+            candidates &= !self.subspaces(&space);
+            result.push(space);
+        }
+
+        result.sort();
+        result
+    }
+
+    /// The set of all trap spaces, encoded using the `CanBeFalse` and `CanBeTrue` variables.
+    ///
+    /// A subspace is a trap space if, for every variable `v`, the update function of `v` can
+    /// only be `true` (or `false`) in the subspace if `v` can be `true` (or `false`).
+    fn trap_spaces_symbolic(&self) -> Bdd<SpaceVariable<T>> {
+        self.update_functions
+            .iter()
+            .fold(Bdd::mk_const(true), |result, (variable, function)| {
+                let can_be_false = Bdd::mk_literal(
+                    space_variable(variable, SpaceVariableKind::CanBeFalse),
+                    true,
+                );
+                let can_be_true =
+                    Bdd::mk_literal(space_variable(variable, SpaceVariableKind::CanBeTrue), true);
+                let function_can_be_true = self.can_be_satisfied(function);
+                let function_can_be_false = self.can_be_satisfied(&!function);
+
+                result
+                    & (&can_be_false | &can_be_true)
+                    & (!function_can_be_true | can_be_true)
+                    & (!function_can_be_false | can_be_false)
+            })
+    }
+
+    /// The set of encoded subspaces in which some state satisfies the given `function`.
+    fn can_be_satisfied(&self, function: &Bdd<T>) -> Bdd<SpaceVariable<T>> {
+        let inputs = function.inputs();
+        let is_member = inputs.iter().fold(Bdd::mk_const(true), |result, input| {
+            let state = Bdd::mk_literal(space_variable(input, SpaceVariableKind::State), true);
+            let can_be_false = space_variable(input, SpaceVariableKind::CanBeFalse);
+            let can_be_true = space_variable(input, SpaceVariableKind::CanBeTrue);
+            result
+                & (!&state | Bdd::mk_literal(can_be_true, true))
+                & (state | Bdd::mk_literal(can_be_false, true))
+        });
+        let function = function.map_inputs(|it| space_variable(it, SpaceVariableKind::State));
+        let states = inputs
+            .iter()
+            .map(|it| space_variable(it, SpaceVariableKind::State))
+            .collect::<BTreeSet<_>>();
+        is_member.relational_product(&function, &states)
+    }
+
+    /// The encoding of exactly the given `space`.
+    fn exact_space(&self, space: &BooleanValuation<T>) -> Bdd<SpaceVariable<T>> {
+        self.update_functions
+            .keys()
+            .fold(Bdd::mk_const(true), |result, variable| {
+                let value = space.get(variable);
+                result
+                    & Bdd::mk_literal(
+                        space_variable(variable, SpaceVariableKind::CanBeFalse),
+                        value != Some(&true),
+                    )
+                    & Bdd::mk_literal(
+                        space_variable(variable, SpaceVariableKind::CanBeTrue),
+                        value != Some(&false),
+                    )
+            })
+    }
+
+    /// The encoding of all subspaces of the given `space` (including itself).
+    fn subspaces(&self, space: &BooleanValuation<T>) -> Bdd<SpaceVariable<T>> {
+        space
+            .iter()
+            .fold(Bdd::mk_const(true), |result, (variable, value)| {
+                result
+                    & Bdd::mk_literal(
+                        space_variable(variable, SpaceVariableKind::CanBeFalse),
+                        !*value,
+                    )
+                    & Bdd::mk_literal(
+                        space_variable(variable, SpaceVariableKind::CanBeTrue),
+                        *value,
+                    )
+            })
+    }
+
+    /// The encoding of all superspaces of the given `space` (including itself).
+    fn superspaces(&self, space: &BooleanValuation<T>) -> Bdd<SpaceVariable<T>> {
+        self.update_functions
+            .keys()
+            .fold(Bdd::mk_const(true), |result, variable| {
+                let can_be_false = space_variable(variable, SpaceVariableKind::CanBeFalse);
+                let can_be_true = space_variable(variable, SpaceVariableKind::CanBeTrue);
+                let literals = match space.get(variable) {
+                    Some(true) => Bdd::mk_literal(can_be_true, true),
+                    Some(false) => Bdd::mk_literal(can_be_false, true),
+                    None => {
+                        Bdd::mk_literal(can_be_false, true) & Bdd::mk_literal(can_be_true, true)
+                    }
+                };
+                result & literals
+            })
+    }
+
+    /// Decodes one of the encoded `spaces`, or returns `None` if there is none.
+    fn pick_space(&self, spaces: &Bdd<SpaceVariable<T>>) -> Option<BooleanValuation<T>> {
+        let valuation = spaces
+            .inputs()
+            .into_iter()
+            .zip(spaces.sat_point()?)
+            .collect::<BTreeMap<_, _>>();
+        let can_be = |variable: &T, kind: SpaceVariableKind| {
+            valuation
+                .get(&space_variable(variable, kind))
+                .copied()
+                .unwrap_or(true)
+        };
+
+        let space = self
+            .update_functions
+            .keys()
+            .filter_map(|variable| {
+                match (
+                    can_be(variable, SpaceVariableKind::CanBeFalse),
+                    can_be(variable, SpaceVariableKind::CanBeTrue),
+                ) {
+                    (true, false) => Some((variable.clone(), false)),
+                    (false, true) => Some((variable.clone(), true)),
+                    _ => None,
+                }
+            })
+            .collect();
+        Some(space)
+    }
+}
+
+fn space_variable<T: Clone>(variable: &T, kind: SpaceVariableKind) -> SpaceVariable<T> {
+    SpaceVariable {
+        variable: variable.clone(),
+        kind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::network::tests::toggle_switch;
+    use crate::network::UpdateSemantics;
+    use itertools::Itertools;
+
+    fn valuation(pairs: &[(&str, bool)]) -> BooleanValuation<String> {
+        pairs
+            .iter()
+            .map(|(variable, value)| (variable.to_string(), *value))
+            .collect()
+    }
+
+    /// All subspaces of the given `network` which are trap spaces.
+    fn explicit_trap_spaces(network: &BooleanNetwork<String>) -> Vec<BooleanValuation<String>> {
+        let variables = network.variables().into_iter().collect::<Vec<_>>();
+        variables
+            .iter()
+            .map(|_| [None, Some(false), Some(true)])
+            .multi_cartesian_product()
+            .map(|values| {
+                variables
+                    .iter()
+                    .zip(values)
+                    .filter_map(|(variable, value)| value.map(|it| (variable.clone(), it)))
+                    .collect::<BooleanValuation<String>>()
+            })
+            .filter(|space| network.is_trap_space(space))
+            .collect()
+    }
+
+    fn is_subspace(inner: &BooleanValuation<String>, outer: &BooleanValuation<String>) -> bool {
+        outer
+            .iter()
+            .all(|(variable, value)| inner.get(variable) == Some(value))
+    }
+
+    fn test_networks() -> Vec<BooleanNetwork<String>> {
+        vec![
+            toggle_switch(),
+            // A negative loop with an output.
+            BooleanNetwork::try_from_functions(BTreeMap::from([
+                ("a".to_string(), !var("b")),
+                ("b".to_string(), var("a")),
+                ("c".to_string(), var("a") | var("c")),
+            ]))
+            .unwrap(),
+            // Two positive loops coupled by a third variable.
+            BooleanNetwork::try_from_functions(BTreeMap::from([
+                ("a".to_string(), var("a") & !var("c")),
+                ("b".to_string(), var("b") | var("a")),
+                ("c".to_string(), var("b") & var("c")),
+                ("d".to_string(), !var("d") | var("a")),
+            ]))
+            .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_trap_spaces_match_explicit_ok() {
+        for network in test_networks() {
+            let trap_spaces = explicit_trap_spaces(&network);
+
+            let mut expected = trap_spaces
+                .iter()
+                .filter(|space| {
+                    trap_spaces
+                        .iter()
+                        .all(|other| other == *space || !is_subspace(other, space))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            expected.sort();
+            assert_eq!(network.minimal_trap_spaces(), expected);
+
+            let mut expected = trap_spaces
+                .iter()
+                .filter(|space| !space.is_empty())
+                .filter(|space| {
+                    trap_spaces.iter().all(|other| {
+                        other == *space || other.is_empty() || !is_subspace(space, other)
+                    })
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            expected.sort();
+            assert_eq!(network.maximal_trap_spaces(), expected);
+        }
+    }
+
+    #[test]
+    fn test_minimal_trap_spaces_toggle_switch_ok() {
+        let network = toggle_switch();
+
+        assert_eq!(
+            network.minimal_trap_spaces(),
+            vec![
+                valuation(&[("a", false), ("b", true), ("c", false)]),
+                valuation(&[("a", true), ("b", false), ("c", false)]),
+            ]
+        );
+        // The value of `c` only percolates from the maximal trap spaces.
+        assert_eq!(
+            network.maximal_trap_spaces(),
+            vec![
+                valuation(&[("a", false), ("b", true)]),
+                valuation(&[("a", true), ("b", false)]),
+            ]
+        );
+        for space in network.maximal_trap_spaces() {
+            assert!(network
+                .minimal_trap_spaces()
+                .contains(&network.percolate(&space)));
+        }
+    }
+
+    #[test]
+    fn test_minimal_trap_spaces_contain_attractors_ok() {
+        for network in test_networks() {
+            let attractors = network.attractors(UpdateSemantics::Asynchronous);
+            for space in network.minimal_trap_spaces() {
+                let space_bdd = space
+                    .iter()
+                    .fold(Bdd::mk_const(true), |result, (var, value)| {
+                        result & Bdd::mk_literal(var.clone(), *value)
+                    });
+                assert!(attractors
+                    .iter()
+                    .any(|it| space_bdd.is_implied_by(it.states())));
+            }
+        }
+    }
+
+    #[test]
+    fn test_percolate_ok() {
+        // `a` is constant, `b` copies `a` and `c` depends on `b` and a free `d`.
+        let network = BooleanNetwork::try_from_functions(BTreeMap::from([
+            ("a".to_string(), var("a")),
+            ("b".to_string(), var("a")),
+            ("c".to_string(), var("b") & var("d")),
+            ("d".to_string(), var("d")),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            network.percolate(&valuation(&[("a", false)])),
+            valuation(&[("a", false), ("b", false), ("c", false)])
+        );
+        assert_eq!(
+            network.percolate(&valuation(&[("a", true)])),
+            valuation(&[("a", true), ("b", true)])
+        );
+        assert_eq!(network.percolate(&BTreeMap::new()), BTreeMap::new());
+
+        let percolated = network.percolate(&valuation(&[("a", false)]));
+        assert!(network.is_trap_space(&valuation(&[("a", false)])));
+        assert!(network.is_trap_space(&percolated));
+        assert!(!network.is_trap_space(&valuation(&[("b", false)])));
+    }
+}
@@ -133,7 +133,7 @@ impl<'a> IntermediateToken<'a> {
     pub const LONGEST_TOKEN_LEN: usize = 5;
 
     // TODO make a trait method
-    pub fn try_from(value: &'a str) -> Option<IntermediateToken> {
+    pub fn try_from(value: &'a str) -> Option<IntermediateToken<'a>> {
         let patterns = Self::ALL_TOKEN_PATTERNS_FROM_LONGEST;
 
         let pattern_or_no_match = PATTERN_SET
@@ -146,7 +146,7 @@ impl<'a> IntermediateToken<'a> {
     }
 
     // TODO make a trait method
-    fn from(pattern: &str) -> IntermediateToken {
+    fn from(pattern: &str) -> IntermediateToken<'_> {
         use IntermediateToken::*;
 
         match pattern.to_lowercase().as_str() {
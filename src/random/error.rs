@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RandomFunctionError {
+    #[error("Cannot create clauses of width {clause_width} over {variable_count} variables")]
+    ClauseWidthExceedsVariableCount {
+        clause_width: usize,
+        variable_count: usize,
+    },
+    #[error("Clauses must contain at least one literal")]
+    EmptyClauseWidth,
+    #[error("Invalid expression bounds: {reason}")]
+    InvalidExpressionBounds { reason: &'static str },
+}
@@ -0,0 +1,416 @@
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::expressions::{Expression, ExpressionNode};
+use crate::random::error::RandomFunctionError;
+use crate::table::TruthTable;
+
+pub mod error;
+#[cfg(feature = "proptest")]
+pub mod proptest_arbitrary;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck_arbitrary;
+
+/// The number of variables (named `x0`, `x1`, ...) used by the `Arbitrary` implementations.
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub const ARBITRARY_VARIABLE_COUNT: usize = 4;
+
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+fn arbitrary_variables(count: usize) -> Vec<String> {
+    (0..count).map(|index| format!("x{index}")).collect()
+}
+
+/// Size limits of expressions created by [RandomFunctions::expression].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomExpressionBounds {
+    /// The maximal number of nodes (literals, constants and operators) of the expression.
+    /// Same as in [Expression::is_literal], a negated literal counts as a single literal.
+    pub max_size: usize,
+    /// The maximal nesting depth of operators, literals are at depth `0`.
+    pub max_depth: usize,
+    /// The maximal number of operands of a single `And`/`Or` operator. Must be at least `2`.
+    pub max_arity: usize,
+}
+
+impl Default for RandomExpressionBounds {
+    fn default() -> Self {
+        RandomExpressionBounds {
+            max_size: 32,
+            max_depth: 6,
+            max_arity: 3,
+        }
+    }
+}
+
+/// A reproducible source of random Boolean functions over a fixed set of `inputs`.
+///
+/// The generator is seeded by the user and uses a portable PRNG, hence the same seed
+/// produces the same sequence of functions on every platform. Expressions can be converted
+/// into the other representations using `TruthTable::from` or `Bdd::try_from`.
+#[derive(Debug, Clone)]
+pub struct RandomFunctions<T: Debug + Clone + Ord> {
+    inputs: Vec<T>,
+    rng: ChaCha8Rng,
+}
+
+impl<T: Debug + Clone + Ord> RandomFunctions<T> {
+    /// Duplicate `inputs` are removed.
+    pub fn new(inputs: Vec<T>, seed: u64) -> Self {
+        let inputs = inputs
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        RandomFunctions {
+            inputs,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn inputs(&self) -> &[T] {
+        &self.inputs
+    }
+
+    /// A truth table chosen uniformly among all functions of the `inputs`.
+    pub fn uniform_table(&mut self) -> TruthTable<T> {
+        let outputs = (0..2_usize.pow(self.inputs.len() as u32))
+            .map(|_| self.rng.gen())
+            .collect();
+
+        TruthTable::new(self.inputs.clone(), outputs)
+    }
+
+    /// A random expression within the given `bounds`.
+    ///
+    /// Each operator is `And`, `Or` or `Not` and each leaf is a (possibly negated) literal.
+    /// Without any `inputs`, the leaves are constants instead.
+    pub fn expression(
+        &mut self,
+        bounds: &RandomExpressionBounds,
+    ) -> Result<Expression<T>, RandomFunctionError> {
+        if bounds.max_size == 0 {
+            return Err(RandomFunctionError::InvalidExpressionBounds {
+                reason: "the size must be at least one",
+            });
+        }
+        if bounds.max_arity < 2 {
+            return Err(RandomFunctionError::InvalidExpressionBounds {
+                reason: "the arity must be at least two",
+            });
+        }
+
+        let size = self.rng.gen_range(1..=bounds.max_size);
+        Ok(self.expression_rec(size, bounds.max_depth, bounds.max_arity))
+    }
+
+    /// A random `k`-CNF with `clause_count` clauses. Each clause contains `clause_width`
+    /// distinct variables, each negated with probability `1/2`.
+    pub fn k_cnf(
+        &mut self,
+        clause_width: usize,
+        clause_count: usize,
+    ) -> Result<Expression<T>, RandomFunctionError> {
+        if clause_width == 0 {
+            return Err(RandomFunctionError::EmptyClauseWidth);
+        }
+        if clause_width > self.inputs.len() {
+            return Err(RandomFunctionError::ClauseWidthExceedsVariableCount {
+                clause_width,
+                variable_count: self.inputs.len(),
+            });
+        }
+
+        let clauses = (0..clause_count)
+            .map(|_| {
+                let mut variables = self
+                    .inputs
+                    .choose_multiple(&mut self.rng, clause_width)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                variables.sort();
+                let literals = variables
+                    .into_iter()
+                    .map(|variable| self.literal(variable))
+                    .collect::<Vec<_>>();
+                Expression::n_ary_or(&literals)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Expression::n_ary_and(&clauses))
+    }
+
+    /// A random monotone (positive) function as a minimal positive DNF.
+    ///
+    /// The function is created from up to `inputs.len()` random terms, each containing
+    /// every variable with probability `1/2`, with absorbed terms removed. The result is not
+    /// uniform among all monotone functions, and it is never constant unless there are
+    /// no `inputs` (in which case it is `false`).
+    pub fn monotone(&mut self) -> Expression<T> {
+        if self.inputs.is_empty() {
+            return ExpressionNode::Constant(false).into();
+        }
+
+        let term_count = self.rng.gen_range(1..=self.inputs.len());
+        let terms = (0..term_count)
+            .map(|_| {
+                let mut term = self
+                    .inputs
+                    .iter()
+                    .filter(|_| self.rng.gen())
+                    .cloned()
+                    .collect::<BTreeSet<_>>();
+                if term.is_empty() {
+                    term.insert(self.inputs.choose(&mut self.rng).unwrap().clone());
+                }
+                term
+            })
+            .collect::<BTreeSet<_>>();
+
+        let minimal_terms = terms
+            .iter()
+            .filter(|term| {
+                !terms
+                    .iter()
+                    .any(|other| other != *term && other.is_subset(term))
+            })
+            .map(|term| {
+                let literals = term
+                    .iter()
+                    .map(|variable| ExpressionNode::Literal(variable.clone()).into())
+                    .collect::<Vec<_>>();
+                Expression::n_ary_and(&literals)
+            })
+            .collect::<Vec<_>>();
+
+        Expression::n_ary_or(&minimal_terms)
+    }
+
+    /// A random nested canalizing function which depends on all `inputs`.
+    ///
+    /// The variables are checked in a random order `x_1, ..., x_n`: if `x_i` has its random
+    /// canalizing value, the output is the random canalized value `b_i`, otherwise `x_{i+1}`
+    /// is checked. If no variable is canalizing, the output is `!b_n`.
+    pub fn nested_canalizing(&mut self) -> Expression<T> {
+        let mut order = self.inputs.clone();
+        order.shuffle(&mut self.rng);
+
+        let Some(last) = order.pop() else {
+            return ExpressionNode::Constant(self.rng.gen()).into();
+        };
+
+        // For the last variable, the output is `b_n` iff the variable is canalizing.
+        let mut result = self.literal(last);
+        for variable in order.into_iter().rev() {
+            let is_canalizing = self.literal(variable);
+            result = if self.rng.gen() {
+                Expression::binary_or(&is_canalizing, &result)
+            } else {
+                Expression::binary_and(&Expression::negate(&is_canalizing), &result)
+            };
+        }
+
+        result
+    }
+
+    fn expression_rec(&mut self, size: usize, depth: usize, max_arity: usize) -> Expression<T> {
+        if size == 1 || depth == 0 {
+            return self.leaf();
+        }
+        if size == 2 || self.rng.gen_ratio(1, 5) {
+            let inner = self.expression_rec(size - 1, depth - 1, max_arity);
+            return Expression::negate(&inner);
+        }
+
+        // Split the remaining size among the operands, each operand gets at least one node.
+        let arity = self.rng.gen_range(2..=max_arity.min(size - 1));
+        let mut sizes = vec![1; arity];
+        for _ in 0..(size - 1 - arity) {
+            sizes[self.rng.gen_range(0..arity)] += 1;
+        }
+
+        let operands = sizes
+            .into_iter()
+            .map(|size| self.expression_rec(size, depth - 1, max_arity))
+            .collect::<Vec<_>>();
+
+        if self.rng.gen() {
+            Expression::n_ary_and(&operands)
+        } else {
+            Expression::n_ary_or(&operands)
+        }
+    }
+
+    fn leaf(&mut self) -> Expression<T> {
+        match self.inputs.choose(&mut self.rng).cloned() {
+            Some(variable) => self.literal(variable),
+            None => ExpressionNode::Constant(self.rng.gen()).into(),
+        }
+    }
+
+    /// A literal of `variable` which is negated with probability `1/2`.
+    fn literal(&mut self, variable: T) -> Expression<T> {
+        let literal = ExpressionNode::Literal(variable).into();
+        if self.rng.gen() {
+            literal
+        } else {
+            Expression::negate(&literal)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdd::Bdd;
+    use crate::traits::{BooleanFunction, Evaluate, GatherLiterals, SemanticEq};
+
+    fn names(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("x{i}")).collect()
+    }
+
+    fn node_count<T: Debug + Clone + Ord>(expression: &Expression<T>) -> usize {
+        if expression.is_literal() {
+            return 1;
+        }
+        match expression.node() {
+            ExpressionNode::Literal(_) | ExpressionNode::Constant(_) => 1,
+            ExpressionNode::Not(inner) => 1 + node_count(inner),
+            ExpressionNode::And(es) | ExpressionNode::Or(es) => {
+                1 + es.iter().map(node_count).sum::<usize>()
+            }
+        }
+    }
+
+    fn depth<T: Debug + Clone + Ord>(expression: &Expression<T>) -> usize {
+        if expression.is_literal() {
+            return 0;
+        }
+        match expression.node() {
+            ExpressionNode::Literal(_) | ExpressionNode::Constant(_) => 0,
+            ExpressionNode::Not(inner) => 1 + depth(inner),
+            ExpressionNode::And(es) | ExpressionNode::Or(es) => {
+                1 + es.iter().map(depth).max().unwrap_or_default()
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_functions_ok() {
+        let mut first = RandomFunctions::new(names(4), 42);
+        let mut second = RandomFunctions::new(names(4), 42);
+        let bounds = RandomExpressionBounds::default();
+
+        for _ in 0..10 {
+            assert_eq!(first.uniform_table(), second.uniform_table());
+            assert_eq!(first.expression(&bounds), second.expression(&bounds));
+            assert_eq!(first.k_cnf(3, 5), second.k_cnf(3, 5));
+            assert_eq!(first.monotone(), second.monotone());
+            assert_eq!(first.nested_canalizing(), second.nested_canalizing());
+        }
+
+        let mut other = RandomFunctions::new(names(4), 43);
+        let tables = (0..10).map(|_| other.uniform_table()).collect::<Vec<_>>();
+        let mut first = RandomFunctions::new(names(4), 42);
+        assert!(tables.iter().any(|table| *table != first.uniform_table()));
+    }
+
+    #[test]
+    fn test_expression_bounds_ok() {
+        let mut generator = RandomFunctions::new(names(5), 7);
+        let bounds = RandomExpressionBounds {
+            max_size: 20,
+            max_depth: 4,
+            max_arity: 3,
+        };
+
+        for _ in 0..100 {
+            let expression = generator.expression(&bounds).unwrap();
+
+            assert!(node_count(&expression) <= bounds.max_size);
+            assert!(depth(&expression) <= bounds.max_depth);
+            assert!(expression.gather_literals().len() <= 5);
+            if let ExpressionNode::And(es) | ExpressionNode::Or(es) = expression.node() {
+                assert!((2..=bounds.max_arity).contains(&es.len()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_expression_bounds_nok() {
+        let mut generator = RandomFunctions::new(names(2), 7);
+        let bounds = RandomExpressionBounds {
+            max_arity: 1,
+            ..Default::default()
+        };
+
+        assert!(generator.expression(&bounds).is_err());
+    }
+
+    #[test]
+    fn test_k_cnf_ok() {
+        let mut generator = RandomFunctions::new(names(5), 1);
+        let cnf = generator.k_cnf(3, 4).unwrap();
+
+        assert!(cnf.is_cnf());
+        let ExpressionNode::And(clauses) = cnf.node() else {
+            panic!("Expected a conjunction of clauses");
+        };
+        assert_eq!(clauses.len(), 4);
+        assert!(clauses
+            .iter()
+            .all(|clause| clause.gather_literals().len() == 3));
+
+        assert_eq!(
+            generator.k_cnf(6, 1),
+            Err(RandomFunctionError::ClauseWidthExceedsVariableCount {
+                clause_width: 6,
+                variable_count: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_monotone_ok() {
+        let mut generator = RandomFunctions::new(names(4), 3);
+
+        for _ in 0..20 {
+            let function = TruthTable::from(generator.monotone());
+
+            // Flipping any input from `false` to `true` never decreases the output.
+            for (point, output) in function.relation() {
+                for index in (0..point.len()).filter(|i| !point[*i]) {
+                    let mut higher = point.clone();
+                    higher[index] = true;
+                    let valuation = function.inputs().into_iter().zip(higher).collect();
+                    assert!(!output || function.evaluate(&valuation));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested_canalizing_ok() {
+        let mut generator = RandomFunctions::new(names(4), 5);
+
+        for _ in 0..20 {
+            let function = Bdd::try_from(generator.nested_canalizing()).unwrap();
+
+            // Nested canalizing functions depend on all their inputs.
+            assert_eq!(function.essential_inputs().len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_uniform_table_ok() {
+        let mut generator = RandomFunctions::new(names(3), 11);
+        let table = generator.uniform_table();
+
+        assert_eq!(table.inputs(), names(3).into_iter().collect());
+        assert!(table.semantic_eq(&table.clone()));
+    }
+}
@@ -0,0 +1,98 @@
+//! Implementations of [proptest::arbitrary::Arbitrary] for the Boolean function
+//! representations, enabled by the `proptest` feature.
+//!
+//! All functions use (a subset of) the variables `x0` to `x3`, see
+//! [ARBITRARY_VARIABLE_COUNT].
+
+use proptest::collection::vec;
+use proptest::prelude::{any, prop_oneof, Arbitrary, BoxedStrategy, Strategy};
+
+use crate::bdd::Bdd;
+use crate::expressions::{Expression, ExpressionNode};
+use crate::random::{arbitrary_variables, RandomExpressionBounds, ARBITRARY_VARIABLE_COUNT};
+use crate::table::TruthTable;
+
+impl Arbitrary for Expression<String> {
+    /// The size bound is only a target for the generated expressions, while the depth
+    /// and arity bounds are always respected.
+    type Parameters = RandomExpressionBounds;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(bounds: Self::Parameters) -> Self::Strategy {
+        let leaf = prop_oneof![
+            any::<bool>().prop_map(|value| ExpressionNode::Constant(value).into()),
+            (0..ARBITRARY_VARIABLE_COUNT)
+                .prop_map(|index| ExpressionNode::Literal(format!("x{index}")).into()),
+        ];
+
+        let max_arity = bounds.max_arity.max(2);
+        leaf.prop_recursive(
+            bounds.max_depth as u32,
+            bounds.max_size as u32,
+            max_arity as u32,
+            move |inner| {
+                prop_oneof![
+                    inner.clone().prop_map(|e| Expression::negate(&e)),
+                    vec(inner.clone(), 2..=max_arity).prop_map(|es| Expression::n_ary_and(&es)),
+                    vec(inner, 2..=max_arity).prop_map(|es| Expression::n_ary_or(&es)),
+                ]
+            },
+        )
+        .boxed()
+    }
+}
+
+impl Arbitrary for TruthTable<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (0..=ARBITRARY_VARIABLE_COUNT)
+            .prop_flat_map(|variable_count| {
+                vec(any::<bool>(), 2_usize.pow(variable_count as u32)).prop_map(move |outputs| {
+                    TruthTable::new(arbitrary_variables(variable_count), outputs)
+                })
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Bdd<String> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<TruthTable<String>>()
+            .prop_map(|table| {
+                Bdd::try_from(table).expect("Arbitrary tables have only a few variables")
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::bdd::Bdd;
+    use crate::expressions::Expression;
+    use crate::table::TruthTable;
+    use crate::traits::SemanticEq;
+
+    proptest! {
+        #[test]
+        fn test_expression_conversions_ok(expression in any::<Expression<String>>()) {
+            let table = TruthTable::from(expression.clone());
+            let bdd = Bdd::try_from(expression.clone()).unwrap();
+
+            prop_assert!(table.semantic_eq(&TruthTable::from(bdd)));
+        }
+
+        #[test]
+        fn test_table_bdd_round_trip_ok(table in any::<TruthTable<String>>()) {
+            let bdd = Bdd::try_from(table.clone()).unwrap();
+
+            prop_assert!(TruthTable::from(bdd).semantic_eq(&table));
+        }
+    }
+}
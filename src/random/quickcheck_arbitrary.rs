@@ -0,0 +1,81 @@
+//! Implementations of [quickcheck::Arbitrary] for the Boolean function representations,
+//! enabled by the `quickcheck` feature.
+//!
+//! All functions use (a subset of) the variables `x0` to `x3`, see
+//! [ARBITRARY_VARIABLE_COUNT]. The generated functions are derived from a seed taken
+//! from the [Gen], the size of which bounds the size of the generated expressions.
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::bdd::Bdd;
+use crate::expressions::{Expression, ExpressionNode};
+use crate::random::{
+    arbitrary_variables, RandomExpressionBounds, RandomFunctions, ARBITRARY_VARIABLE_COUNT,
+};
+use crate::table::TruthTable;
+
+impl Arbitrary for Expression<String> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let bounds = RandomExpressionBounds {
+            max_size: g.size().max(1),
+            ..RandomExpressionBounds::default()
+        };
+
+        RandomFunctions::new(
+            arbitrary_variables(ARBITRARY_VARIABLE_COUNT),
+            u64::arbitrary(g),
+        )
+        .expression(&bounds)
+        .expect("The default bounds are valid")
+    }
+
+    /// Shrinks an expression into its direct sub-expressions.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self.node() {
+            ExpressionNode::Literal(_) | ExpressionNode::Constant(_) => {
+                Box::new(std::iter::empty())
+            }
+            ExpressionNode::Not(inner) => Box::new(std::iter::once(inner.clone())),
+            ExpressionNode::And(es) | ExpressionNode::Or(es) => Box::new(es.clone().into_iter()),
+        }
+    }
+}
+
+impl Arbitrary for TruthTable<String> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let variable_count = usize::arbitrary(g) % (ARBITRARY_VARIABLE_COUNT + 1);
+
+        RandomFunctions::new(arbitrary_variables(variable_count), u64::arbitrary(g)).uniform_table()
+    }
+}
+
+impl Arbitrary for Bdd<String> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Bdd::try_from(TruthTable::arbitrary(g)).expect("Arbitrary tables have only a few variables")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use crate::bdd::Bdd;
+    use crate::expressions::Expression;
+    use crate::table::TruthTable;
+    use crate::traits::SemanticEq;
+
+    quickcheck! {
+        fn test_expression_conversions_ok(expression: Expression<String>) -> bool {
+            let table = TruthTable::from(expression.clone());
+            let bdd = Bdd::try_from(expression).unwrap();
+
+            table.semantic_eq(&TruthTable::from(bdd))
+        }
+
+        fn test_bdd_table_round_trip_ok(bdd: Bdd<String>) -> bool {
+            let table = TruthTable::from(bdd.clone());
+
+            Bdd::try_from(table).unwrap() == bdd
+        }
+    }
+}
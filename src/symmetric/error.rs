@@ -0,0 +1,18 @@
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SymmetricFunctionError {
+    #[error("A symmetric function of {input_count} inputs needs {} values, got {value_count}", input_count + 1)]
+    ValueCountMismatch {
+        input_count: usize,
+        value_count: usize,
+    },
+}
+
+#[cfg(feature = "python")]
+impl From<SymmetricFunctionError> for PyErr {
+    fn from(err: SymmetricFunctionError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
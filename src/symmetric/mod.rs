@@ -0,0 +1,231 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::num::TryFromIntError;
+
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddVariable, BddVariableSet};
+
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::table::TruthTable;
+use crate::traits::{BooleanValuation, Symmetry};
+
+pub use error::SymmetricFunctionError;
+
+mod error;
+
+/// A totally symmetric Boolean function, i.e. a function whose output only depends on the
+/// number of its `true` inputs.
+///
+/// The function is stored compactly by its *value vector*: `values[k]` is the output of the
+/// function when exactly `k` inputs are `true`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymmetricFunction<T: Debug + Clone + Ord> {
+    inputs: BTreeSet<T>,
+    values: Vec<bool>,
+}
+
+impl<T: Debug + Clone + Ord> SymmetricFunction<T> {
+    /// Creates a symmetric function of the given `inputs` from its value vector, which must
+    /// contain exactly `inputs.len() + 1` values.
+    pub fn new(
+        inputs: BTreeSet<T>,
+        values: Vec<bool>,
+    ) -> Result<SymmetricFunction<T>, SymmetricFunctionError> {
+        if values.len() != inputs.len() + 1 {
+            return Err(SymmetricFunctionError::ValueCountMismatch {
+                input_count: inputs.len(),
+                value_count: values.len(),
+            });
+        }
+        Ok(SymmetricFunction { inputs, values })
+    }
+
+    /// The function which is `true` when at least `count` inputs are `true`.
+    pub fn mk_at_least(inputs: BTreeSet<T>, count: usize) -> SymmetricFunction<T> {
+        let values = (0..=inputs.len()).map(|it| it >= count).collect();
+        SymmetricFunction { inputs, values }
+    }
+
+    /// The function which is `true` when exactly `count` inputs are `true`.
+    pub fn mk_exactly(inputs: BTreeSet<T>, count: usize) -> SymmetricFunction<T> {
+        let values = (0..=inputs.len()).map(|it| it == count).collect();
+        SymmetricFunction { inputs, values }
+    }
+
+    /// The function which is `true` when more than half of the inputs are `true`.
+    pub fn mk_majority(inputs: BTreeSet<T>) -> SymmetricFunction<T> {
+        let count = inputs.len() / 2 + 1;
+        Self::mk_at_least(inputs, count)
+    }
+
+    /// The function which is `true` when an odd number of inputs is `true`.
+    pub fn mk_parity(inputs: BTreeSet<T>) -> SymmetricFunction<T> {
+        let values = (0..=inputs.len()).map(|it| it % 2 == 1).collect();
+        SymmetricFunction { inputs, values }
+    }
+
+    /// The value vector of the given `function`, or `None` if it is not totally symmetric.
+    pub fn from_function<F: Symmetry<T>>(function: &F) -> Option<SymmetricFunction<T>> {
+        if !function.is_totally_symmetric() {
+            return None;
+        }
+
+        // The output for `k` true inputs is the output for the first `k` inputs set to `true`.
+        let inputs = function.inputs();
+        let values = (0..=inputs.len())
+            .map(|count| {
+                let valuation = inputs
+                    .iter()
+                    .enumerate()
+                    .map(|(index, input)| (input.clone(), index < count))
+                    .collect();
+                function.restrict(&valuation).sat_point().is_some()
+            })
+            .collect();
+        Some(SymmetricFunction { inputs, values })
+    }
+
+    pub fn inputs(&self) -> &BTreeSet<T> {
+        &self.inputs
+    }
+
+    /// The output of this function for every number of `true` inputs.
+    pub fn values(&self) -> &[bool] {
+        &self.values
+    }
+
+    /// Evaluates this function in the given `valuation`. Inputs missing from the `valuation`
+    /// are treated as `false`.
+    pub fn evaluate(&self, valuation: &BooleanValuation<T>) -> bool {
+        let count = self
+            .inputs
+            .iter()
+            .filter(|input| valuation.get(*input).copied().unwrap_or_default())
+            .count();
+        self.values[count]
+    }
+
+    /// Builds the [Bdd] of this function. Its size is at most quadratic in the number
+    /// of inputs, since every node only needs to track the number of `true` inputs so far.
+    ///
+    /// Returns an `Err` if there are more than 2<sup>16</sup> inputs.
+    pub fn to_bdd(&self) -> Result<Bdd<T>, TryFromIntError> {
+        let variables = BddVariableSet::new_anonymous(u16::try_from(self.inputs.len())?);
+        let mut cache = HashMap::new();
+        let bdd = symmetric_bdd(&variables, &self.values, (0, 0), &mut cache);
+        Ok(Bdd::new(bdd, self.inputs.iter().cloned().collect()))
+    }
+
+    /// Builds an `Expression` of this function by converting its [SymmetricFunction::to_bdd].
+    ///
+    /// Returns an `Err` if there are more than 2<sup>16</sup> inputs.
+    pub fn to_expression(&self) -> Result<Expression<T>, TryFromIntError> {
+        Ok(Expression::from(self.to_bdd()?))
+    }
+
+    /// Builds the [TruthTable] of this function.
+    pub fn to_table(&self) -> TruthTable<T> {
+        let row_count = 1usize << self.inputs.len();
+        let outputs = (0..row_count)
+            .map(|row_index| self.values[row_index.count_ones() as usize])
+            .collect();
+        TruthTable::new(self.inputs.iter().cloned().collect(), outputs)
+    }
+}
+
+/// The BDD of the variables from `index` onwards, given that `count` of the already decided
+/// variables are `true`.
+fn symmetric_bdd(
+    variables: &BddVariableSet,
+    values: &[bool],
+    (index, count): (usize, usize),
+    cache: &mut HashMap<(usize, usize), InnerBdd>,
+) -> InnerBdd {
+    // The remaining variables can add between `0` and `remaining` true inputs.
+    let remaining = values.len() - 1 - index;
+    let reachable = &values[count..=count + remaining];
+    if reachable.iter().all(|it| *it) {
+        return variables.mk_true();
+    } else if !reachable.iter().any(|it| *it) {
+        return variables.mk_false();
+    } else if let Some(result) = cache.get(&(index, count)) {
+        return result.clone();
+    }
+
+    let high = symmetric_bdd(variables, values, (index + 1, count + 1), cache);
+    let low = symmetric_bdd(variables, values, (index + 1, count), cache);
+    let variable = variables.mk_var(BddVariable::from_index(index));
+    let result = InnerBdd::if_then_else(&variable, &high, &low);
+    cache.insert((index, count), result.clone());
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::traits::BooleanFunction;
+
+    fn inputs(variables: &[&str]) -> BTreeSet<String> {
+        variables.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_new_err() {
+        let result = SymmetricFunction::new(inputs(&["a", "b"]), vec![true, false]);
+        assert_eq!(
+            result,
+            Err(SymmetricFunctionError::ValueCountMismatch {
+                input_count: 2,
+                value_count: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_constructors_ok() {
+        let variables = inputs(&["a", "b", "c"]);
+        let majority = (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c"));
+        let parity = var("a") ^ var("b") ^ var("c");
+        let exactly_one = (var("a") & !var("b") & !var("c"))
+            | (!var("a") & var("b") & !var("c"))
+            | (!var("a") & !var("b") & var("c"));
+
+        let cases = [
+            (SymmetricFunction::mk_majority(variables.clone()), majority),
+            (SymmetricFunction::mk_parity(variables.clone()), parity),
+            (
+                SymmetricFunction::mk_exactly(variables.clone(), 1),
+                exactly_one,
+            ),
+        ];
+        for (function, expected) in cases {
+            let expected = Bdd::try_from(expected).unwrap();
+            assert!(function.to_bdd().unwrap().is_equivalent(&expected));
+            assert_eq!(function.to_table(), TruthTable::from(expected.clone()));
+            assert!(Bdd::try_from(function.to_expression().unwrap())
+                .unwrap()
+                .is_equivalent(&expected));
+        }
+
+        let at_least = SymmetricFunction::mk_at_least(variables, 4);
+        assert!(at_least
+            .to_bdd()
+            .unwrap()
+            .is_equivalent(&Bdd::mk_const(false)));
+    }
+
+    #[test]
+    fn test_from_function_ok() {
+        let function = TruthTable::from(var("a") ^ var("b") ^ (var("c") & var("d")));
+        assert_eq!(SymmetricFunction::from_function(&function), None);
+
+        let function = Bdd::try_from(!(var("a") ^ var("b") ^ var("c"))).unwrap();
+        let symmetric = SymmetricFunction::from_function(&function).unwrap();
+        assert_eq!(symmetric.values(), &[true, false, true, false]);
+        assert!(symmetric.to_bdd().unwrap().is_equivalent(&function));
+
+        let valuation = BooleanValuation::from([("a".to_string(), true)]);
+        assert!(!symmetric.evaluate(&valuation));
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TruthTableConstructionError {
+    #[error("Encountered the same variable multiple times: {name}")]
+    DuplicateVariableName { name: String },
+    #[error("Expected table with {variable_count} variables to contain {} outputs, found {actual_output_count} outputs", 2_usize.pow(*variable_count as u32))]
+    MismatchedOutputCountAndVariableCount {
+        variable_count: usize,
+        actual_output_count: usize,
+    },
+    #[error("Term {term} is out of range for a table with {variable_count} variables")]
+    TermOutOfRange { term: usize, variable_count: usize },
+    #[error("Invalid character '{actual}' in a truth table string, expected a {expected} digit")]
+    InvalidDigit {
+        actual: char,
+        expected: &'static str,
+    },
+    #[error("Expected truth table string for {variable_count} variables to contain {expected_len} digits, found {actual_len} digits")]
+    MismatchedStringLength {
+        variable_count: usize,
+        expected_len: usize,
+        actual_len: usize,
+    },
+}
+
+#[cfg(feature = "python")]
+impl From<TruthTableConstructionError> for PyErr {
+    fn from(err: TruthTableConstructionError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt::Debug;
+
+use crate::table::constructors::error::TruthTableConstructionError;
+use crate::table::TruthTable;
+
+impl<TLiteral: Debug + Clone + Eq + Ord> TruthTable<TLiteral> {
+    /// Creates a table from a hexadecimal truth table string in the convention used by ABC.
+    ///
+    /// The string lists the outputs from the highest to the lowest minterm, four minterms
+    /// per digit, where the minterm index uses the *first* of the given `inputs` as its
+    /// *least* significant bit. For example, `a & b` is `8` and `a & !b` is `2`. An optional
+    /// `0x` prefix is allowed.
+    ///
+    /// The string must contain exactly `2^inputs.len() / 4` digits. For tables with less
+    /// than two inputs, a single digit is expected and only its lowest `2^inputs.len()`
+    /// bits are used.
+    pub fn from_hex(inputs: Vec<TLiteral>, hex: &str) -> Result<Self, TruthTableConstructionError> {
+        let hex = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex);
+        let row_count = 2_usize.pow(inputs.len() as u32);
+        ensure_string_length(inputs.len(), hex, row_count.div_ceil(4))?;
+
+        let mut abc_outputs = Vec::with_capacity(4 * hex.len());
+        for character in hex.chars().rev() {
+            let digit =
+                character
+                    .to_digit(16)
+                    .ok_or(TruthTableConstructionError::InvalidDigit {
+                        actual: character,
+                        expected: "hexadecimal",
+                    })?;
+            abc_outputs.extend((0..4).map(|bit| digit & (1 << bit) != 0));
+        }
+        abc_outputs.truncate(row_count);
+
+        Self::from_abc_outputs(inputs, abc_outputs)
+    }
+
+    /// Creates a table from a binary truth table string in the convention used by ABC.
+    ///
+    /// Same as [TruthTable::from_hex], but each character is one output, i.e. `a & !b`
+    /// is `0010`. The string must contain exactly `2^inputs.len()` digits.
+    pub fn from_binary_string(
+        inputs: Vec<TLiteral>,
+        binary: &str,
+    ) -> Result<Self, TruthTableConstructionError> {
+        ensure_string_length(inputs.len(), binary, 2_usize.pow(inputs.len() as u32))?;
+
+        let abc_outputs = binary
+            .chars()
+            .rev()
+            .map(|character| match character {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(TruthTableConstructionError::InvalidDigit {
+                    actual: character,
+                    expected: "binary",
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_abc_outputs(inputs, abc_outputs)
+    }
+
+    /// The hexadecimal truth table string of this table, see [TruthTable::from_hex].
+    ///
+    /// Same as in ABC, tables with less than two inputs are repeated to fill a full digit,
+    /// i.e. the table of `a` is `A`.
+    pub fn to_hex(&self) -> String {
+        let mut abc_outputs = self.abc_outputs();
+        while abc_outputs.len() < 4 {
+            abc_outputs.extend_from_within(..);
+        }
+
+        abc_outputs
+            .chunks(4)
+            .rev()
+            .map(|chunk| {
+                let digit = chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(bit, output)| u32::from(*output) << bit)
+                    .sum::<u32>();
+                char::from_digit(digit, 16)
+                    .expect("Four bits always form a hexadecimal digit")
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+
+    /// The binary truth table string of this table, see [TruthTable::from_binary_string].
+    pub fn to_binary_string(&self) -> String {
+        self.abc_outputs()
+            .into_iter()
+            .rev()
+            .map(|output| if output { '1' } else { '0' })
+            .collect()
+    }
+
+    fn from_abc_outputs(
+        inputs: Vec<TLiteral>,
+        abc_outputs: Vec<bool>,
+    ) -> Result<Self, TruthTableConstructionError> {
+        let variable_count = inputs.len();
+        let outputs = (0..abc_outputs.len())
+            .map(|row_index| abc_outputs[reverse_bits(row_index, variable_count)])
+            .collect();
+
+        Self::from_outputs(inputs, outputs)
+    }
+
+    /// The outputs of this table indexed with the first input as the least significant bit.
+    fn abc_outputs(&self) -> Vec<bool> {
+        (0..self.outputs.len())
+            .map(|abc_index| self.outputs[reverse_bits(abc_index, self.variable_count())])
+            .collect()
+    }
+}
+
+/// Reverses the order of the lowest `bit_count` bits of `value`.
+fn reverse_bits(value: usize, bit_count: usize) -> usize {
+    (0..bit_count)
+        .filter(|bit| value & (1 << bit) != 0)
+        .map(|bit| 1 << (bit_count - 1 - bit))
+        .sum()
+}
+
+fn ensure_string_length(
+    variable_count: usize,
+    string: &str,
+    expected_len: usize,
+) -> Result<(), TruthTableConstructionError> {
+    let actual_len = string.chars().count();
+    if actual_len != expected_len {
+        return Err(TruthTableConstructionError::MismatchedStringLength {
+            variable_count,
+            expected_len,
+            actual_len,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{bool, var};
+    use crate::table::constructors::error::TruthTableConstructionError;
+    use crate::table::TruthTable;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_hex_ok() {
+        let cases = [
+            (names(&["a", "b"]), "8", var("a") & var("b")),
+            (names(&["a", "b"]), "2", var("a") & !var("b")),
+            (names(&["b", "a"]), "2", var("b") & !var("a")),
+            (names(&["a", "b", "c"]), "0xE8", {
+                (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c"))
+            }),
+            (names(&["a", "b", "c", "d"]), "8000", {
+                var("a") & var("b") & var("c") & var("d")
+            }),
+            (names(&["a"]), "A", var("a")),
+            (names(&["a"]), "2", var("a")),
+        ];
+
+        for (inputs, hex, expression) in cases {
+            let actual = TruthTable::from_hex(inputs, hex).unwrap();
+
+            assert_eq!(actual, TruthTable::from(expression), "{hex}");
+        }
+    }
+
+    #[test]
+    fn test_to_hex_ok() {
+        assert_eq!(TruthTable::from(var("a") & !var("b")).to_hex(), "2");
+        assert_eq!(TruthTable::from(var("a")).to_hex(), "A");
+        assert_eq!(TruthTable::from(bool(true)).to_hex(), "F");
+
+        let majority = (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c"));
+        assert_eq!(TruthTable::from(majority).to_hex(), "E8");
+
+        let input = TruthTable::from((var("a") ^ var("c")) | (var("b") & !var("d")) | var("e"));
+        let actual = TruthTable::from_hex(names(&["a", "b", "c", "d", "e"]), &input.to_hex());
+        assert_eq!(actual, Ok(input));
+    }
+
+    #[test]
+    fn test_binary_string_ok() {
+        let input = TruthTable::from(var("a") & !var("b"));
+        assert_eq!(input.to_binary_string(), "0010");
+
+        let input = TruthTable::from((var("a") ^ var("b")) | var("c"));
+        let actual =
+            TruthTable::from_binary_string(names(&["a", "b", "c"]), &input.to_binary_string());
+        assert_eq!(actual, Ok(input));
+    }
+
+    #[test]
+    fn test_from_string_nok() {
+        assert_eq!(
+            TruthTable::from_hex(names(&["a", "b", "c"]), "E"),
+            Err(TruthTableConstructionError::MismatchedStringLength {
+                variable_count: 3,
+                expected_len: 2,
+                actual_len: 1
+            })
+        );
+        assert_eq!(
+            TruthTable::from_hex(names(&["a", "b", "c"]), "EG"),
+            Err(TruthTableConstructionError::InvalidDigit {
+                actual: 'G',
+                expected: "hexadecimal"
+            })
+        );
+        assert_eq!(
+            TruthTable::from_binary_string(names(&["a", "b"]), "0120"),
+            Err(TruthTableConstructionError::InvalidDigit {
+                actual: '2',
+                expected: "binary"
+            })
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Debug;
+
+use crate::table::constructors::error::TruthTableConstructionError;
+use crate::table::TruthTable;
+
+impl<TLiteral: Debug + Clone + Eq + Ord> TruthTable<TLiteral> {
+    /// Creates a table which is `true` exactly for the given `minterms`.
+    ///
+    /// A minterm is the index of a row, where the first of the given `inputs` is the most
+    /// significant bit, i.e. `f(a, b, c) = Σm(1, 6)` is `(!a & !b & c) | (a & b & !c)`.
+    /// Repeated minterms are allowed.
+    pub fn from_minterms(
+        inputs: Vec<TLiteral>,
+        minterms: &[usize],
+    ) -> Result<Self, TruthTableConstructionError> {
+        Self::from_terms(inputs, minterms, true)
+    }
+
+    /// Creates a table which is `false` exactly for the given `maxterms`.
+    ///
+    /// The maxterms are indexed the same way as minterms in [TruthTable::from_minterms].
+    pub fn from_maxterms(
+        inputs: Vec<TLiteral>,
+        maxterms: &[usize],
+    ) -> Result<Self, TruthTableConstructionError> {
+        Self::from_terms(inputs, maxterms, false)
+    }
+
+    /// The sorted indices of rows in which this table is `true`.
+    ///
+    /// The indices use the (sorted) inputs of this table, see [TruthTable::from_minterms].
+    pub fn minterms(&self) -> Vec<usize> {
+        self.terms(true)
+    }
+
+    /// The sorted indices of rows in which this table is `false`.
+    ///
+    /// The indices use the (sorted) inputs of this table, see [TruthTable::from_maxterms].
+    pub fn maxterms(&self) -> Vec<usize> {
+        self.terms(false)
+    }
+
+    fn from_terms(
+        inputs: Vec<TLiteral>,
+        terms: &[usize],
+        term_value: bool,
+    ) -> Result<Self, TruthTableConstructionError> {
+        let row_count = 2_usize.pow(inputs.len() as u32);
+        let mut outputs = vec![!term_value; row_count];
+        for term in terms {
+            let output =
+                outputs
+                    .get_mut(*term)
+                    .ok_or(TruthTableConstructionError::TermOutOfRange {
+                        term: *term,
+                        variable_count: inputs.len(),
+                    })?;
+            *output = term_value;
+        }
+
+        Self::from_outputs(inputs, outputs)
+    }
+
+    fn terms(&self, term_value: bool) -> Vec<usize> {
+        self.outputs
+            .iter()
+            .enumerate()
+            .filter(|(_index, output)| **output == term_value)
+            .map(|(index, _output)| index)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::constructors::error::TruthTableConstructionError;
+    use crate::table::TruthTable;
+
+    #[test]
+    fn test_from_minterms_ok() {
+        let inputs = ["a", "b", "c"].map(|it| it.to_string()).to_vec();
+        let actual = TruthTable::from_minterms(inputs, &[1, 6, 6]).unwrap();
+        let expected = TruthTable::from(
+            (!var("a") & !var("b") & var("c")) | (var("a") & var("b") & !var("c")),
+        );
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.minterms(), vec![1, 6]);
+        assert_eq!(actual.maxterms(), vec![0, 2, 3, 4, 5, 7]);
+    }
+
+    #[test]
+    fn test_from_maxterms_ok() {
+        let inputs = ["b", "a"].map(|it| it.to_string()).to_vec();
+        // False only for `b=1, a=0`.
+        let actual = TruthTable::from_maxterms(inputs, &[2]).unwrap();
+        let expected = TruthTable::from(!var("b") | var("a"));
+
+        assert_eq!(actual, expected);
+        // In the sorted order (`a`, `b`), the maxterm is `a=0, b=1`.
+        assert_eq!(actual.maxterms(), vec![1]);
+    }
+
+    #[test]
+    fn test_from_terms_out_of_range_nok() {
+        let actual = TruthTable::from_minterms(vec!["a"], &[0, 2]);
+
+        assert_eq!(
+            actual,
+            Err(TruthTableConstructionError::TermOutOfRange {
+                term: 2,
+                variable_count: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use crate::table::constructors::error::TruthTableConstructionError;
+use crate::table::utils::boolean_point_to_row_index;
+use crate::table::TruthTable;
+use crate::traits::BooleanValuation;
+use crate::utils::row_index_to_bool_point;
+
+pub mod error;
+pub mod from_string;
+pub mod from_terms;
+
+impl<TLiteral: Debug + Clone + Eq + Ord> TruthTable<TLiteral> {
+    /// Creates a table from an explicit vector of outputs.
+    ///
+    /// The `outputs` are indexed by rows where the first of the given `inputs` is the most
+    /// significant bit (i.e. the same order as in [TruthTable::row]). The `inputs` do not
+    /// have to be sorted, but must not contain duplicates, and there must be exactly
+    /// `2^inputs.len()` outputs.
+    pub fn from_outputs(
+        inputs: Vec<TLiteral>,
+        outputs: Vec<bool>,
+    ) -> Result<Self, TruthTableConstructionError> {
+        ensure_unique_inputs(&inputs)?;
+
+        let expected_output_count = 2_usize.pow(inputs.len() as u32);
+        if outputs.len() != expected_output_count {
+            return Err(
+                TruthTableConstructionError::MismatchedOutputCountAndVariableCount {
+                    variable_count: inputs.len(),
+                    actual_output_count: outputs.len(),
+                },
+            );
+        }
+
+        Ok(Self::from_unsorted_outputs(inputs, outputs))
+    }
+
+    /// Creates a table by evaluating `function` for every valuation of the given `inputs`.
+    ///
+    /// The `inputs` do not have to be sorted, but must not contain duplicates.
+    pub fn from_fn<F: Fn(&BooleanValuation<TLiteral>) -> bool>(
+        inputs: Vec<TLiteral>,
+        function: F,
+    ) -> Result<Self, TruthTableConstructionError> {
+        ensure_unique_inputs(&inputs)?;
+
+        let mut inputs = inputs;
+        inputs.sort();
+
+        let outputs = (0..2_usize.pow(inputs.len() as u32))
+            .map(|row_index| {
+                let valuation = inputs
+                    .iter()
+                    .cloned()
+                    .zip(row_index_to_bool_point(row_index, inputs.len()))
+                    .collect::<BooleanValuation<_>>();
+                function(&valuation)
+            })
+            .collect();
+
+        Ok(TruthTable::new(inputs, outputs))
+    }
+
+    /// Sorts `inputs` and permutes the `outputs` accordingly. The `outputs` are expected
+    /// to be indexed with the first of the given `inputs` as the most significant bit.
+    ///
+    /// The caller is responsible for checking that the `inputs` are unique and that the
+    /// number of `outputs` matches.
+    fn from_unsorted_outputs(inputs: Vec<TLiteral>, outputs: Vec<bool>) -> Self {
+        let mut order = (0..inputs.len()).collect::<Vec<_>>();
+        order.sort_by(|x, y| inputs[*x].cmp(&inputs[*y]));
+
+        if order.iter().enumerate().all(|(i, original)| i == *original) {
+            return TruthTable::new(inputs, outputs);
+        }
+
+        let sorted_outputs = (0..outputs.len())
+            .map(|row_index| {
+                let sorted_point = row_index_to_bool_point(row_index, inputs.len());
+                let mut original_point = vec![false; inputs.len()];
+                for (sorted_index, original_index) in order.iter().enumerate() {
+                    original_point[*original_index] = sorted_point[sorted_index];
+                }
+                outputs[boolean_point_to_row_index(&original_point)]
+            })
+            .collect();
+        let sorted_inputs = order.into_iter().map(|i| inputs[i].clone()).collect();
+
+        TruthTable::new(sorted_inputs, sorted_outputs)
+    }
+}
+
+fn ensure_unique_inputs<TLiteral: Debug + Ord>(
+    inputs: &[TLiteral],
+) -> Result<(), TruthTableConstructionError> {
+    let mut seen = BTreeSet::new();
+    for input in inputs {
+        if !seen.insert(input) {
+            return Err(TruthTableConstructionError::DuplicateVariableName {
+                name: format!("{input:?}"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::constructors::error::TruthTableConstructionError;
+    use crate::table::TruthTable;
+
+    #[test]
+    fn test_from_outputs_ok() {
+        let actual = TruthTable::from_outputs(
+            vec!["a".to_string(), "b".to_string()],
+            vec![false, true, true, true],
+        )
+        .unwrap();
+        let expected = TruthTable::from(var("a") | var("b"));
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_from_outputs_unsorted_inputs_ok() {
+        // `b & !a`, written with `b` as the most significant bit
+        let actual = TruthTable::from_outputs(
+            vec!["b".to_string(), "a".to_string()],
+            vec![false, false, true, false],
+        )
+        .unwrap();
+        let expected = TruthTable::from(var("b") & !var("a"));
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_from_outputs_nok() {
+        let actual = TruthTable::from_outputs(vec!["a", "b"], vec![false, true, true]);
+        assert_eq!(
+            actual,
+            Err(
+                TruthTableConstructionError::MismatchedOutputCountAndVariableCount {
+                    variable_count: 2,
+                    actual_output_count: 3,
+                }
+            )
+        );
+
+        let actual = TruthTable::from_outputs(vec!["a", "a"], vec![false, true, true, true]);
+        assert_eq!(
+            actual,
+            Err(TruthTableConstructionError::DuplicateVariableName {
+                name: "\"a\"".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_fn_ok() {
+        let inputs = ["c", "a", "b"].map(|it| it.to_string()).to_vec();
+        let actual = TruthTable::from_fn(inputs, |valuation| {
+            valuation["a"] && (valuation["b"] || !valuation["c"])
+        })
+        .unwrap();
+        let expected = TruthTable::from(var("a") & (var("b") | !var("c")));
+
+        assert_eq!(actual, expected);
+    }
+}
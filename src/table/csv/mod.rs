@@ -1,4 +1,3 @@
 pub mod error;
 pub mod from_csv;
 pub mod to_csv;
-mod utils;
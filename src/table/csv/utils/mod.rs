@@ -1,3 +0,0 @@
-pub use string_to_bool::string_to_bool;
-
-mod string_to_bool;
@@ -6,7 +6,7 @@ use tabled::settings::{Padding, Style};
 use crate::table::TruthTable;
 
 #[cfg_attr(feature = "python", pyo3::pyclass)]
-#[derive(Default, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum TableStyle {
     Ascii,
     Modern,
@@ -16,7 +16,7 @@ pub enum TableStyle {
 }
 
 impl TableStyle {
-    fn build_table_with(&self, builder: Builder) -> String {
+    pub(crate) fn build_table_with(&self, builder: Builder) -> String {
         let mut built = builder.build();
         match self {
             // The default style is not as empty as it seems
@@ -0,0 +1,21 @@
+use crate::table::display_karnaugh::{KARNAUGH_MAX_VARIABLES, KARNAUGH_MIN_VARIABLES};
+
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KarnaughMapError {
+    #[error("Karnaugh maps support {KARNAUGH_MIN_VARIABLES} to {KARNAUGH_MAX_VARIABLES} variables, found {variable_count}")]
+    UnsupportedVariableCount { variable_count: usize },
+    #[error("The cover must be a non-constant expression in disjunctive normal form")]
+    CoverNotInDnf,
+    #[error("The cover uses variables that are not inputs of the table: {}", .names.join(", "))]
+    UnknownCoverVariables { names: Vec<String> },
+}
+
+#[cfg(feature = "python")]
+impl From<KarnaughMapError> for PyErr {
+    fn from(err: KarnaughMapError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
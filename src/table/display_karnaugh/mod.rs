@@ -0,0 +1,425 @@
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+
+use tabled::builder::Builder;
+
+use crate::expressions::{Expression, ExpressionNode};
+use crate::table::display_formatted::{TableBooleanFormatting, TableStyle};
+use crate::table::TruthTable;
+
+pub use error::KarnaughMapError;
+
+mod error;
+
+pub const KARNAUGH_MIN_VARIABLES: usize = 2;
+pub const KARNAUGH_MAX_VARIABLES: usize = 6;
+
+impl<TLiteral: Debug + Clone + Display + Eq + Ord> TruthTable<TLiteral> {
+    /// Renders this table as a Karnaugh map.
+    ///
+    /// The first `n / 2` (rounded down) inputs are used for the row labels, the remaining inputs
+    /// for the column labels. Both axes follow the reflected Gray code, so that neighbouring
+    /// cells (including the wrap-around) differ in exactly one variable.
+    ///
+    /// Only tables with 2 to 6 inputs are supported.
+    pub fn to_karnaugh_map(
+        &self,
+        table_style: TableStyle,
+        inputs_formatting: TableBooleanFormatting,
+        output_formatting: TableBooleanFormatting,
+    ) -> Result<String, KarnaughMapError> {
+        self.to_karnaugh_map_common(table_style, &inputs_formatting, &output_formatting, &[])
+    }
+
+    /// Same as [TruthTable::to_karnaugh_map], but additionally marks the implicant groups
+    /// of the given `cover`.
+    ///
+    /// The `cover` must be an expression in disjunctive normal form over the inputs of this
+    /// table. Its conjunctive clauses are numbered from `1` (in the order in which they appear
+    /// in the expression), each cell lists the numbers of the clauses that contain it, and
+    /// a legend with the clauses is printed below the map. The method does not check that
+    /// the clauses are actually implicants of this function, such that also wrong covers
+    /// can be reviewed. Clauses with contradictory literals (like `a & !a`) contain no cells
+    /// and are marked as empty in the legend.
+    pub fn to_karnaugh_map_with_cover(
+        &self,
+        table_style: TableStyle,
+        inputs_formatting: TableBooleanFormatting,
+        output_formatting: TableBooleanFormatting,
+        cover: &Expression<TLiteral>,
+    ) -> Result<String, KarnaughMapError> {
+        if !cover.is_dnf() {
+            return Err(KarnaughMapError::CoverNotInDnf);
+        }
+
+        let clauses = dnf_clauses(cover);
+        let unknown = clauses
+            .iter()
+            .flatten()
+            .map(|(variable, _value)| variable)
+            .filter(|variable| self.inputs.binary_search(variable).is_err())
+            .map(|variable| variable.to_string())
+            .collect::<BTreeSet<_>>();
+        if !unknown.is_empty() {
+            return Err(KarnaughMapError::UnknownCoverVariables {
+                names: unknown.into_iter().collect(),
+            });
+        }
+
+        let map = self.to_karnaugh_map_common(
+            table_style,
+            &inputs_formatting,
+            &output_formatting,
+            &clauses,
+        )?;
+        let legend = clauses
+            .iter()
+            .enumerate()
+            .map(|(index, clause)| {
+                let literals = clause
+                    .iter()
+                    .map(|(variable, value)| {
+                        if *value {
+                            variable.to_string()
+                        } else {
+                            format!("!{variable}")
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let is_contradictory = clause
+                    .iter()
+                    .zip(clause.iter().skip(1))
+                    .any(|((first, _), (second, _))| first == second);
+                let flag = if is_contradictory { " (empty)" } else { "" };
+                format!("{}: {}{flag}", index + 1, literals.join(" & "))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(format!("{map}\n{}", legend.join("\n")))
+    }
+
+    fn to_karnaugh_map_common(
+        &self,
+        table_style: TableStyle,
+        inputs_formatting: &TableBooleanFormatting,
+        output_formatting: &TableBooleanFormatting,
+        clauses: &[BTreeSet<(TLiteral, bool)>],
+    ) -> Result<String, KarnaughMapError> {
+        let variable_count = self.variable_count();
+        if !(KARNAUGH_MIN_VARIABLES..=KARNAUGH_MAX_VARIABLES).contains(&variable_count) {
+            return Err(KarnaughMapError::UnsupportedVariableCount { variable_count });
+        }
+
+        let row_variable_count = variable_count / 2;
+        let column_variable_count = variable_count - row_variable_count;
+        let row_codes = gray_code(row_variable_count);
+        let column_codes = gray_code(column_variable_count);
+
+        let names = |range: std::ops::Range<usize>| {
+            self.inputs[range]
+                .iter()
+                .map(|it| it.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+
+        let mut builder = Builder::default();
+        builder.push_record(
+            [format!(
+                "{} \\ {}",
+                names(0..row_variable_count),
+                names(row_variable_count..variable_count)
+            )]
+            .into_iter()
+            .chain(
+                column_codes
+                    .iter()
+                    .map(|code| format_code(inputs_formatting, code)),
+            ),
+        );
+
+        for row_code in &row_codes {
+            let cells = column_codes.iter().map(|column_code| {
+                let point = [row_code.as_slice(), column_code.as_slice()].concat();
+                let row_index = crate::table::utils::boolean_point_to_row_index(&point);
+                let output = output_formatting.format_bool(&self.outputs[row_index]);
+
+                if clauses.is_empty() {
+                    return output;
+                }
+
+                let groups = clauses
+                    .iter()
+                    .enumerate()
+                    .filter(|(_index, clause)| self.point_in_clause(&point, clause))
+                    .map(|(index, _clause)| (index + 1).to_string())
+                    .collect::<Vec<_>>();
+
+                if groups.is_empty() {
+                    output
+                } else {
+                    format!("{output} ({})", groups.join(","))
+                }
+            });
+
+            builder.push_record(
+                [format_code(inputs_formatting, row_code)]
+                    .into_iter()
+                    .chain(cells),
+            );
+        }
+
+        Ok(table_style.build_table_with(builder))
+    }
+
+    /// Returns `true` if every literal of the `clause` is satisfied by the `point`, hence
+    /// a contradictory clause contains no points.
+    fn point_in_clause(&self, point: &[bool], clause: &BTreeSet<(TLiteral, bool)>) -> bool {
+        clause.iter().all(|(variable, value)| {
+            self.inputs
+                .binary_search(variable)
+                .is_ok_and(|index| point[index] == *value)
+        })
+    }
+}
+
+/// The reflected binary Gray code over `bit_count` bits, most significant bit first.
+fn gray_code(bit_count: usize) -> Vec<Vec<bool>> {
+    (0..1usize << bit_count)
+        .map(|index| {
+            let code = index ^ (index >> 1);
+            (0..bit_count)
+                .rev()
+                .map(|bit| code & (1 << bit) != 0)
+                .collect()
+        })
+        .collect()
+}
+
+/// Formats the values of a Gray code label. Single-character values are concatenated
+/// (e.g. `01`), longer values are separated by commas (e.g. `false,true`).
+fn format_code(formatting: &TableBooleanFormatting, code: &[bool]) -> String {
+    let values = code
+        .iter()
+        .map(|value| formatting.format_bool(value))
+        .collect::<Vec<_>>();
+    let separator = if values.iter().all(|it| it.len() == 1) {
+        ""
+    } else {
+        ","
+    };
+    values.join(separator)
+}
+
+/// Splits an expression in disjunctive normal form into its conjunctive clauses, each
+/// represented by the set of its literals.
+///
+/// A clause with contradictory literals (like `a & !a`) keeps both literals.
+fn dnf_clauses<T: Debug + Clone + Ord>(expression: &Expression<T>) -> Vec<BTreeSet<(T, bool)>> {
+    match expression.node() {
+        ExpressionNode::Or(es) => es.iter().flat_map(dnf_clauses).collect(),
+        _ => {
+            let mut clause = BTreeSet::new();
+            collect_clause_literals(expression, &mut clause);
+            vec![clause]
+        }
+    }
+}
+
+fn collect_clause_literals<T: Debug + Clone + Ord>(
+    expression: &Expression<T>,
+    clause: &mut BTreeSet<(T, bool)>,
+) {
+    match expression.node() {
+        ExpressionNode::Literal(name) => {
+            clause.insert((name.clone(), true));
+        }
+        ExpressionNode::Not(inner) => {
+            if let ExpressionNode::Literal(name) = inner.node() {
+                clause.insert((name.clone(), false));
+            }
+        }
+        ExpressionNode::And(es) => es.iter().for_each(|e| collect_clause_literals(e, clause)),
+        // Not reachable for expressions in DNF.
+        ExpressionNode::Or(_) | ExpressionNode::Constant(_) => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{bool, var};
+
+    #[test]
+    fn test_gray_code_ok() {
+        let expected = vec![
+            vec![false, false],
+            vec![false, true],
+            vec![true, true],
+            vec![true, false],
+        ];
+
+        assert_eq!(gray_code(2), expected);
+        assert_eq!(gray_code(1), vec![vec![false], vec![true]]);
+    }
+
+    #[test]
+    fn test_karnaugh_two_variables_ok() {
+        let input = TruthTable::from(var("a") | var("b"));
+
+        let actual = input
+            .to_karnaugh_map(
+                TableStyle::Markdown,
+                TableBooleanFormatting::Number,
+                TableBooleanFormatting::Number,
+            )
+            .unwrap();
+        let expected = concat!(
+            "| a \\ b | 0 | 1 |\n",
+            "|-------|---|---|\n",
+            "| 0     | 0 | 1 |\n",
+            "| 1     | 1 | 1 |",
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_karnaugh_four_variables_gray_order_ok() {
+        // True exactly for `a=0, b=1, c=1, d=0`.
+        let input = TruthTable::from(!var("a") & var("b") & var("c") & !var("d"));
+
+        let actual = input
+            .to_karnaugh_map(
+                TableStyle::Markdown,
+                TableBooleanFormatting::Number,
+                TableBooleanFormatting::Character,
+            )
+            .unwrap();
+        let expected = concat!(
+            "| a,b \\ c,d | 00 | 01 | 11 | 10 |\n",
+            "|-----------|----|----|----|----|\n",
+            "| 00        | F  | F  | F  | F  |\n",
+            "| 01        | F  | F  | F  | T  |\n",
+            "| 11        | F  | F  | F  | F  |\n",
+            "| 10        | F  | F  | F  | F  |",
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_karnaugh_with_cover_ok() {
+        let function = (var("a") & var("b")) | var("c");
+        let input = TruthTable::from(function.clone());
+
+        let actual = input
+            .to_karnaugh_map_with_cover(
+                TableStyle::Markdown,
+                TableBooleanFormatting::Number,
+                TableBooleanFormatting::Number,
+                &function,
+            )
+            .unwrap();
+        let expected = concat!(
+            "| a \\ b,c | 00 | 01    | 11      | 10    |\n",
+            "|---------|----|-------|---------|-------|\n",
+            "| 0       | 0  | 1 (2) | 1 (2)   | 0     |\n",
+            "| 1       | 0  | 1 (2) | 1 (1,2) | 1 (1) |\n",
+            "1: a & b\n",
+            "2: c",
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_karnaugh_with_contradictory_cover_ok() {
+        let input = TruthTable::from(var("a") & var("b"));
+        let cover = (var("a") & var("b")) | (var("a") & !var("a"));
+
+        let actual = input
+            .to_karnaugh_map_with_cover(
+                TableStyle::Markdown,
+                TableBooleanFormatting::Number,
+                TableBooleanFormatting::Number,
+                &cover,
+            )
+            .unwrap();
+        let expected = concat!(
+            "| a \\ b | 0 | 1     |\n",
+            "|-------|---|-------|\n",
+            "| 0     | 0 | 0     |\n",
+            "| 1     | 0 | 1 (1) |\n",
+            "1: a & b\n",
+            "2: !a & a (empty)",
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_karnaugh_variable_count_nok() {
+        for input in [
+            TruthTable::from(var("a")),
+            TruthTable::from(Expression::n_ary_and(
+                &(0..7).map(|i| var(format!("x{i}"))).collect::<Vec<_>>(),
+            )),
+        ] {
+            let actual = input.to_karnaugh_map(
+                TableStyle::Empty,
+                TableBooleanFormatting::Number,
+                TableBooleanFormatting::Number,
+            );
+
+            assert!(matches!(
+                actual,
+                Err(KarnaughMapError::UnsupportedVariableCount { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_karnaugh_six_variables_ok() {
+        let input = TruthTable::from(Expression::n_ary_or(
+            &(0..6).map(|i| var(format!("x{i}"))).collect::<Vec<_>>(),
+        ));
+
+        let actual = input
+            .to_karnaugh_map(
+                TableStyle::Empty,
+                TableBooleanFormatting::Number,
+                TableBooleanFormatting::Number,
+            )
+            .unwrap();
+
+        // One header row and eight rows for the Gray code over three variables.
+        assert_eq!(actual.lines().count(), 9);
+    }
+
+    #[test]
+    fn test_karnaugh_invalid_cover_nok() {
+        let input = TruthTable::from(var("a") & var("b"));
+        let format = TableBooleanFormatting::Number;
+
+        let not_dnf = !(var("a") & var("b"));
+        assert_eq!(
+            input.to_karnaugh_map_with_cover(TableStyle::Empty, format, format, &not_dnf),
+            Err(KarnaughMapError::CoverNotInDnf)
+        );
+
+        let constant = bool(true);
+        assert_eq!(
+            input.to_karnaugh_map_with_cover(TableStyle::Empty, format, format, &constant),
+            Err(KarnaughMapError::CoverNotInDnf)
+        );
+
+        let unknown = var("a") & var("c");
+        assert_eq!(
+            input.to_karnaugh_map_with_cover(TableStyle::Empty, format, format, &unknown),
+            Err(KarnaughMapError::UnknownCoverVariables {
+                names: vec!["c".to_string()]
+            })
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::table::display_formatted::{TableStyle, ALL_BOOL_STRINGS};
+
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TruthTableFromFormattedError {
+    #[error("Encountered the same variable multiple times: {name}")]
+    DuplicateVariableName { name: String },
+    #[error("Reached end of input before reading the header row.")]
+    UnexpectedEof,
+    #[error("Line {line_index} does not follow the {style:?} table style: '{line}'")]
+    UnexpectedLine {
+        line_index: usize,
+        line: String,
+        style: TableStyle,
+    },
+    #[error("Expected row with index {row_index} to contain {expected_row_len} cells, found {actual_row_len} cells")]
+    RecordDifferentSizeThanHeader {
+        row_index: usize,
+        expected_row_len: usize,
+        actual_row_len: usize,
+    },
+    #[error("Invalid cell value, found '{actual}', expected one of '{}'", ALL_BOOL_STRINGS.join(", "))]
+    NonBooleanCellValue { actual: String },
+    #[error("Couldn't get last column of boolean function outputs.")]
+    NoOutputColumn,
+    #[error("Expected table with {variable_count} variables to contain {} rows, found {actual_row_count} rows", 2_usize.pow(*variable_count as u32))]
+    MismatchedRecordCountAndVariableCount {
+        variable_count: usize,
+        actual_row_count: usize,
+    },
+    #[error("Row with index {row_index} repeats the inputs of an earlier row")]
+    DuplicateRecord { row_index: usize },
+}
+
+#[cfg(feature = "python")]
+impl From<TruthTableFromFormattedError> for PyErr {
+    fn from(err: TruthTableFromFormattedError) -> PyErr {
+        use pyo3::exceptions::{PyEOFError, PyRuntimeError, PyTypeError};
+        use TruthTableFromFormattedError::*;
+
+        match err {
+            e @ UnexpectedEof => PyEOFError::new_err(e.to_string()),
+            e @ NonBooleanCellValue { .. } => PyTypeError::new_err(e.to_string()),
+            e @ DuplicateVariableName { .. }
+            | e @ UnexpectedLine { .. }
+            | e @ RecordDifferentSizeThanHeader { .. }
+            | e @ NoOutputColumn
+            | e @ MismatchedRecordCountAndVariableCount { .. }
+            | e @ DuplicateRecord { .. } => PyRuntimeError::new_err(e.to_string()),
+        }
+    }
+}
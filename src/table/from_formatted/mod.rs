@@ -0,0 +1,353 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::table::display_formatted::TableStyle;
+use crate::table::from_formatted::error::TruthTableFromFormattedError;
+use crate::table::utils::{string_to_bool, values_to_row_index};
+use crate::table::TruthTable;
+
+pub mod error;
+
+const MODERN_BORDER_CHARACTERS: &str = "┌┬┐├┼┤└┴┘─";
+const ASCII_BORDER_CHARACTERS: &str = "+-";
+const MARKDOWN_SEPARATOR_CHARACTERS: &str = "|-: ";
+
+impl TruthTable<String> {
+    /// Parses a table written by [TruthTable::to_string_formatted] (or typed by hand in the
+    /// same layout), detecting the [TableStyle] from the first line.
+    ///
+    /// See [TruthTable::from_formatted_with_style] for details.
+    pub fn from_formatted(input: &str) -> Result<TruthTable<String>, TruthTableFromFormattedError> {
+        Self::from_formatted_with_style(input, detect_style(input))
+    }
+
+    /// Parses a table in the given [TableStyle].
+    ///
+    /// The first row is the header with the variable names, the last column contains
+    /// the outputs (its header is ignored). Cells may use any [TableBooleanFormatting],
+    /// even mixed within one table, and rows may be in any order, but each valuation
+    /// of the inputs must appear exactly once. Blank lines and border lines are skipped.
+    ///
+    /// In the [TableStyle::Empty] style, cells are separated by whitespace, hence variable
+    /// names must not contain any whitespace.
+    ///
+    /// [TableBooleanFormatting]: crate::table::display_formatted::TableBooleanFormatting
+    pub fn from_formatted_with_style(
+        input: &str,
+        style: TableStyle,
+    ) -> Result<TruthTable<String>, TruthTableFromFormattedError> {
+        if input.trim().is_empty() {
+            return Ok(TruthTable::new(vec![], vec![]));
+        }
+
+        let mut records = split_records(input, style)?.into_iter();
+        let header = records
+            .next()
+            .ok_or(TruthTableFromFormattedError::UnexpectedEof)?;
+        let variable_column_index_map = inputs_from_header(&header)?;
+        let records = records.collect::<Vec<_>>();
+
+        let variable_count = variable_column_index_map.len();
+        if records.len() != 2_usize.pow(variable_count as u32) {
+            return Err(
+                TruthTableFromFormattedError::MismatchedRecordCountAndVariableCount {
+                    variable_count,
+                    actual_row_count: records.len(),
+                },
+            );
+        }
+
+        let order = variable_column_index_map
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut outputs = vec![None; records.len()];
+        for (row_index, record) in records.iter().enumerate() {
+            if record.len() != header.len() {
+                return Err(
+                    TruthTableFromFormattedError::RecordDifferentSizeThanHeader {
+                        row_index,
+                        expected_row_len: header.len(),
+                        actual_row_len: record.len(),
+                    },
+                );
+            }
+
+            let valuation = variable_column_index_map
+                .iter()
+                .map(|(name, column_index)| Ok((name.clone(), parse_cell(&record[*column_index])?)))
+                .collect::<Result<BTreeMap<_, _>, _>>()?;
+            let output = parse_cell(record.last().expect("Records have the size of the header"))?;
+
+            let index = values_to_row_index(&order, &valuation);
+            if outputs[index].replace(output).is_some() {
+                return Err(TruthTableFromFormattedError::DuplicateRecord { row_index });
+            }
+        }
+
+        // Every row was set exactly once, since there are no duplicates and the counts match.
+        Ok(TruthTable::new(
+            order,
+            outputs.into_iter().flatten().collect(),
+        ))
+    }
+}
+
+fn detect_style(input: &str) -> TableStyle {
+    let first_character = input
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .and_then(|line| line.chars().next());
+
+    match first_character {
+        Some('┌') | Some('│') => TableStyle::Modern,
+        Some('+') => TableStyle::Ascii,
+        Some('|') => TableStyle::Markdown,
+        _ => TableStyle::Empty,
+    }
+}
+
+/// Splits the input into rows of trimmed cells, skipping blank and border lines.
+fn split_records(
+    input: &str,
+    style: TableStyle,
+) -> Result<Vec<Vec<String>>, TruthTableFromFormattedError> {
+    let mut records = vec![];
+
+    for (line_index, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let unexpected_line = || TruthTableFromFormattedError::UnexpectedLine {
+            line_index,
+            line: line.to_string(),
+            style,
+        };
+
+        let cells = match style {
+            TableStyle::Empty => {
+                records.push(trimmed.split_whitespace().map(str::to_string).collect());
+                continue;
+            }
+            TableStyle::Markdown => {
+                if trimmed
+                    .chars()
+                    .all(|c| MARKDOWN_SEPARATOR_CHARACTERS.contains(c))
+                {
+                    continue;
+                }
+                if !trimmed.contains('|') {
+                    return Err(unexpected_line());
+                }
+                // Leading and trailing pipes are optional in Markdown.
+                let inner = trimmed.strip_prefix('|').unwrap_or(trimmed);
+                inner.strip_suffix('|').unwrap_or(inner).split('|')
+            }
+            TableStyle::Ascii => {
+                if trimmed.chars().all(|c| ASCII_BORDER_CHARACTERS.contains(c)) {
+                    continue;
+                }
+                strip_borders(trimmed, '|')
+                    .ok_or_else(unexpected_line)?
+                    .split('|')
+            }
+            TableStyle::Modern => {
+                if trimmed
+                    .chars()
+                    .all(|c| MODERN_BORDER_CHARACTERS.contains(c))
+                {
+                    continue;
+                }
+                strip_borders(trimmed, '│')
+                    .ok_or_else(unexpected_line)?
+                    .split('│')
+            }
+        };
+
+        records.push(cells.map(|cell| cell.trim().to_string()).collect());
+    }
+
+    Ok(records)
+}
+
+fn strip_borders(line: &str, border: char) -> Option<&str> {
+    line.strip_prefix(border)?.strip_suffix(border)
+}
+
+fn inputs_from_header(
+    header: &[String],
+) -> Result<BTreeMap<String, usize>, TruthTableFromFormattedError> {
+    // skip last element = output
+    let Some((_output, inputs)) = header.split_last() else {
+        return Err(TruthTableFromFormattedError::NoOutputColumn);
+    };
+
+    let mut unique_test_set = BTreeSet::new();
+    for name in inputs {
+        if !unique_test_set.insert(name) {
+            return Err(TruthTableFromFormattedError::DuplicateVariableName { name: name.clone() });
+        }
+    }
+
+    Ok(inputs
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (name.clone(), index))
+        .collect())
+}
+
+fn parse_cell(cell: &str) -> Result<bool, TruthTableFromFormattedError> {
+    string_to_bool(cell).ok_or(TruthTableFromFormattedError::NonBooleanCellValue {
+        actual: cell.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rstest_reuse::{apply, template};
+
+    use crate::expressions::var;
+    use crate::table::display_formatted::TableBooleanFormatting::{
+        self, CapitalizedWord, Character, Number, Word,
+    };
+    use crate::table::display_formatted::TableStyle::{self, Ascii, Empty, Markdown, Modern};
+    use crate::table::from_formatted::error::TruthTableFromFormattedError;
+    use crate::table::TruthTable;
+
+    #[template]
+    #[rstest]
+    fn round_trip_template(
+        #[values(Ascii, Modern, Markdown, Empty)] style: TableStyle,
+        #[values(Number, Character, Word, CapitalizedWord)]
+        inputs_formatting: TableBooleanFormatting,
+        #[values(Number, Character, Word, CapitalizedWord)]
+        output_formatting: TableBooleanFormatting,
+    ) {
+    }
+
+    #[apply(round_trip_template)]
+    fn test_round_trip_ok(
+        style: TableStyle,
+        inputs_formatting: TableBooleanFormatting,
+        output_formatting: TableBooleanFormatting,
+    ) {
+        let tables = [
+            TruthTable::from((var("a") & !var("ščř")) | var("c")),
+            TruthTable::from(var("x") ^ var("y")),
+            TruthTable::new(vec![], vec![true]),
+        ];
+
+        for table in tables {
+            let formatted = table.to_string_formatted(style, inputs_formatting, output_formatting);
+
+            assert_eq!(TruthTable::from_formatted(&formatted), Ok(table.clone()));
+            assert_eq!(
+                TruthTable::from_formatted_with_style(&formatted, style),
+                Ok(table)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hand_written_markdown_ok() {
+        let input = concat!(
+            "\n",
+            "b | a | out\n",
+            ":-:|:-:|---\n",
+            "1 | 1 | False\n",
+            "0 | 0 | F\n",
+            "0 | 1 | true\n",
+            "1 | 0 | false\n",
+        );
+        let expected = TruthTable::from(var("a") & !var("b"));
+
+        assert_eq!(
+            TruthTable::from_formatted_with_style(input, Markdown),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_misaligned_row_nok() {
+        let input = concat!(
+            "| a | b | result |\n",
+            "|---|---|--------|\n",
+            "| 0 | 0 | 0 |\n",
+            "| 0 | 1 | 1 |\n",
+            "| 1 | 0 | 1 | 1 |\n",
+            "| 1 | 1 | 1 |",
+        );
+
+        assert_eq!(
+            TruthTable::from_formatted(input),
+            Err(
+                TruthTableFromFormattedError::RecordDifferentSizeThanHeader {
+                    row_index: 2,
+                    expected_row_len: 3,
+                    actual_row_len: 4,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_missing_border_nok() {
+        let input = concat!(
+            "+---+--------+\n",
+            "| a | result |\n",
+            "+---+--------+\n",
+            "| 0 | 0 |\n",
+            "| 1 | 1\n",
+            "+---+--------+",
+        );
+
+        assert_eq!(
+            TruthTable::from_formatted(input),
+            Err(TruthTableFromFormattedError::UnexpectedLine {
+                line_index: 4,
+                line: "| 1 | 1".to_string(),
+                style: Ascii,
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_records_nok() {
+        assert_eq!(
+            TruthTable::from_formatted("a b result\n0 0 1\n0 1 1\n1 0 0\n"),
+            Err(
+                TruthTableFromFormattedError::MismatchedRecordCountAndVariableCount {
+                    variable_count: 2,
+                    actual_row_count: 3,
+                }
+            )
+        );
+        assert_eq!(
+            TruthTable::from_formatted("a result\n0 1\n0 0\n"),
+            Err(TruthTableFromFormattedError::DuplicateRecord { row_index: 1 })
+        );
+        assert_eq!(
+            TruthTable::from_formatted("a a result\n0 0 1\n0 1 1\n1 0 0\n1 1 0"),
+            Err(TruthTableFromFormattedError::DuplicateVariableName {
+                name: "a".to_string()
+            })
+        );
+        assert_eq!(
+            TruthTable::from_formatted("a result\n0 yes\n1 no\n"),
+            Err(TruthTableFromFormattedError::NonBooleanCellValue {
+                actual: "yes".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_ok() {
+        assert_eq!(
+            TruthTable::from_formatted("  \n"),
+            Ok(TruthTable::new(vec![], vec![]))
+        );
+    }
+}
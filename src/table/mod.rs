@@ -6,9 +6,12 @@ use crate::expressions::{Expression, ExpressionNode};
 use crate::table::display_formatted::TableBooleanFormatting;
 use crate::utils::row_index_to_bool_point;
 
+pub mod constructors;
 #[cfg(feature = "csv")]
 pub mod csv;
 pub mod display_formatted;
+pub mod display_karnaugh;
+pub mod from_formatted;
 pub mod iterators;
 pub mod traits;
 mod utils;
@@ -0,0 +1,42 @@
+use crate::table::TruthTable;
+use crate::traits::Canalization;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> Canalization<T> for TruthTable<T> {
+    fn canalized_output(&self, variable: &T, value: bool) -> Option<bool> {
+        let bit = self.variable_bit(variable);
+        let mut outputs = (0..self.row_count())
+            .filter(|row_index| bit.is_none_or(|bit| (row_index & bit != 0) == value))
+            .map(|row_index| self.outputs[row_index]);
+
+        let first = outputs.next()?;
+        outputs.all(|output| output == first).then_some(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::{Canalization, CanalizingInput};
+
+    #[test]
+    fn test_canalization_ok() {
+        let function = TruthTable::from(var("a") | (var("b") & !var("c")));
+
+        assert_eq!(
+            function.canalizing_variables(),
+            vec![CanalizingInput {
+                variable: "a".to_string(),
+                value: true,
+                output: true,
+            }]
+        );
+        assert_eq!(function.nested_canalizing_layers().unwrap().len(), 2);
+        assert_eq!(function.canalizing_depth(), 3);
+
+        let function = TruthTable::from((var("a") ^ var("b")) | var("c"));
+        assert_eq!(function.canalizing_depth(), 1);
+        assert!(!function.is_nested_canalizing());
+    }
+}
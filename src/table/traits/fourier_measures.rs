@@ -0,0 +1,304 @@
+use crate::table::TruthTable;
+use crate::traits::fourier_measures::check_flip_probability;
+use crate::traits::{FourierMeasures, FourierMeasuresError};
+use num_bigint::{BigInt, BigUint};
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> FourierMeasures<T> for TruthTable<T> {
+    fn influence(&self, variable: &T) -> BigRational {
+        let Some(bit) = self.variable_bit(variable) else {
+            return BigRational::zero();
+        };
+
+        let sensitive_rows = (0..self.row_count())
+            .filter(|row_index| row_index & bit == 0)
+            .filter(|row_index| self.outputs[*row_index] != self.outputs[row_index ^ bit])
+            .count();
+
+        // There are `2^(n-1)` pairs of rows that differ only in `variable`.
+        BigRational::new(
+            BigInt::from(sensitive_rows),
+            BigInt::one() << (self.variable_count() - 1),
+        )
+    }
+
+    fn max_sensitivity(&self) -> usize {
+        (0..self.row_count())
+            .map(|row_index| self.row_sensitivity(row_index))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn max_block_sensitivity(&self) -> usize {
+        (0..self.row_count())
+            .map(|row_index| self.row_block_sensitivity(row_index))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn average_block_sensitivity(&self) -> BigRational {
+        let total = (0..self.row_count())
+            .map(|row_index| self.row_block_sensitivity(row_index))
+            .sum::<usize>();
+
+        BigRational::new(BigInt::from(total), BigInt::from(self.row_count()))
+    }
+
+    fn noise_stability(
+        &self,
+        flip_probability: &BigRational,
+    ) -> Result<BigRational, FourierMeasuresError> {
+        check_flip_probability(flip_probability)?;
+
+        let correlation =
+            BigRational::one() - BigRational::from_integer(2.into()) * flip_probability;
+
+        let mut result = BigRational::zero();
+        let mut correlation_power = BigRational::one();
+        for level_weight in self.fourier_level_weights() {
+            result += &correlation_power * BigRational::from_integer(level_weight.into());
+            correlation_power *= &correlation;
+        }
+
+        // The Fourier coefficients are the Walsh coefficients divided by `2^n`.
+        Ok(result / BigRational::from_integer(BigInt::one() << (2 * self.variable_count())))
+    }
+}
+
+impl<T: Debug + Clone + Ord> TruthTable<T> {
+    /// The bit mask which corresponds to the given `variable` in a row index, or `None` if
+    /// `variable` is not an input of this table.
+    ///
+    /// The first input is the most significant bit of the row index.
+    pub(crate) fn variable_bit(&self, variable: &T) -> Option<usize> {
+        self.inputs
+            .binary_search(variable)
+            .ok()
+            .map(|index| 1 << (self.variable_count() - index - 1))
+    }
+
+    /// The number of single-variable flips which change the output in the given row.
+    fn row_sensitivity(&self, row_index: usize) -> usize {
+        (0..self.variable_count())
+            .filter(|bit_index| {
+                self.outputs[row_index] != self.outputs[row_index ^ (1 << bit_index)]
+            })
+            .count()
+    }
+
+    /// The maximal number of disjoint sensitive blocks in the given row.
+    ///
+    /// A block is represented as a bit mask over row indices. It is sufficient to only consider
+    /// the minimal sensitive blocks, since any packing of sensitive blocks can be shrunk to
+    /// a packing of the minimal ones.
+    fn row_block_sensitivity(&self, row_index: usize) -> usize {
+        let block_count = self.row_count();
+        let value = self.outputs[row_index];
+        let is_sensitive = |block: usize| self.outputs[row_index ^ block] != value;
+
+        // `contains_sensitive[block]` is true if some subset of `block` is sensitive.
+        let mut contains_sensitive = vec![false; block_count];
+        let mut minimal_blocks = Vec::new();
+        for block in 1..block_count {
+            let subset_sensitive = (0..self.variable_count())
+                .filter(|bit_index| block & (1 << bit_index) != 0)
+                .any(|bit_index| contains_sensitive[block ^ (1 << bit_index)]);
+
+            if is_sensitive(block) && !subset_sensitive {
+                minimal_blocks.push(block);
+            }
+            contains_sensitive[block] = subset_sensitive || is_sensitive(block);
+        }
+
+        max_disjoint_blocks(&minimal_blocks, 0)
+    }
+
+    /// Computes the sums of squared Walsh coefficients, grouped by the size of the
+    /// coefficient's variable set.
+    ///
+    /// The Walsh coefficients are computed by the fast Walsh-Hadamard transform of the output
+    /// vector, with outputs interpreted as `1` (false) and `-1` (true).
+    fn fourier_level_weights(&self) -> Vec<BigUint> {
+        let mut coefficients = self
+            .outputs
+            .iter()
+            .map(|output| if *output { -1i64 } else { 1i64 })
+            .collect::<Vec<_>>();
+
+        let mut half = 1;
+        while half < coefficients.len() {
+            for start in (0..coefficients.len()).step_by(2 * half) {
+                for index in start..start + half {
+                    let (low, high) = (coefficients[index], coefficients[index + half]);
+                    coefficients[index] = low + high;
+                    coefficients[index + half] = low - high;
+                }
+            }
+            half *= 2;
+        }
+
+        let mut level_weights = vec![BigUint::zero(); self.variable_count() + 1];
+        for (mask, coefficient) in coefficients.into_iter().enumerate() {
+            let squared = BigUint::from(coefficient.unsigned_abs()).pow(2);
+            level_weights[mask.count_ones() as usize] += squared;
+        }
+
+        level_weights
+    }
+}
+
+/// The maximal number of pairwise disjoint bit masks in `blocks` that are also disjoint
+/// with `used`.
+fn max_disjoint_blocks(blocks: &[usize], used: usize) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .filter(|(_index, block)| *block & used == 0)
+        .map(|(index, block)| 1 + max_disjoint_blocks(&blocks[index + 1..], used | block))
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{bool, var, Expression};
+    use crate::table::TruthTable;
+    use crate::traits::{FourierMeasures, FourierMeasuresError};
+    use num_rational::BigRational;
+    use num_traits::{One, Zero};
+
+    fn ratio(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(numerator.into(), denominator.into())
+    }
+
+    #[test]
+    fn test_influence_and_ok() {
+        let input = TruthTable::from(var("a") & var("b"));
+
+        assert_eq!(input.influence(&"a".to_string()), ratio(1, 2));
+        assert_eq!(input.influence(&"b".to_string()), ratio(1, 2));
+        assert_eq!(input.influence(&"c".to_string()), BigRational::zero());
+        assert_eq!(input.total_influence(), BigRational::one());
+    }
+
+    #[test]
+    fn test_influence_majority_ok() {
+        let input =
+            TruthTable::from((var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c")));
+
+        assert_eq!(input.influence(&"a".to_string()), ratio(1, 2));
+        assert_eq!(input.total_influence(), ratio(3, 2));
+        assert_eq!(input.average_sensitivity(), ratio(3, 2));
+        assert_eq!(input.max_sensitivity(), 2);
+    }
+
+    #[test]
+    fn test_sensitivity_parity_ok() {
+        let input = TruthTable::from(var("a") ^ var("b") ^ var("c"));
+
+        assert_eq!(input.total_influence(), ratio(3, 1));
+        assert_eq!(input.max_sensitivity(), 3);
+        assert_eq!(input.max_block_sensitivity(), 3);
+        assert_eq!(input.average_block_sensitivity(), ratio(3, 1));
+    }
+
+    #[test]
+    fn test_block_sensitivity_exceeds_sensitivity_ok() {
+        // In `0000`, no single flip changes the output, but both `{a, b}` and `{c, d}` do.
+        let input = TruthTable::from(
+            (var("a") & var("b") & !var("c") & !var("d"))
+                | (!var("a") & !var("b") & var("c") & var("d")),
+        );
+
+        assert_eq!(input.row_sensitivity(0b0000), 0);
+        assert_eq!(input.row_block_sensitivity(0b0000), 2);
+        // In `1100`, every single flip changes the output.
+        assert_eq!(input.max_sensitivity(), 4);
+        assert_eq!(input.max_block_sensitivity(), 4);
+    }
+
+    #[test]
+    fn test_constant_ok() {
+        let input = TruthTable::from(var("a") | !var("a"));
+
+        assert_eq!(input.total_influence(), BigRational::zero());
+        assert_eq!(input.max_sensitivity(), 0);
+        assert_eq!(input.max_block_sensitivity(), 0);
+        assert_eq!(
+            input.noise_stability(&ratio(1, 3)).unwrap(),
+            BigRational::one()
+        );
+
+        let input = TruthTable::from(bool(false));
+        assert_eq!(input.max_sensitivity(), 0);
+        assert_eq!(
+            input.noise_stability(&ratio(1, 2)).unwrap(),
+            BigRational::one()
+        );
+    }
+
+    #[test]
+    fn test_noise_stability_ok() {
+        // Dictator: the output changes exactly when the variable flips.
+        let input = TruthTable::from(var("a") | (var("b") & !var("b")));
+        assert_eq!(input.noise_stability(&ratio(1, 4)).unwrap(), ratio(1, 2));
+
+        // Parity of `k` variables has stability `(1 - 2 * p)^k`.
+        let input = TruthTable::from(var("a") ^ var("b") ^ var("c"));
+        assert_eq!(input.noise_stability(&ratio(1, 4)).unwrap(), ratio(1, 8));
+        assert_eq!(
+            input.noise_stability(&ratio(1, 2)).unwrap(),
+            BigRational::zero()
+        );
+        assert_eq!(input.noise_stability(&ratio(1, 1)).unwrap(), ratio(-1, 1));
+
+        // AND of two variables: F' = {1/2, 1/2, 1/2, -1/2}.
+        let input = TruthTable::from(var("a") & var("b"));
+        assert_eq!(
+            input.noise_stability(&BigRational::zero()).unwrap(),
+            BigRational::one()
+        );
+        assert_eq!(input.noise_stability(&ratio(1, 4)).unwrap(), ratio(9, 16));
+    }
+
+    #[test]
+    fn test_noise_stability_matches_definition_ok() {
+        let expression = Expression::n_ary_or(&[var("a") & var("b"), var("c") & !var("d")]);
+        let input = TruthTable::from(expression);
+        let flip = ratio(1, 5);
+
+        // Directly compute `1 - 2 * P[F(x) != F(y)]`.
+        let n = input.variable_count();
+        let mut disagreement = BigRational::zero();
+        for x in 0..input.row_count() {
+            for y in 0..input.row_count() {
+                if input.outputs[x] != input.outputs[y] {
+                    let flipped = (x ^ y).count_ones() as i32;
+                    let probability = num_traits::pow(flip.clone(), flipped as usize)
+                        * num_traits::pow(BigRational::one() - &flip, n - flipped as usize);
+                    disagreement += probability;
+                }
+            }
+        }
+        disagreement /= BigRational::from_integer(input.row_count().into());
+
+        let expected = BigRational::one() - BigRational::from_integer(2.into()) * disagreement;
+        assert_eq!(input.noise_stability(&flip).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_noise_stability_invalid_probability_nok() {
+        let input = TruthTable::from(var("a"));
+
+        for flip in [ratio(3, 2), ratio(-1, 4)] {
+            assert_eq!(
+                input.noise_stability(&flip),
+                Err(FourierMeasuresError::InvalidFlipProbability {
+                    flip_probability: flip.to_string()
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::table::utils::boolean_point_to_row_index;
+use crate::table::TruthTable;
+use crate::traits::BooleanFunction;
+use crate::zdd::Zdd;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> From<Zdd<T>> for TruthTable<T> {
+    fn from(value: Zdd<T>) -> Self {
+        let inputs = value.inputs();
+        let mut outputs = vec![false; 2usize.pow(inputs.len() as u32)];
+
+        value
+            .support()
+            .map(|point| boolean_point_to_row_index(&point))
+            .for_each(|index| outputs[index] = true);
+
+        TruthTable::new(inputs.into_iter().collect(), outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+
+    #[test]
+    fn test_table_from_zdd_ok() {
+        let expression = (var("b") | var("a") & var("c")) & !var("a");
+        let zdd = Zdd::try_from(expression.clone()).unwrap();
+
+        let expected = TruthTable::from(expression);
+        let actual = TruthTable::from(zdd);
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,9 +1,16 @@
 pub mod bit;
 mod boolean_function;
+pub mod canalization;
 pub mod display;
 pub mod evaluate;
+pub mod fourier_measures;
 pub mod from_bdd;
 pub mod from_expression;
+pub mod from_zdd;
 pub mod gather_literals;
+pub mod monotonicity;
 pub mod power_set;
+pub mod sample_support;
 pub mod semantic_eq;
+pub mod symmetry;
+pub mod weighted_count;
@@ -0,0 +1,140 @@
+use crate::expressions::Expression;
+use crate::table::TruthTable;
+use crate::traits::monotonicity::positive_terms_to_expression;
+use crate::traits::{Monotonicity, RegulationSign};
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> Monotonicity<T> for TruthTable<T> {
+    fn regulation_sign(&self, variable: &T) -> RegulationSign {
+        let Some(bit) = self.variable_bit(variable) else {
+            return RegulationSign::NonObservable;
+        };
+
+        let mut can_increase = false;
+        let mut can_decrease = false;
+        for row_index in (0..self.row_count()).filter(|row_index| row_index & bit == 0) {
+            let low = self.outputs[row_index];
+            let high = self.outputs[row_index | bit];
+            can_increase |= !low && high;
+            can_decrease |= low && !high;
+        }
+        RegulationSign::from_effects(can_increase, can_decrease)
+    }
+
+    fn upper_monotone_approximation(&self) -> Self {
+        let mut outputs = self.outputs.clone();
+        for bit in self.variable_bits() {
+            for row_index in (0..self.row_count()).filter(|row_index| row_index & bit == 0) {
+                outputs[row_index | bit] |= outputs[row_index];
+            }
+        }
+        TruthTable::new(self.inputs.clone(), outputs)
+    }
+
+    fn lower_monotone_approximation(&self) -> Self {
+        let mut outputs = self.outputs.clone();
+        for bit in self.variable_bits() {
+            for row_index in (0..self.row_count()).filter(|row_index| row_index & bit == 0) {
+                outputs[row_index] &= outputs[row_index | bit];
+            }
+        }
+        TruthTable::new(self.inputs.clone(), outputs)
+    }
+
+    fn minimal_positive_dnf(&self) -> Option<Expression<T>> {
+        if !self.is_monotone() {
+            return None;
+        }
+
+        // A satisfying row is minimal if unsetting any of its bits makes it unsatisfying.
+        let terms = (0..self.row_count())
+            .filter(|row_index| self.outputs[*row_index])
+            .filter(|row_index| {
+                self.variable_bits()
+                    .filter(|bit| row_index & bit != 0)
+                    .all(|bit| !self.outputs[row_index ^ bit])
+            })
+            .map(|row_index| {
+                self.inputs
+                    .iter()
+                    .zip(self.variable_bits())
+                    .filter(|(_, bit)| row_index & bit != 0)
+                    .map(|(variable, _)| variable.clone())
+                    .collect::<BTreeSet<_>>()
+            })
+            .collect();
+        Some(positive_terms_to_expression(terms))
+    }
+}
+
+impl<T: Debug + Clone + Ord> TruthTable<T> {
+    /// The bit masks of all inputs in a row index, in the order of the inputs.
+    fn variable_bits(&self) -> impl Iterator<Item = usize> {
+        let variable_count = self.variable_count();
+        (0..variable_count).map(move |index| 1 << (variable_count - index - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{bool, var};
+    use crate::table::TruthTable;
+    use crate::traits::{Monotonicity, RegulationSign};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_regulation_signs_ok() {
+        let function = TruthTable::from((var("a") & !var("b")) | (var("c") ^ var("d")));
+
+        assert_eq!(
+            function.regulation_signs(),
+            BTreeMap::from([
+                ("a".to_string(), RegulationSign::Activating),
+                ("b".to_string(), RegulationSign::Inhibiting),
+                ("c".to_string(), RegulationSign::Dual),
+                ("d".to_string(), RegulationSign::Dual),
+            ])
+        );
+        assert_eq!(
+            function.regulation_sign(&"x".to_string()),
+            RegulationSign::NonObservable
+        );
+    }
+
+    #[test]
+    fn test_monotone_approximations_ok() {
+        let function = TruthTable::from(!var("a") | (var("b") & var("c")));
+
+        assert!(!function.is_monotone());
+        assert_eq!(
+            function.upper_monotone_approximation(),
+            TruthTable::from(!var("a") | var("a") | (var("b") & var("c")))
+        );
+        assert_eq!(
+            function.lower_monotone_approximation(),
+            TruthTable::from(var("b") & var("c") & (var("a") | !var("a")))
+        );
+        assert_eq!(
+            function.monotone_closure(),
+            function.upper_monotone_approximation()
+        );
+    }
+
+    #[test]
+    fn test_minimal_positive_dnf_ok() {
+        let function =
+            TruthTable::from(var("a") & (var("b") | var("c")) | (var("a") & var("b") & var("d")));
+
+        assert!(function.is_monotone());
+        assert_eq!(
+            function.minimal_positive_dnf(),
+            Some((var("a") & var("b")) | (var("a") & var("c")))
+        );
+        assert_eq!(TruthTable::from(!var("a")).minimal_positive_dnf(), None);
+        assert_eq!(
+            TruthTable::from(var("a") | !var("a")).minimal_positive_dnf(),
+            Some(bool(true))
+        );
+    }
+}
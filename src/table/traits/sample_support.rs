@@ -0,0 +1,84 @@
+use crate::table::TruthTable;
+use crate::traits::{BooleanPoint, BooleanValuation, SampleSupport};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> SampleSupport<T> for TruthTable<T> {
+    fn sample_support_restricted(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: &BooleanValuation<T>,
+    ) -> Vec<BooleanPoint> {
+        // The bit of the row index that holds the value of each restricted input.
+        let fixed_bits = self
+            .inputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| {
+                let bit = self.variable_count() - index - 1;
+                restriction.get(input).map(|value| (bit, *value))
+            })
+            .collect::<Vec<_>>();
+
+        let rows = (0..self.outputs.len())
+            .filter(|row| self.outputs[*row])
+            .filter(|row| {
+                fixed_bits
+                    .iter()
+                    .all(|(bit, value)| ((row >> bit) & 1 == 1) == *value)
+            })
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return Vec::new();
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| self.row(rows[rng.gen_range(0..rows.len())]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::{BooleanFunction, SampleSupport};
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_sample_support_uniform_ok() {
+        let table = TruthTable::from((var("a") & var("b")) | !var("c"));
+        let support = table.support().collect::<Vec<_>>();
+
+        let samples = table.sample_support(5000, 7);
+
+        assert_eq!(samples.len(), 5000);
+        let mut counts = HashMap::new();
+        for point in samples {
+            assert!(support.contains(&point));
+            *counts.entry(point).or_insert(0) += 1;
+        }
+        // Each of the 5 points is expected 1000 times.
+        assert_eq!(counts.len(), support.len());
+        assert!(counts.values().all(|count| (850..1150).contains(count)));
+    }
+
+    #[test]
+    fn test_sample_support_restricted_ok() {
+        let table = TruthTable::from((var("a") & var("b")) | !var("c"));
+        let restriction = BTreeMap::from([("c".to_string(), true), ("x".to_string(), false)]);
+
+        let samples = table.sample_support_restricted(10, 1, &restriction);
+
+        assert_eq!(samples, vec![vec![true, true, true]; 10]);
+        assert_eq!(table.sample_support(10, 3), table.sample_support(10, 3));
+
+        let restriction = BTreeMap::from([("a".to_string(), false), ("c".to_string(), true)]);
+        assert!(table
+            .sample_support_restricted(10, 1, &restriction)
+            .is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+use crate::table::TruthTable;
+use crate::traits::Symmetry;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> Symmetry<T> for TruthTable<T> {
+    fn is_symmetric_in(&self, first: &T, second: &T) -> bool {
+        self.is_swap_invariant(first, second, false)
+    }
+
+    fn is_negation_symmetric_in(&self, first: &T, second: &T) -> bool {
+        self.is_swap_invariant(first, second, true)
+    }
+}
+
+impl<T: Debug + Clone + Ord> TruthTable<T> {
+    /// Compares the rows where `first = 0` and `second = !negated` with the rows where both
+    /// values are flipped. Variables which are not inputs of this table have no bits,
+    /// hence the comparison only depends on the other variable.
+    fn is_swap_invariant(&self, first: &T, second: &T, negated: bool) -> bool {
+        let first = self.variable_bit(first).unwrap_or(0);
+        let second = self.variable_bit(second).unwrap_or(0);
+        let second_low = if negated { 0 } else { second };
+        let second_high = second ^ second_low;
+
+        (0..self.row_count())
+            .filter(|row_index| row_index & (first | second) == 0)
+            .all(|row_index| {
+                self.outputs[row_index | second_low]
+                    == self.outputs[row_index | first | second_high]
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::Symmetry;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    #[test]
+    fn test_symmetry_classes_ok() {
+        let function = TruthTable::from((var("a") & var("b")) | (var("c") ^ var("d")) | var("e"));
+
+        assert_eq!(
+            function.symmetry_classes(),
+            vec![
+                BTreeSet::from(["a".to_string(), "b".to_string()]),
+                BTreeSet::from(["c".to_string(), "d".to_string()]),
+                BTreeSet::from(["e".to_string()]),
+            ]
+        );
+        assert!(TruthTable::from(var("a") ^ var("b") ^ var("c")).is_totally_symmetric());
+    }
+
+    #[test]
+    fn test_negation_symmetry_classes_ok() {
+        let function = TruthTable::from((var("a") & !var("b") & var("c")) | var("d"));
+        let [a, b, c, d] = ["a", "b", "c", "d"].map(String::from);
+
+        assert!(function.is_negation_symmetric_in(&a, &b));
+        assert!(function.is_negation_symmetric_in(&b, &c));
+        assert!(!function.is_negation_symmetric_in(&a, &c));
+        assert!(!function.is_negation_symmetric_in(&b, &b));
+        assert!(!function.is_symmetric_in(&d, &"x".to_string()));
+        assert_eq!(
+            function.negation_symmetry_classes(),
+            vec![
+                BTreeMap::from([(a, false), (b, true), (c, false)]),
+                BTreeMap::from([(d, false)]),
+            ]
+        );
+    }
+}
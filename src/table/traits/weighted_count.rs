@@ -0,0 +1,55 @@
+use crate::table::TruthTable;
+use crate::traits::{Semiring, WeightedCount};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> WeightedCount<T> for TruthTable<T> {
+    fn weighted_count<S: Semiring, F: Fn(&T, bool) -> S>(&self, weight: F) -> S {
+        let weights = self
+            .inputs
+            .iter()
+            .map(|input| [weight(input, false), weight(input, true)])
+            .collect::<Vec<_>>();
+
+        (0..self.outputs.len())
+            .filter(|row| self.outputs[*row])
+            .map(|row| {
+                self.row(row)
+                    .into_iter()
+                    .zip(&weights)
+                    .fold(S::one(), |acc, (value, weight)| {
+                        acc.mul(&weight[usize::from(value)])
+                    })
+            })
+            .fold(S::zero(), |acc, it| acc.add(&it))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::WeightedCount;
+    use num_rational::BigRational;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_probability_matches_bdd_ok() {
+        let expression = (var("a") ^ var("b")) | (var("c") & !var("a"));
+        let table = TruthTable::from(expression.clone());
+        let bdd = Bdd::try_from(expression).unwrap();
+        let probabilities = BTreeMap::from([
+            ("a".to_string(), BigRational::new(1.into(), 3.into())),
+            ("c".to_string(), BigRational::new(4.into(), 5.into())),
+        ]);
+
+        assert_eq!(
+            table.probability(&probabilities),
+            bdd.probability(&probabilities)
+        );
+        assert_eq!(
+            table.weighted_count(|_, value| if value { 3.0 } else { 1.0 }),
+            bdd.weighted_count(|_, value| if value { 3.0 } else { 1.0 })
+        );
+    }
+}
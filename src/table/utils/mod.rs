@@ -1,7 +1,9 @@
 pub use bool_point_to_row_index::boolean_point_to_row_index;
+pub use string_to_bool::string_to_bool;
 pub use valuation_to_row_index::{
     values_to_row_index, values_to_row_index_checked, values_to_row_index_with_default,
 };
 
 mod bool_point_to_row_index;
+mod string_to_bool;
 mod valuation_to_row_index;
@@ -0,0 +1,224 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::num::TryFromIntError;
+
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddVariable, BddVariableSet};
+
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::traits::BooleanValuation;
+
+mod recognition;
+mod simplex;
+
+/// The largest number of inputs supported by [ThresholdFunction::recognize], which
+/// enumerates the whole domain of the recognized function.
+pub const THRESHOLD_RECOGNITION_MAX_INPUTS: usize = 20;
+
+/// A linear threshold function, i.e. a function which is satisfied for `x` if
+/// `sum(weights[v] * x[v]) >= threshold`, with the values of `x` interpreted as `0` and `1`.
+///
+/// The weights can be negative. Every threshold function is unate: it is positive unate in
+/// variables with positive weights and negative unate in variables with negative weights.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThresholdFunction<T: Debug + Clone + Ord> {
+    weights: BTreeMap<T, i64>,
+    threshold: i64,
+}
+
+/// A certificate that a function is not a threshold function: the `true_points` and
+/// `false_points` have the same size and their component-wise sums are equal.
+///
+/// For a threshold function, the weighted sum of every true point would be at least the
+/// threshold and the weighted sum of every false point would be below it, which is impossible
+/// when both sums are equal. A function is a threshold function if and only if no such
+/// certificate exists (i.e. if it is *asummable*).
+///
+/// The points are complete valuations of the inputs and can repeat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsummabilityWitness<T: Debug + Clone + Ord> {
+    true_points: Vec<BooleanValuation<T>>,
+    false_points: Vec<BooleanValuation<T>>,
+}
+
+impl<T: Debug + Clone + Ord> ThresholdFunction<T> {
+    pub fn new(weights: BTreeMap<T, i64>, threshold: i64) -> ThresholdFunction<T> {
+        ThresholdFunction { weights, threshold }
+    }
+
+    /// The weight of every input of this function.
+    pub fn weights(&self) -> &BTreeMap<T, i64> {
+        &self.weights
+    }
+
+    pub fn threshold(&self) -> i64 {
+        self.threshold
+    }
+
+    /// Evaluates this function in the given `valuation`. Inputs missing from the `valuation`
+    /// are treated as `false`.
+    pub fn evaluate(&self, valuation: &BooleanValuation<T>) -> bool {
+        let sum = self
+            .weights
+            .iter()
+            .filter(|(variable, _)| valuation.get(*variable).copied().unwrap_or_default())
+            .map(|(_, weight)| *weight)
+            .sum::<i64>();
+        sum >= self.threshold
+    }
+
+    /// Builds the [Bdd] of this function. The inputs of the [Bdd] are all variables which
+    /// have a weight (including zero weights).
+    ///
+    /// The [Bdd] is constructed directly, by merging the partial sums of the variables
+    /// that are already decided. Its size is thus at most `n * S`, where `S` is the number
+    /// of distinct partial sums.
+    ///
+    /// Returns an `Err` if there are more than 2<sup>16</sup> inputs.
+    pub fn to_bdd(&self) -> Result<Bdd<T>, TryFromIntError> {
+        let variables = BddVariableSet::new_anonymous(u16::try_from(self.weights.len())?);
+        let weights = self.weights.values().copied().collect::<Vec<_>>();
+
+        // The maximal and minimal sums that the variables from `index` onwards can add.
+        let mut max_remaining = vec![0; weights.len() + 1];
+        let mut min_remaining = vec![0; weights.len() + 1];
+        for index in (0..weights.len()).rev() {
+            max_remaining[index] = max_remaining[index + 1] + weights[index].max(0);
+            min_remaining[index] = min_remaining[index + 1] + weights[index].min(0);
+        }
+
+        let mut cache = HashMap::new();
+        let bdd = threshold_bdd(
+            &variables,
+            &weights,
+            self.threshold,
+            (&max_remaining, &min_remaining),
+            (0, 0),
+            &mut cache,
+        );
+        Ok(Bdd::new(bdd, self.weights.keys().cloned().collect()))
+    }
+
+    /// Builds an `Expression` of this function by converting its [ThresholdFunction::to_bdd].
+    ///
+    /// Returns an `Err` if there are more than 2<sup>16</sup> inputs.
+    pub fn to_expression(&self) -> Result<Expression<T>, TryFromIntError> {
+        Ok(Expression::from(self.to_bdd()?))
+    }
+}
+
+/// The BDD of the variables from `index` onwards, given that the already decided
+/// variables add up to `sum`.
+fn threshold_bdd(
+    variables: &BddVariableSet,
+    weights: &[i64],
+    threshold: i64,
+    (max_remaining, min_remaining): (&[i64], &[i64]),
+    (index, sum): (usize, i64),
+    cache: &mut HashMap<(usize, i64), InnerBdd>,
+) -> InnerBdd {
+    if sum + min_remaining[index] >= threshold {
+        return variables.mk_true();
+    } else if sum + max_remaining[index] < threshold {
+        return variables.mk_false();
+    } else if let Some(result) = cache.get(&(index, sum)) {
+        return result.clone();
+    }
+
+    let remaining = (max_remaining, min_remaining);
+    let high = threshold_bdd(
+        variables,
+        weights,
+        threshold,
+        remaining,
+        (index + 1, sum + weights[index]),
+        cache,
+    );
+    let low = threshold_bdd(
+        variables,
+        weights,
+        threshold,
+        remaining,
+        (index + 1, sum),
+        cache,
+    );
+    let variable = variables.mk_var(BddVariable::from_index(index));
+    let result = InnerBdd::if_then_else(&variable, &high, &low);
+    cache.insert((index, sum), result.clone());
+    result
+}
+
+impl<T: Debug + Clone + Ord> AsummabilityWitness<T> {
+    /// The points in which the function is satisfied.
+    pub fn true_points(&self) -> &[BooleanValuation<T>] {
+        &self.true_points
+    }
+
+    /// The points in which the function is not satisfied.
+    pub fn false_points(&self) -> &[BooleanValuation<T>] {
+        &self.false_points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+    use crate::traits::BooleanFunction;
+
+    fn weights(pairs: &[(&str, i64)]) -> BTreeMap<String, i64> {
+        pairs
+            .iter()
+            .map(|(variable, weight)| (variable.to_string(), *weight))
+            .collect()
+    }
+
+    #[test]
+    fn test_to_bdd_ok() {
+        // The majority of three variables.
+        let majority = ThresholdFunction::new(weights(&[("a", 1), ("b", 1), ("c", 1)]), 2);
+        let expected = (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c"));
+        assert!(majority
+            .to_bdd()
+            .unwrap()
+            .is_equivalent(&Bdd::try_from(expected).unwrap()));
+
+        // Negative weights and a zero weight.
+        let function =
+            ThresholdFunction::new(weights(&[("a", 2), ("b", -1), ("c", 1), ("d", 0)]), 2);
+        let bdd = function.to_bdd().unwrap();
+        let expected = var("a") & (!var("b") | var("c"));
+        assert!(bdd.is_equivalent(&Bdd::try_from(expected).unwrap()));
+        assert_eq!(bdd.inputs().len(), 4);
+    }
+
+    #[test]
+    fn test_to_expression_ok() {
+        let function = ThresholdFunction::new(weights(&[("a", 3), ("b", 2), ("c", 2)]), 4);
+        let expression = function.to_expression().unwrap();
+
+        for (point, output) in Bdd::try_from(expression).unwrap().relation() {
+            let valuation = ["a", "b", "c"]
+                .into_iter()
+                .map(String::from)
+                .zip(point)
+                .collect();
+            assert_eq!(function.evaluate(&valuation), output);
+        }
+    }
+
+    #[test]
+    fn test_constant_threshold_functions_ok() {
+        let tautology = ThresholdFunction::new(weights(&[("a", 1)]), 0);
+        assert!(tautology
+            .to_bdd()
+            .unwrap()
+            .is_equivalent(&Bdd::mk_const(true)));
+
+        let contradiction = ThresholdFunction::new(weights(&[("a", 1)]), 2);
+        assert!(contradiction
+            .to_bdd()
+            .unwrap()
+            .is_equivalent(&Bdd::mk_const(false)));
+    }
+}
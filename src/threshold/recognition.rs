@@ -0,0 +1,529 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+use crate::threshold::simplex::{minimize, Constraint, Relation};
+use crate::threshold::{AsummabilityWitness, ThresholdFunction, THRESHOLD_RECOGNITION_MAX_INPUTS};
+use crate::traits::{BooleanFunction, BooleanValuation};
+
+/// A point of the function domain, encoded as a bit mask (bit `i` is the value of input `i`).
+type Mask = usize;
+
+impl<T: Debug + Clone + Ord> ThresholdFunction<T> {
+    /// Decides whether the given `function` is a linear threshold function.
+    ///
+    /// If it is, returns its [ThresholdFunction] with integer weights over all inputs
+    /// of the `function`. Otherwise, returns an [AsummabilityWitness] which certifies that
+    /// no such weights exist.
+    ///
+    /// The weights are found by a linear program which minimizes their sum. The rational
+    /// solution is scaled to integers and then greedily decreased, so the weights are small,
+    /// but not necessarily the smallest possible ones. Non-essential inputs have weight `0`.
+    ///
+    /// ### Implementation notes
+    ///
+    /// The operation enumerates the whole [BooleanFunction::domain] of the `function`,
+    /// so it is only usable for functions with a small number of inputs. A non-unate
+    /// function is rejected by a witness of size two. For unate functions, the linear program
+    /// is solved exactly (in rational arithmetic) over the minimal true and maximal false
+    /// points, adding the violated points as constraints lazily.
+    ///
+    /// Panics if the `function` has more than [THRESHOLD_RECOGNITION_MAX_INPUTS] inputs.
+    pub fn recognize<F: BooleanFunction<T>>(
+        function: &F,
+    ) -> Result<ThresholdFunction<T>, AsummabilityWitness<T>> {
+        let inputs = function.inputs().into_iter().collect::<Vec<_>>();
+        assert!(
+            inputs.len() <= THRESHOLD_RECOGNITION_MAX_INPUTS,
+            "Threshold recognition supports at most {THRESHOLD_RECOGNITION_MAX_INPUTS} inputs, found {}",
+            inputs.len()
+        );
+        let mut outputs = vec![false; 1 << inputs.len()];
+        for (point, output) in function.relation() {
+            let mask = point
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| **value)
+                .fold(0, |mask, (index, _)| mask | (1 << index));
+            outputs[mask] = output;
+        }
+
+        let to_valuations = |masks: Vec<Mask>| {
+            masks
+                .into_iter()
+                .map(|mask| mask_to_valuation(&inputs, mask))
+                .collect::<Vec<_>>()
+        };
+
+        // Variables in which the function is negative unate are flipped, such that
+        // the resulting function is monotone.
+        let mut flipped: Mask = 0;
+        for index in 0..inputs.len() {
+            let bit = 1 << index;
+            let lower_points = (0..outputs.len()).filter(|mask| mask & bit == 0);
+            let increasing = lower_points
+                .clone()
+                .find(|mask| !outputs[*mask] && outputs[mask | bit]);
+            let decreasing = lower_points
+                .clone()
+                .find(|mask| outputs[*mask] && !outputs[mask | bit]);
+            match (increasing, decreasing) {
+                (Some(x), Some(y)) => {
+                    // `x + bit` and `y` are true, `x` and `y + bit` are false.
+                    return Err(AsummabilityWitness {
+                        true_points: to_valuations(vec![x | bit, y]),
+                        false_points: to_valuations(vec![x, y | bit]),
+                    });
+                }
+                (None, Some(_)) => flipped |= bit,
+                _ => {}
+            }
+        }
+
+        let monotone = |mask: Mask| outputs[mask ^ flipped];
+        let bits = (0..inputs.len())
+            .map(|index| 1 << index)
+            .collect::<Vec<Mask>>();
+        let minimal_true = (0..outputs.len())
+            .filter(|mask| monotone(*mask))
+            .filter(|mask| {
+                bits.iter()
+                    .all(|bit| mask & bit == 0 || !monotone(mask ^ bit))
+            })
+            .collect::<Vec<_>>();
+        let maximal_false = (0..outputs.len())
+            .filter(|mask| !monotone(*mask))
+            .filter(|mask| {
+                bits.iter()
+                    .all(|bit| mask & bit != 0 || monotone(mask | bit))
+            })
+            .collect::<Vec<_>>();
+
+        let weights = if minimal_true.is_empty() || maximal_false.is_empty() {
+            // Constant functions only need the threshold.
+            vec![0; inputs.len()]
+        } else {
+            match monotone_weights(inputs.len(), &minimal_true, &maximal_false) {
+                Ok(weights) => weights,
+                Err((true_points, false_points)) => {
+                    let flip =
+                        |masks: Vec<Mask>| masks.into_iter().map(|mask| mask ^ flipped).collect();
+                    return Err(AsummabilityWitness {
+                        true_points: to_valuations(flip(true_points)),
+                        false_points: to_valuations(flip(false_points)),
+                    });
+                }
+            }
+        };
+
+        // The smallest sum of a true point, or `1` for a constant false function.
+        let threshold = if maximal_false.is_empty() {
+            0
+        } else {
+            minimal_true
+                .iter()
+                .map(|mask| weighted_sum(&weights, *mask))
+                .min()
+                .unwrap_or(1)
+        };
+
+        // A flipped variable contributes `w * (1 - x)`, i.e. `-w * x` plus a constant `w`.
+        let mut result = BTreeMap::new();
+        let mut threshold = threshold;
+        for (index, input) in inputs.into_iter().enumerate() {
+            if flipped & (1 << index) != 0 {
+                result.insert(input, -weights[index]);
+                threshold -= weights[index];
+            } else {
+                result.insert(input, weights[index]);
+            }
+        }
+        Ok(ThresholdFunction::new(result, threshold))
+    }
+}
+
+/// Finds non-negative integer weights of a non-constant monotone function given by its
+/// `minimal_true` and `maximal_false` points, or the true and false points of a witness
+/// that no such weights exist.
+#[allow(clippy::type_complexity)]
+fn monotone_weights(
+    variable_count: usize,
+    minimal_true: &[Mask],
+    maximal_false: &[Mask],
+) -> Result<Vec<i64>, (Vec<Mask>, Vec<Mask>)> {
+    // The LP variables are the weights followed by the threshold, all of them non-negative.
+    // True points must reach the threshold, false points must stay at least `1` below.
+    let point_constraint = |mask: Mask, is_true: bool| {
+        let sign = if is_true { 1 } else { -1 };
+        let mut coefficients = (0..variable_count)
+            .map(|index| {
+                let value = if mask & (1 << index) != 0 { sign } else { 0 };
+                BigRational::from_integer(value.into())
+            })
+            .collect::<Vec<_>>();
+        coefficients.push(BigRational::from_integer((-sign).into()));
+        Constraint {
+            coefficients,
+            relation: Relation::AtLeast,
+            bound: BigRational::from_integer(if is_true { 0 } else { 1 }.into()),
+        }
+    };
+    let objective = vec![BigRational::one(); variable_count + 1];
+
+    let mut true_points = Vec::new();
+    let mut false_points = Vec::new();
+    loop {
+        let constraints = true_points
+            .iter()
+            .map(|mask| point_constraint(*mask, true))
+            .chain(
+                false_points
+                    .iter()
+                    .map(|mask| point_constraint(*mask, false)),
+            )
+            .collect::<Vec<_>>();
+        let Some(solution) = minimize(&objective, &constraints) else {
+            return Err(witness(variable_count, &true_points, &false_points));
+        };
+
+        // Add the most violated true and false point (if any).
+        let threshold = &solution[variable_count];
+        let sum = |mask: Mask| {
+            (0..variable_count)
+                .filter(|index| mask & (1 << index) != 0)
+                .fold(BigRational::zero(), |sum, index| sum + &solution[index])
+        };
+        let violated_true = minimal_true
+            .iter()
+            .map(|mask| (threshold - sum(*mask), *mask))
+            .filter(|(violation, _)| violation.is_positive())
+            .max();
+        let violated_false = maximal_false
+            .iter()
+            .map(|mask| (sum(*mask) - threshold + BigRational::one(), *mask))
+            .filter(|(violation, _)| violation.is_positive())
+            .max();
+        if violated_true.is_none() && violated_false.is_none() {
+            return Ok(minimize_integer_weights(
+                to_integers(&solution[..variable_count]),
+                minimal_true,
+                maximal_false,
+            ));
+        }
+        true_points.extend(violated_true.map(|(_, mask)| mask));
+        false_points.extend(violated_false.map(|(_, mask)| mask));
+    }
+}
+
+/// Builds the witness of infeasibility of the weights for the given `true_points` and
+/// `false_points` of a monotone function.
+///
+/// By Farkas' lemma, there are non-negative multipliers of the points such that the
+/// multiplied false points have a larger total than the true points, both in every
+/// component and in the number of points. These are turned into equal sums by adding
+/// the zero point and removing ones from false points, which keeps them false.
+fn witness(
+    variable_count: usize,
+    true_points: &[Mask],
+    false_points: &[Mask],
+) -> (Vec<Mask>, Vec<Mask>) {
+    let point_count = true_points.len() + false_points.len();
+    let mut constraints = (0..variable_count)
+        .map(|index| {
+            let coefficients = true_points
+                .iter()
+                .map(|mask| if mask & (1 << index) != 0 { -1 } else { 0 })
+                .chain(
+                    false_points
+                        .iter()
+                        .map(|mask| if mask & (1 << index) != 0 { 1 } else { 0 }),
+                )
+                .map(|it| BigRational::from_integer(it.into()))
+                .collect();
+            Constraint {
+                coefficients,
+                relation: Relation::AtLeast,
+                bound: BigRational::zero(),
+            }
+        })
+        .collect::<Vec<_>>();
+    let point_signs = |true_sign: i64, false_sign: i64| {
+        let true_signs = true_points.iter().map(move |_| true_sign);
+        let false_signs = false_points.iter().map(move |_| false_sign);
+        true_signs
+            .chain(false_signs)
+            .map(|it| BigRational::from_integer(it.into()))
+            .collect::<Vec<_>>()
+    };
+    constraints.push(Constraint {
+        coefficients: point_signs(1, -1),
+        relation: Relation::AtLeast,
+        bound: BigRational::zero(),
+    });
+    constraints.push(Constraint {
+        coefficients: point_signs(0, 1),
+        relation: Relation::Equal,
+        bound: BigRational::one(),
+    });
+    let objective = point_signs(1, 0);
+
+    let multipliers = minimize(&objective, &constraints)
+        .expect("Infeasible weights should have a Farkas certificate");
+    debug_assert_eq!(multipliers.len(), point_count);
+    let multipliers = to_integers(&multipliers);
+
+    let repeat = |masks: &[Mask], multipliers: &[i64]| {
+        masks
+            .iter()
+            .zip(multipliers)
+            .flat_map(|(mask, count)| (0..*count).map(move |_| *mask))
+            .collect::<Vec<_>>()
+    };
+    let witness_true = repeat(true_points, &multipliers[..true_points.len()]);
+    let mut witness_false = repeat(false_points, &multipliers[true_points.len()..]);
+
+    // The zero point is false in a non-constant monotone function.
+    witness_false.resize(witness_true.len(), 0);
+    for index in 0..variable_count {
+        let bit = 1 << index;
+        let count = |masks: &[Mask]| masks.iter().filter(|mask| *mask & bit != 0).count();
+        let mut excess = count(&witness_false) - count(&witness_true);
+        for mask in witness_false.iter_mut() {
+            if excess == 0 {
+                break;
+            }
+            if *mask & bit != 0 {
+                *mask ^= bit;
+                excess -= 1;
+            }
+        }
+    }
+    (witness_true, witness_false)
+}
+
+/// Scales non-negative rational `values` to the smallest integers with the same ratios.
+fn to_integers(values: &[BigRational]) -> Vec<i64> {
+    let denominator = values
+        .iter()
+        .fold(BigInt::one(), |result, it| lcm(&result, it.denom()));
+    let numerators = values
+        .iter()
+        .map(|it| (it * &denominator).to_integer())
+        .collect::<Vec<_>>();
+    let divisor = numerators
+        .iter()
+        .fold(BigInt::zero(), |result, it| gcd(&result, it));
+    numerators
+        .into_iter()
+        .map(|it| {
+            let it = if divisor.is_zero() { it } else { it / &divisor };
+            it.to_i64().expect("Threshold weights should fit into i64")
+        })
+        .collect()
+}
+
+/// Greedily decreases the `weights` of a monotone function while they still separate
+/// the `minimal_true` points from the `maximal_false` points.
+fn minimize_integer_weights(
+    mut weights: Vec<i64>,
+    minimal_true: &[Mask],
+    maximal_false: &[Mask],
+) -> Vec<i64> {
+    let is_valid = |weights: &[i64]| {
+        let lowest_true = minimal_true
+            .iter()
+            .map(|it| weighted_sum(weights, *it))
+            .min();
+        let highest_false = maximal_false
+            .iter()
+            .map(|it| weighted_sum(weights, *it))
+            .max();
+        lowest_true > highest_false
+    };
+
+    for index in 0..weights.len() {
+        while weights[index] > 0 {
+            weights[index] -= 1;
+            if !is_valid(&weights) {
+                weights[index] += 1;
+                break;
+            }
+        }
+    }
+
+    let divisor = weights.iter().fold(0, |result, it| gcd_i64(result, *it));
+    if divisor > 1 {
+        weights.iter_mut().for_each(|it| *it /= divisor);
+    }
+    weights
+}
+
+fn weighted_sum(weights: &[i64], mask: Mask) -> i64 {
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| mask & (1 << index) != 0)
+        .map(|(_, weight)| *weight)
+        .sum()
+}
+
+fn mask_to_valuation<T: Debug + Clone + Ord>(inputs: &[T], mask: Mask) -> BooleanValuation<T> {
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(index, input)| (input.clone(), mask & (1 << index) != 0))
+        .collect()
+}
+
+fn gcd(left: &BigInt, right: &BigInt) -> BigInt {
+    let (mut left, mut right) = (left.abs(), right.abs());
+    while !right.is_zero() {
+        let remainder = &left % &right;
+        left = right;
+        right = remainder;
+    }
+    left
+}
+
+fn lcm(left: &BigInt, right: &BigInt) -> BigInt {
+    left / gcd(left, right) * right
+}
+
+fn gcd_i64(left: i64, right: i64) -> i64 {
+    gcd(&left.into(), &right.into())
+        .to_i64()
+        .expect("The gcd of two i64 values should fit into i64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdd::Bdd;
+    use crate::expressions::{var, Expression};
+    use crate::table::TruthTable;
+
+    /// Checks that the `witness` is a valid certificate for the `function`.
+    fn assert_valid_witness(function: &Bdd<String>, witness: &AsummabilityWitness<String>) {
+        assert!(!witness.true_points().is_empty());
+        assert_eq!(witness.true_points().len(), witness.false_points().len());
+        let evaluate = |valuation: &BooleanValuation<String>| {
+            function
+                .restrict(valuation)
+                .is_equivalent(&Bdd::mk_const(true))
+        };
+        assert!(witness.true_points().iter().all(evaluate));
+        assert!(!witness.false_points().iter().any(evaluate));
+
+        for input in function.inputs() {
+            let count =
+                |points: &[BooleanValuation<String>]| points.iter().filter(|it| it[&input]).count();
+            assert_eq!(count(witness.true_points()), count(witness.false_points()));
+        }
+    }
+
+    fn assert_represents(function: &Bdd<String>, threshold: &ThresholdFunction<String>) {
+        assert!(threshold.to_bdd().unwrap().is_equivalent(function));
+        assert_eq!(
+            threshold.weights().keys().cloned().collect::<Vec<_>>(),
+            function.inputs().into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_recognize_threshold_ok() {
+        let majority = (var("a") & var("b")) | (var("a") & var("c")) | (var("b") & var("c"));
+        let majority = Bdd::try_from(majority).unwrap();
+        let result = ThresholdFunction::recognize(&majority).unwrap();
+        assert_represents(&majority, &result);
+        assert_eq!(
+            result.weights().values().copied().collect::<Vec<_>>(),
+            vec![1, 1, 1]
+        );
+        assert_eq!(result.threshold(), 2);
+
+        // `a | (b & !c)` needs weights `2, 1, -1` and threshold `1`.
+        let function = Bdd::try_from(var("a") | (var("b") & !var("c"))).unwrap();
+        let result = ThresholdFunction::recognize(&function).unwrap();
+        assert_represents(&function, &result);
+        assert_eq!(
+            result.weights().values().copied().collect::<Vec<_>>(),
+            vec![2, 1, -1]
+        );
+        assert_eq!(result.threshold(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Threshold recognition supports at most")]
+    fn test_recognize_too_many_inputs_nok() {
+        let inputs = (0..=THRESHOLD_RECOGNITION_MAX_INPUTS)
+            .map(|index| var(format!("x{index}")))
+            .collect::<Vec<_>>();
+        let function = Bdd::try_from(Expression::n_ary_or(&inputs)).unwrap();
+        let _ = ThresholdFunction::recognize(&function);
+    }
+
+    #[test]
+    fn test_recognize_other_representations_ok() {
+        let expression: Expression<String> =
+            var("a") & (var("b") | var("c") | (var("d") & var("e")));
+        let bdd = Bdd::try_from(expression.clone()).unwrap();
+
+        let result = ThresholdFunction::recognize(&expression).unwrap();
+        assert_represents(&bdd, &result);
+        let result = ThresholdFunction::recognize(&TruthTable::from(expression)).unwrap();
+        assert_represents(&bdd, &result);
+    }
+
+    #[test]
+    fn test_recognize_constants_ok() {
+        let function = Bdd::try_from(var("a") & !var("a")).unwrap();
+        let result = ThresholdFunction::recognize(&function).unwrap();
+        assert_represents(&function, &result);
+
+        let function = Bdd::try_from(var("a") | !var("a")).unwrap();
+        let result = ThresholdFunction::recognize(&function).unwrap();
+        assert_represents(&function, &result);
+    }
+
+    #[test]
+    fn test_recognize_non_unate_err() {
+        let function = Bdd::try_from(var("a") ^ var("b")).unwrap();
+        let witness = ThresholdFunction::recognize(&function).unwrap_err();
+
+        assert_eq!(witness.true_points().len(), 2);
+        assert_valid_witness(&function, &witness);
+    }
+
+    #[test]
+    fn test_recognize_monotone_non_threshold_err() {
+        // The classical example of a monotone function which is not a threshold function.
+        let function = (var("a") & var("b")) | (var("c") & var("d"));
+        let function = Bdd::try_from(function).unwrap();
+        let witness = ThresholdFunction::recognize(&function).unwrap_err();
+        assert_valid_witness(&function, &witness);
+
+        // The same function with negated variables, and a non-essential input.
+        let function = (!var("a") & var("b")) | (var("c") & !var("d")) | (var("e") & !var("e"));
+        let function = Bdd::try_from(function).unwrap();
+        let witness = ThresholdFunction::recognize(&function).unwrap_err();
+        assert_valid_witness(&function, &witness);
+    }
+
+    #[test]
+    fn test_recognize_roundtrip_ok() {
+        let weights = BTreeMap::from([
+            ("a".to_string(), 5),
+            ("b".to_string(), -3),
+            ("c".to_string(), 2),
+            ("d".to_string(), 2),
+            ("e".to_string(), 1),
+        ]);
+        let function = ThresholdFunction::new(weights, 3).to_bdd().unwrap();
+
+        let result = ThresholdFunction::recognize(&function).unwrap();
+        assert_represents(&function, &result);
+    }
+}
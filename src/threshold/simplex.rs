@@ -0,0 +1,291 @@
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+
+/// The relation of a linear [Constraint].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Relation {
+    AtLeast,
+    AtMost,
+    Equal,
+}
+
+/// A linear constraint `coefficients · z (relation) bound`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Constraint {
+    pub coefficients: Vec<BigRational>,
+    pub relation: Relation,
+    pub bound: BigRational,
+}
+
+/// Minimizes `objective · z` subject to the `constraints` and `z >= 0` using the two-phase
+/// simplex method with exact rational arithmetic. Returns `None` if the constraints
+/// are infeasible.
+///
+/// Entering and leaving variables are chosen using Bland's rule, which prevents cycling.
+/// The objective must be bounded from below on the feasible set, otherwise the method panics.
+pub(crate) fn minimize(
+    objective: &[BigRational],
+    constraints: &[Constraint],
+) -> Option<Vec<BigRational>> {
+    let variable_count = objective.len();
+    let mut tableau = Tableau::new(variable_count, constraints);
+
+    // Phase one: minimize the sum of artificial variables.
+    let phase_one = (0..tableau.column_count())
+        .map(|column| {
+            if tableau.is_artificial(column) {
+                BigRational::from_integer(1.into())
+            } else {
+                BigRational::zero()
+            }
+        })
+        .collect::<Vec<_>>();
+    tableau.optimize(&phase_one, true);
+    if tableau.objective_value(&phase_one).is_positive() {
+        return None;
+    }
+    tableau.remove_artificial_variables();
+
+    // Phase two: minimize the actual objective, without artificial variables.
+    let mut phase_two = objective.to_vec();
+    phase_two.resize(tableau.column_count(), BigRational::zero());
+    tableau.optimize(&phase_two, false);
+
+    Some(tableau.solution(variable_count))
+}
+
+struct Tableau {
+    /// The rows of the constraint matrix (in the current basis), with the right-hand side
+    /// stored as the last element.
+    rows: Vec<Vec<BigRational>>,
+    /// The basic variable of every row.
+    basis: Vec<usize>,
+    /// The columns from this index onwards belong to artificial variables.
+    first_artificial: usize,
+}
+
+impl Tableau {
+    fn new(variable_count: usize, constraints: &[Constraint]) -> Tableau {
+        // Every inequality gets a slack variable, rows without an obvious basic variable
+        // get an artificial variable.
+        let slack_count = constraints
+            .iter()
+            .filter(|it| it.relation != Relation::Equal)
+            .count();
+        let first_artificial = variable_count + slack_count;
+
+        let mut rows = Vec::new();
+        let mut basis = Vec::new();
+        let mut next_slack = variable_count;
+        let mut artificial = Vec::new();
+        for constraint in constraints {
+            let mut row = constraint.coefficients.clone();
+            row.resize(first_artificial, BigRational::zero());
+            let mut bound = constraint.bound.clone();
+            let mut relation = constraint.relation;
+            if bound.is_negative() {
+                row.iter_mut().for_each(|it| *it = -it.clone());
+                bound = -bound;
+                relation = match relation {
+                    Relation::AtLeast => Relation::AtMost,
+                    Relation::AtMost => Relation::AtLeast,
+                    Relation::Equal => Relation::Equal,
+                };
+            }
+
+            match relation {
+                Relation::AtMost => {
+                    row[next_slack] = BigRational::from_integer(1.into());
+                    basis.push(next_slack);
+                }
+                Relation::AtLeast => {
+                    row[next_slack] = BigRational::from_integer((-1).into());
+                    artificial.push(rows.len());
+                    basis.push(usize::MAX);
+                }
+                Relation::Equal => {
+                    artificial.push(rows.len());
+                    basis.push(usize::MAX);
+                }
+            }
+            if relation != Relation::Equal {
+                next_slack += 1;
+            }
+            row.push(bound);
+            rows.push(row);
+        }
+
+        // Artificial variables are inserted before the right-hand side.
+        let artificial_count = artificial.len();
+        for row in rows.iter_mut() {
+            let bound = row.pop().unwrap();
+            row.resize(first_artificial + artificial_count, BigRational::zero());
+            row.push(bound);
+        }
+        for (index, row_index) in artificial.into_iter().enumerate() {
+            rows[row_index][first_artificial + index] = BigRational::from_integer(1.into());
+            basis[row_index] = first_artificial + index;
+        }
+
+        Tableau {
+            rows,
+            basis,
+            first_artificial,
+        }
+    }
+
+    fn column_count(&self) -> usize {
+        self.rows
+            .first()
+            .map_or(self.first_artificial, |row| row.len() - 1)
+    }
+
+    fn is_artificial(&self, column: usize) -> bool {
+        column >= self.first_artificial
+    }
+
+    fn objective_value(&self, costs: &[BigRational]) -> BigRational {
+        self.rows
+            .iter()
+            .zip(&self.basis)
+            .map(|(row, basic)| &costs[*basic] * row.last().unwrap())
+            .fold(BigRational::zero(), |sum, it| sum + it)
+    }
+
+    /// Runs the simplex iterations for the given `costs` until the current basis is optimal.
+    fn optimize(&mut self, costs: &[BigRational], allow_artificial: bool) {
+        loop {
+            let entering = (0..self.column_count())
+                .filter(|column| allow_artificial || !self.is_artificial(*column))
+                .find(|column| self.reduced_cost(costs, *column).is_negative());
+            let Some(entering) = entering else {
+                return;
+            };
+
+            let leaving = (0..self.rows.len())
+                .filter(|row| self.rows[*row][entering].is_positive())
+                .map(|row| {
+                    let ratio = self.rows[row].last().unwrap() / &self.rows[row][entering];
+                    (ratio, self.basis[row], row)
+                })
+                .min()
+                .map(|(_, _, row)| row)
+                .expect("The linear program should be bounded");
+            self.pivot(leaving, entering);
+        }
+    }
+
+    fn reduced_cost(&self, costs: &[BigRational], column: usize) -> BigRational {
+        self.rows
+            .iter()
+            .zip(&self.basis)
+            .fold(costs[column].clone(), |cost, (row, basic)| {
+                cost - &costs[*basic] * &row[column]
+            })
+    }
+
+    fn pivot(&mut self, pivot_row: usize, pivot_column: usize) {
+        let pivot = self.rows[pivot_row][pivot_column].clone();
+        self.rows[pivot_row].iter_mut().for_each(|it| *it /= &pivot);
+        let pivot_values = self.rows[pivot_row].clone();
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            let factor = row[pivot_column].clone();
+            if index == pivot_row || factor.is_zero() {
+                continue;
+            }
+            for (value, pivot_value) in row.iter_mut().zip(&pivot_values) {
+                *value -= &factor * pivot_value;
+            }
+        }
+        self.basis[pivot_row] = pivot_column;
+    }
+
+    /// Moves the (zero-valued) artificial variables out of the basis. Rows in which this is
+    /// not possible are redundant and are removed.
+    fn remove_artificial_variables(&mut self) {
+        let mut row = 0;
+        while row < self.rows.len() {
+            if !self.is_artificial(self.basis[row]) {
+                row += 1;
+                continue;
+            }
+            let column = (0..self.first_artificial).find(|it| !self.rows[row][*it].is_zero());
+            match column {
+                Some(column) => {
+                    self.pivot(row, column);
+                    row += 1;
+                }
+                None => {
+                    self.rows.remove(row);
+                    self.basis.remove(row);
+                }
+            }
+        }
+    }
+
+    fn solution(&self, variable_count: usize) -> Vec<BigRational> {
+        let mut result = vec![BigRational::zero(); variable_count];
+        for (row, basic) in self.rows.iter().zip(&self.basis) {
+            if *basic < variable_count {
+                result[*basic] = row.last().unwrap().clone();
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(value: i64) -> BigRational {
+        BigRational::from_integer(value.into())
+    }
+
+    fn constraint(coefficients: &[i64], relation: Relation, bound: i64) -> Constraint {
+        Constraint {
+            coefficients: coefficients.iter().map(|it| rational(*it)).collect(),
+            relation,
+            bound: rational(bound),
+        }
+    }
+
+    #[test]
+    fn test_minimize_ok() {
+        // minimize x + 2y s.t. x + y >= 3, x <= 2, y - x = -1
+        let constraints = [
+            constraint(&[1, 1], Relation::AtLeast, 3),
+            constraint(&[1, 0], Relation::AtMost, 2),
+            constraint(&[-1, 1], Relation::Equal, -1),
+        ];
+        let objective = [rational(1), rational(2)];
+
+        let solution = minimize(&objective, &constraints).unwrap();
+        assert_eq!(solution, vec![rational(2), rational(1)]);
+    }
+
+    #[test]
+    fn test_minimize_fractional_ok() {
+        // minimize x + y s.t. 2x + y >= 1, x + 3y >= 1
+        let constraints = [
+            constraint(&[2, 1], Relation::AtLeast, 1),
+            constraint(&[1, 3], Relation::AtLeast, 1),
+        ];
+        let objective = [rational(1), rational(1)];
+
+        let solution = minimize(&objective, &constraints).unwrap();
+        let expected = [2, 1].map(|it| BigRational::new(it.into(), 5.into()));
+        assert_eq!(solution, expected.to_vec());
+    }
+
+    #[test]
+    fn test_minimize_infeasible() {
+        let constraints = [
+            constraint(&[1, 1], Relation::AtLeast, 3),
+            constraint(&[1, 1], Relation::AtMost, 2),
+        ];
+        let objective = [rational(1), rational(1)];
+
+        assert_eq!(minimize(&objective, &constraints), None);
+    }
+}
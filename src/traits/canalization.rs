@@ -0,0 +1,140 @@
+use crate::traits::{BooleanFunction, BooleanValuation};
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+/// A canalizing input of a Boolean function: setting `variable` to `value` fixes the output
+/// of the function to `output`, regardless of the other inputs.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CanalizingInput<T> {
+    pub variable: T,
+    pub value: bool,
+    pub output: bool,
+}
+
+/// A trait implemented by Boolean function representations that can detect canalizing
+/// and nested canalizing structure.
+///
+/// A variable `v` is *canalizing* in `F` if there is a value `a` such that `F[v = a]` is
+/// a constant `b`, but `F[v = !a]` is not the same constant (i.e. `v` is essential).
+/// A function is *nested canalizing* if the variables can be ordered so that each of them
+/// is canalizing once the previous ones are fixed to their non-canalizing values.
+pub trait Canalization<T>: BooleanFunction<T>
+where
+    T: Debug + Clone + Eq + Ord,
+{
+    /// The constant that this function is restricted to when `variable` is set to `value`,
+    /// or `None` if the restricted function is not constant.
+    ///
+    /// If `variable` is not an input of this function, the result is only `Some` when this
+    /// function is itself constant.
+    fn canalized_output(&self, variable: &T, value: bool) -> Option<bool>;
+
+    /// All canalizing inputs of this function, ordered by variable and value.
+    ///
+    /// A function that is a literal of `v` is canalized by both values of `v`, hence `v`
+    /// appears twice. Constant functions have no canalizing inputs.
+    ///
+    /// ### Examples
+    ///
+    /// In `a & (b | c)`, the only canalizing input is `a` with value `false` (and output
+    /// `false`).
+    fn canalizing_variables(&self) -> Vec<CanalizingInput<T>> {
+        self.inputs()
+            .into_iter()
+            .flat_map(|variable| {
+                let outputs = [
+                    self.canalized_output(&variable, false),
+                    self.canalized_output(&variable, true),
+                ];
+                [false, true]
+                    .into_iter()
+                    .filter_map(move |value| {
+                        let output = outputs[usize::from(value)]?;
+                        let other = outputs[usize::from(!value)];
+                        (other != Some(output)).then(|| CanalizingInput {
+                            variable: variable.clone(),
+                            value,
+                            output,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// The layers of a nested canalizing function, or `None` if this function is not
+    /// nested canalizing (this includes constant functions).
+    ///
+    /// The first layer contains all canalizing inputs of this function. Each subsequent layer
+    /// contains the canalizing inputs of the function restricted to the non-canalizing values
+    /// of all previous layers. All inputs of one layer share the same output, except for
+    /// a layer in which the restricted function is a literal. Such a layer is always the last
+    /// one, and contains both values of the literal's variable with opposite outputs.
+    ///
+    /// ### Examples
+    ///
+    /// The layers of `a | (b & !c)` are `[a = true -> true]` and
+    /// `[b = false -> false, c = true -> false]`. The only layer of `!a` is
+    /// `[a = false -> true, a = true -> false]`.
+    fn nested_canalizing_layers(&self) -> Option<Vec<Vec<CanalizingInput<T>>>> {
+        if self.essential_inputs().is_empty() {
+            return None;
+        }
+        let (layers, is_nested) = canalizing_layers(self);
+        is_nested.then_some(layers)
+    }
+
+    /// Returns `true` if every essential input of this function is canalizing in the nested
+    /// sense, see [Canalization::nested_canalizing_layers].
+    fn is_nested_canalizing(&self) -> bool {
+        self.nested_canalizing_layers().is_some()
+    }
+
+    /// The canalizing depth of this function, i.e. the number of variables which are
+    /// canalizing in the nested sense before the remaining core function has no canalizing
+    /// inputs (or becomes constant).
+    ///
+    /// The depth of a nested canalizing function is the number of its essential inputs, the
+    /// depth of a constant or a non-canalizing function is `0`.
+    fn canalizing_depth(&self) -> usize {
+        let (layers, _) = canalizing_layers(self);
+        layers
+            .iter()
+            .flatten()
+            .map(|input| &input.variable)
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+}
+
+/// Repeatedly removes all canalizing inputs of the `function` by fixing them to their
+/// non-canalizing values. Returns the removed layers, and whether the remaining core
+/// function is constant.
+fn canalizing_layers<T, F>(function: &F) -> (Vec<Vec<CanalizingInput<T>>>, bool)
+where
+    T: Debug + Clone + Eq + Ord,
+    F: Canalization<T>,
+{
+    let mut layers = Vec::new();
+    let mut layer = function.canalizing_variables();
+    if layer.is_empty() {
+        return (layers, function.essential_inputs().is_empty());
+    }
+
+    // The non-canalizing values of all variables in the removed layers.
+    let mut valuation = BooleanValuation::new();
+    loop {
+        valuation.extend(
+            layer
+                .iter()
+                .map(|input| (input.variable.clone(), !input.value)),
+        );
+        let core = function.restrict(&valuation);
+        layers.push(layer);
+
+        layer = core.canalizing_variables();
+        if layer.is_empty() {
+            return (layers, core.essential_inputs().is_empty());
+        }
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FourierMeasuresError {
+    #[error("Expected a flip probability between 0 and 1, got {flip_probability}")]
+    InvalidFlipProbability { flip_probability: String },
+    #[error("Noise stability supports at most {} variables", u16::MAX / 3)]
+    TooManyVariables,
+}
+
+#[cfg(feature = "python")]
+impl From<FourierMeasuresError> for PyErr {
+    fn from(err: FourierMeasuresError) -> PyErr {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::traits::BooleanFunction;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::fmt::Debug;
+
+pub use error::FourierMeasuresError;
+
+mod error;
+
+/// A trait implemented by Boolean function representations that can compute the standard
+/// measures of the analysis of Boolean functions (influence, sensitivity and noise stability).
+///
+/// All measures are computed with respect to the uniform distribution over the domain of the
+/// function, i.e. over all `2^n` input points of [BooleanFunction::inputs]. Non-essential inputs
+/// do not change any of the results. Probabilities and expected values are reported as exact
+/// rational numbers.
+///
+/// For the definitions, see for example the book
+/// [Analysis of Boolean Functions](https://arxiv.org/abs/2105.10386) by Ryan O'Donnell.
+pub trait FourierMeasures<T>: BooleanFunction<T>
+where
+    T: Debug + Clone + Eq + Ord,
+{
+    /// The influence of the given `variable`, i.e. the probability that flipping the value
+    /// of `variable` in a uniformly random input point changes the output of the function.
+    ///
+    /// The influence of a variable that is not an input of this function is zero.
+    ///
+    /// ### Examples
+    ///
+    /// Every variable of `a ^ b` has influence `1`, every variable of `a & b` has
+    /// influence `1/2`.
+    fn influence(&self, variable: &T) -> BigRational;
+
+    /// The sum of [FourierMeasures::influence] over all inputs of this function.
+    ///
+    /// This is the same value as [FourierMeasures::average_sensitivity].
+    fn total_influence(&self) -> BigRational {
+        self.inputs()
+            .iter()
+            .map(|variable| self.influence(variable))
+            .fold(BigRational::zero(), |acc, it| acc + it)
+    }
+
+    /// The maximal number of variables that change the output of the function when flipped
+    /// individually, taken over all input points.
+    fn max_sensitivity(&self) -> usize;
+
+    /// The expected number of variables that change the output of the function when flipped
+    /// individually, taken over a uniformly random input point.
+    fn average_sensitivity(&self) -> BigRational {
+        self.total_influence()
+    }
+
+    /// The maximal number of disjoint sets of variables (blocks) such that flipping all variables
+    /// in any one of the blocks changes the output of the function, taken over all input points.
+    ///
+    /// ### Implementation notes
+    ///
+    /// Computing block sensitivity is NP-hard in general. The operation enumerates all input
+    /// points and all blocks, making it only usable for functions with a small number of inputs.
+    fn max_block_sensitivity(&self) -> usize;
+
+    /// The expected block sensitivity (see [FourierMeasures::max_block_sensitivity]) of
+    /// a uniformly random input point.
+    ///
+    /// ### Implementation notes
+    ///
+    /// Same as [FourierMeasures::max_block_sensitivity], the operation is exponential in the
+    /// number of inputs.
+    fn average_block_sensitivity(&self) -> BigRational;
+
+    /// The noise stability of this function for the given `flip_probability`.
+    ///
+    /// Given a uniformly random input point `x`, we obtain `y` by flipping each variable of `x`
+    /// independently with probability `flip_probability`. The noise stability is then the
+    /// expected value of `F(x) * F(y)` with outputs interpreted as `1` (false) and `-1` (true).
+    /// Equivalently, it is `1 - 2 * P[F(x) != F(y)]`, or `sum_S (1 - 2 * flip_probability)^|S|
+    /// * F'(S)^2` in terms of the Fourier coefficients `F'(S)`.
+    ///
+    /// Returns an `Err` if the `flip_probability` is not between `0` and `1` (inclusive).
+    fn noise_stability(
+        &self,
+        flip_probability: &BigRational,
+    ) -> Result<BigRational, FourierMeasuresError>;
+}
+
+/// Checks that the `flip_probability` of [FourierMeasures::noise_stability] is between
+/// `0` and `1` (inclusive).
+pub(crate) fn check_flip_probability(
+    flip_probability: &BigRational,
+) -> Result<(), FourierMeasuresError> {
+    if flip_probability.is_negative() || flip_probability > &BigRational::one() {
+        return Err(FourierMeasuresError::InvalidFlipProbability {
+            flip_probability: flip_probability.to_string(),
+        });
+    }
+    Ok(())
+}
@@ -1,13 +1,25 @@
 pub use boolean_function::{BooleanFunction, BooleanPoint, BooleanValuation};
+pub use canalization::{Canalization, CanalizingInput};
 pub use evaluate::Evaluate;
+pub use fourier_measures::{FourierMeasures, FourierMeasuresError};
 pub use gather_literals::GatherLiterals;
+pub use monotonicity::{Monotonicity, RegulationSign};
 pub use operations::{equality::Equality, implication::Implication};
 pub use power_set::PowerSet;
+pub use sample_support::SampleSupport;
 pub use semantic_eq::SemanticEq;
+pub use symmetry::Symmetry;
+pub use weighted_count::{ProbabilityValue, Semiring, WeightedCount};
 
 mod boolean_function;
+mod canalization;
 mod evaluate;
+pub(crate) mod fourier_measures;
 mod gather_literals;
+pub(crate) mod monotonicity;
 mod operations;
 mod power_set;
+mod sample_support;
 mod semantic_eq;
+mod symmetry;
+mod weighted_count;
@@ -0,0 +1,163 @@
+use crate::expressions::{Expression, ExpressionNode};
+use crate::traits::BooleanFunction;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Debug, Display, Formatter};
+
+/// The effect of an input variable on the output of a Boolean function, as determined by
+/// comparing the two cofactors `F[v = 0]` and `F[v = 1]`.
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegulationSign {
+    /// `F[v = 0] => F[v = 1]`, i.e. increasing `v` never decreases the output
+    /// (the function is positive unate in `v`).
+    Activating,
+    /// `F[v = 1] => F[v = 0]`, i.e. increasing `v` never increases the output
+    /// (the function is negative unate in `v`).
+    Inhibiting,
+    /// Increasing `v` increases the output for some inputs and decreases it for others.
+    Dual,
+    /// `F[v = 0] = F[v = 1]`, i.e. `v` is not an essential input of the function.
+    NonObservable,
+}
+
+impl RegulationSign {
+    /// Determines the sign from the two one-directional effects of a variable, i.e. whether
+    /// increasing the variable can increase or decrease the output.
+    pub(crate) fn from_effects(can_increase: bool, can_decrease: bool) -> RegulationSign {
+        match (can_increase, can_decrease) {
+            (true, false) => RegulationSign::Activating,
+            (false, true) => RegulationSign::Inhibiting,
+            (true, true) => RegulationSign::Dual,
+            (false, false) => RegulationSign::NonObservable,
+        }
+    }
+}
+
+impl Display for RegulationSign {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            RegulationSign::Activating => "activating",
+            RegulationSign::Inhibiting => "inhibiting",
+            RegulationSign::Dual => "dual",
+            RegulationSign::NonObservable => "non-observable",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A trait implemented by Boolean function representations that can determine how each of
+/// their inputs influences the output, i.e. whether the function is monotone (unate)
+/// in the input.
+///
+/// Throughout, *monotone* means positive (non-decreasing): `x <= y` implies `F(x) <= F(y)`,
+/// where points are compared component-wise.
+pub trait Monotonicity<T>: BooleanFunction<T>
+where
+    T: Debug + Clone + Eq + Ord,
+{
+    /// The [RegulationSign] of the given `variable`.
+    ///
+    /// A variable that is not an input of this function is [RegulationSign::NonObservable].
+    ///
+    /// ### Examples
+    ///
+    /// In `a & !b`, `a` is activating and `b` is inhibiting. In `a ^ b`, both variables
+    /// are dual.
+    fn regulation_sign(&self, variable: &T) -> RegulationSign;
+
+    /// The [RegulationSign] of every input of this function.
+    ///
+    /// Unlike [BooleanFunction::essential_inputs], the result also contains the non-essential
+    /// inputs (as [RegulationSign::NonObservable]).
+    fn regulation_signs(&self) -> BTreeMap<T, RegulationSign> {
+        self.inputs()
+            .into_iter()
+            .map(|variable| {
+                let sign = self.regulation_sign(&variable);
+                (variable, sign)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if increasing `variable` never decreases the output, i.e. `variable`
+    /// is [RegulationSign::Activating] or [RegulationSign::NonObservable].
+    fn is_positive_unate_in(&self, variable: &T) -> bool {
+        matches!(
+            self.regulation_sign(variable),
+            RegulationSign::Activating | RegulationSign::NonObservable
+        )
+    }
+
+    /// Returns `true` if increasing `variable` never increases the output, i.e. `variable`
+    /// is [RegulationSign::Inhibiting] or [RegulationSign::NonObservable].
+    fn is_negative_unate_in(&self, variable: &T) -> bool {
+        matches!(
+            self.regulation_sign(variable),
+            RegulationSign::Inhibiting | RegulationSign::NonObservable
+        )
+    }
+
+    /// Returns `true` if this function is (positive) monotone, i.e. it is positive unate
+    /// in every input.
+    fn is_monotone(&self) -> bool {
+        self.inputs()
+            .iter()
+            .all(|variable| self.is_positive_unate_in(variable))
+    }
+
+    /// The least monotone function above this function, i.e. the function which is
+    /// satisfied in `x` if this function is satisfied in some `y <= x`.
+    ///
+    /// The result has the same inputs as this function.
+    fn upper_monotone_approximation(&self) -> Self;
+
+    /// The greatest monotone function below this function, i.e. the function which is
+    /// satisfied in `x` if this function is satisfied in every `y >= x`.
+    ///
+    /// The result has the same inputs as this function.
+    fn lower_monotone_approximation(&self) -> Self;
+
+    /// The monotone closure of this function, i.e. its
+    /// [Monotonicity::upper_monotone_approximation].
+    fn monotone_closure(&self) -> Self {
+        self.upper_monotone_approximation()
+    }
+
+    /// The unique minimal DNF of a monotone function, i.e. the disjunction of all its prime
+    /// implicants, which only contain positive literals. Returns `None` if this function is
+    /// not monotone.
+    ///
+    /// Each prime implicant corresponds to a minimal point in which the function is satisfied.
+    /// The terms are ordered lexicographically by their (sorted) variables.
+    ///
+    /// ### Examples
+    ///
+    /// The minimal positive DNF of `a & (b | c) | (a & b & d)` is `(a & b) | (a & c)`.
+    fn minimal_positive_dnf(&self) -> Option<Expression<T>>;
+}
+
+/// Converts the prime implicants of a monotone function (sets of positive literals)
+/// to a DNF expression. Single-literal terms and single-term disjunctions are not wrapped
+/// in an n-ary operator.
+pub(crate) fn positive_terms_to_expression<T: Debug + Clone + Ord>(
+    terms: BTreeSet<BTreeSet<T>>,
+) -> Expression<T> {
+    let conjunction = |term: BTreeSet<T>| {
+        let mut literals = term
+            .into_iter()
+            .map(|variable| ExpressionNode::Literal(variable).into())
+            .collect::<Vec<Expression<T>>>();
+        match literals.len() {
+            0 => ExpressionNode::Constant(true).into(),
+            1 => literals.remove(0),
+            _ => Expression::n_ary_and(&literals),
+        }
+    };
+
+    let mut terms = terms.into_iter().map(conjunction).collect::<Vec<_>>();
+    match terms.len() {
+        0 => ExpressionNode::Constant(false).into(),
+        1 => terms.remove(0),
+        _ => Expression::n_ary_or(&terms),
+    }
+}
@@ -0,0 +1,39 @@
+use crate::traits::{BooleanFunction, BooleanPoint, BooleanValuation};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+/// A trait implemented by Boolean function representations that can draw random points
+/// from their [BooleanFunction::support].
+///
+/// The points are drawn independently (i.e. with replacement) and uniformly. The sampling
+/// is reproducible: the same `seed` always produces the same points for the same function.
+pub trait SampleSupport<T>: BooleanFunction<T>
+where
+    T: Debug + Clone + Eq + Ord,
+{
+    /// Draws `count` uniformly random points for which this function evaluates to `1`.
+    ///
+    /// Returns an empty vector if the function is not satisfiable.
+    ///
+    /// ### Implementation notes
+    ///
+    /// Unlike [BooleanFunction::sat_point], this is not a constant-time operation: tables
+    /// first collect their support, BDDs and ZDDs compute the number of satisfying points
+    /// below every node. Expressions are converted to a BDD.
+    fn sample_support(&self, count: usize, seed: u64) -> Vec<BooleanPoint> {
+        self.sample_support_restricted(count, seed, &BTreeMap::new())
+    }
+
+    /// Draws `count` uniformly random points for which this function evaluates to `1`
+    /// and which agree with the given `restriction`.
+    ///
+    /// Unlike [BooleanFunction::restrict], the points still contain the values of all inputs
+    /// (including the restricted ones). Variables of the `restriction` that are not inputs
+    /// of this function are ignored. Returns an empty vector if no such point exists.
+    fn sample_support_restricted(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: &BooleanValuation<T>,
+    ) -> Vec<BooleanPoint>;
+}
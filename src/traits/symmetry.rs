@@ -0,0 +1,96 @@
+use crate::traits::BooleanFunction;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+/// A trait implemented by Boolean function representations that can detect symmetries
+/// between their inputs.
+///
+/// A function `F` is *symmetric* in `a` and `b` if swapping the values of `a` and `b` does
+/// not change the output, i.e. `F[a = 0, b = 1] = F[a = 1, b = 0]`. It is *negation symmetric*
+/// in `a` and `b` if swapping `a` with `!b` does not change the output, i.e.
+/// `F[a = 0, b = 0] = F[a = 1, b = 1]`.
+pub trait Symmetry<T>: BooleanFunction<T>
+where
+    T: Debug + Clone + Eq + Ord,
+{
+    /// Returns `true` if this function does not change when the values of `first` and
+    /// `second` are swapped.
+    ///
+    /// Every function is symmetric in a variable with itself. A variable which is not
+    /// an input of this function is symmetric with the variables that are not essential.
+    fn is_symmetric_in(&self, first: &T, second: &T) -> bool;
+
+    /// Returns `true` if this function does not change when the value of `first` is swapped
+    /// with the negated value of `second`.
+    ///
+    /// A function is negation symmetric in a variable with itself only if the variable
+    /// is not essential.
+    fn is_negation_symmetric_in(&self, first: &T, second: &T) -> bool;
+
+    /// The maximal classes of inputs in which this function is symmetric. The classes
+    /// partition the inputs and are ordered by their smallest variable.
+    ///
+    /// ### Examples
+    ///
+    /// The classes of `(a & b) | c` are `{a, b}` and `{c}`.
+    fn symmetry_classes(&self) -> Vec<BTreeSet<T>> {
+        // Symmetry is an equivalence relation, hence it is sufficient to compare each
+        // variable with the first variable of each class.
+        let mut classes: Vec<BTreeSet<T>> = Vec::new();
+        for variable in self.inputs() {
+            let class = classes.iter_mut().find(|class| {
+                let representative = class.first().expect("Classes are not empty");
+                self.is_symmetric_in(representative, &variable)
+            });
+            match class {
+                Some(class) => {
+                    class.insert(variable);
+                }
+                None => classes.push(BTreeSet::from([variable])),
+            }
+        }
+        classes
+    }
+
+    /// The maximal classes of inputs in which this function is symmetric when negations
+    /// are allowed. The classes partition the inputs and are ordered by their smallest
+    /// variable.
+    ///
+    /// Every class maps its variables to `true` if the variable is negated relative to the
+    /// smallest variable of the class (which is itself mapped to `false`). If two variables are
+    /// symmetric both with and without negation, the plain symmetry is preferred.
+    ///
+    /// ### Examples
+    ///
+    /// The function `(a & !b) | c` has classes `{a: false, b: true}` and `{c: false}`.
+    fn negation_symmetry_classes(&self) -> Vec<BTreeMap<T, bool>> {
+        // Composing (negated) swaps results in (negated) swaps, hence the classes can be
+        // built by comparing each variable with the first variable of each class.
+        let mut classes: Vec<BTreeMap<T, bool>> = Vec::new();
+        for variable in self.inputs() {
+            let class = classes.iter_mut().find_map(|class| {
+                let (representative, _) = class.first_key_value().expect("Classes are not empty");
+                if self.is_symmetric_in(representative, &variable) {
+                    Some((class, false))
+                } else if self.is_negation_symmetric_in(representative, &variable) {
+                    Some((class, true))
+                } else {
+                    None
+                }
+            });
+            match class {
+                Some((class, is_negated)) => {
+                    class.insert(variable, is_negated);
+                }
+                None => classes.push(BTreeMap::from([(variable, false)])),
+            }
+        }
+        classes
+    }
+
+    /// Returns `true` if this function is symmetric in every pair of its inputs, i.e. its
+    /// output only depends on the number of `true` inputs.
+    fn is_totally_symmetric(&self) -> bool {
+        self.symmetry_classes().len() <= 1
+    }
+}
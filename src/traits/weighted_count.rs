@@ -0,0 +1,147 @@
+use crate::traits::BooleanFunction;
+use num_bigint::BigUint;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+/// A commutative semiring of weights used by [WeightedCount].
+///
+/// The implementations for numeric types use the standard addition and multiplication.
+/// For `bool`, addition is disjunction and multiplication is conjunction.
+pub trait Semiring: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(&self, other: &Self) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+}
+
+/// A [Semiring] of probabilities, i.e. values between `0` and `1`.
+pub trait ProbabilityValue: Semiring {
+    /// The probability of the complementary event, i.e. `1 - self`.
+    fn complement(&self) -> Self;
+
+    /// The probability `1/2`.
+    fn half() -> Self;
+}
+
+/// A trait implemented by Boolean function representations that can compute weighted model
+/// counts, i.e. sums of weights of satisfying points.
+pub trait WeightedCount<T>: BooleanFunction<T>
+where
+    T: Debug + Clone + Eq + Ord,
+{
+    /// The sum over all points of [BooleanFunction::support] of the product of the weights
+    /// of all input literals, where `weight(v, b)` is the weight of input `v` with value `b`.
+    ///
+    /// For example, with `weight` always `1`, the result is [BooleanFunction::weight].
+    fn weighted_count<S: Semiring, F: Fn(&T, bool) -> S>(&self, weight: F) -> S;
+
+    /// The probability that this function evaluates to `1` when each input `v` is independently
+    /// `true` with probability `probabilities[v]`. Inputs without a given probability are
+    /// `true` with probability `1/2`, same as in [BooleanFunction::weight].
+    ///
+    /// Use `BigRational` probabilities to obtain an exact result.
+    fn probability<P: ProbabilityValue>(&self, probabilities: &BTreeMap<T, P>) -> P {
+        self.weighted_count(|variable, value| {
+            let p_true = probabilities.get(variable).cloned().unwrap_or_else(P::half);
+            if value {
+                p_true
+            } else {
+                p_true.complement()
+            }
+        })
+    }
+}
+
+impl Semiring for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+impl ProbabilityValue for f64 {
+    fn complement(&self) -> Self {
+        1.0 - self
+    }
+
+    fn half() -> Self {
+        0.5
+    }
+}
+
+impl Semiring for BigRational {
+    fn zero() -> Self {
+        Zero::zero()
+    }
+
+    fn one() -> Self {
+        One::one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+impl ProbabilityValue for BigRational {
+    fn complement(&self) -> Self {
+        <BigRational as One>::one() - self
+    }
+
+    fn half() -> Self {
+        BigRational::new(1.into(), 2.into())
+    }
+}
+
+impl Semiring for BigUint {
+    fn zero() -> Self {
+        Zero::zero()
+    }
+
+    fn one() -> Self {
+        One::one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+impl Semiring for bool {
+    fn zero() -> Self {
+        false
+    }
+
+    fn one() -> Self {
+        true
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        *self || *other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        *self && *other
+    }
+}
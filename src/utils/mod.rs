@@ -1,7 +1,9 @@
 pub use bool_point_to_valuation::boolean_point_to_valuation;
 pub use btreeset_to_valuation::btreeset_to_valuation;
+pub use random_below::random_below;
 pub use row_index_to_bool_point::row_index_to_bool_point;
 
 mod bool_point_to_valuation;
 mod btreeset_to_valuation;
+mod random_below;
 mod row_index_to_bool_point;
@@ -0,0 +1,53 @@
+use num_bigint::BigUint;
+use rand::Rng;
+
+/// A uniformly random number in `[0, bound)`, computed by rejection sampling.
+///
+/// The `bound` must be positive.
+pub fn random_below<R: Rng>(rng: &mut R, bound: &BigUint) -> BigUint {
+    assert!(bound.bits() > 0, "The bound must be positive");
+    let bits = bound.bits();
+    let bytes = bits.div_ceil(8) as usize;
+    let mut buffer = vec![0u8; bytes];
+    loop {
+        rng.fill(buffer.as_mut_slice());
+        // Clear the bits above the length of `bound`, so that the rejection probability
+        // is below one half.
+        let excess = (bytes as u64) * 8 - bits;
+        if let Some(last) = buffer.last_mut() {
+            *last &= 0xFF >> excess;
+        }
+        let candidate = BigUint::from_bytes_le(&buffer);
+        if &candidate < bound {
+            return candidate;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_random_below_ok() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let bound = BigUint::from(5u8);
+
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            let value = random_below(&mut rng, &bound);
+            assert!(value < bound);
+            seen[usize::try_from(value).unwrap()] = true;
+        }
+        assert!(seen.iter().all(|it| *it));
+
+        let large = BigUint::from(1u8) << 100;
+        assert!(random_below(&mut rng, &large) < large);
+        assert_eq!(
+            random_below(&mut rng, &BigUint::from(1u8)),
+            BigUint::from(0u8)
+        );
+    }
+}
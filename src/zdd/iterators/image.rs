@@ -0,0 +1,48 @@
+use crate::iterators::DomainIterator;
+use crate::zdd::evaluate_point;
+use crate::zdd::manager::ZddNode;
+
+pub struct ImageIterator {
+    domain_iterator: DomainIterator,
+    nodes: Vec<ZddNode>,
+    root: usize,
+}
+
+impl ImageIterator {
+    pub(crate) fn new(input_count: usize, nodes: &[ZddNode], root: usize) -> Self {
+        Self {
+            domain_iterator: DomainIterator::from_count(input_count),
+            nodes: nodes.to_vec(),
+            root,
+        }
+    }
+}
+
+impl Iterator for ImageIterator {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.domain_iterator
+            .next()
+            .map(|point| evaluate_point(&self.nodes, self.root, &point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::BooleanFunction;
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_image_ok() {
+        let expression = var("d") & var("b") | var("a");
+        let input = Zdd::try_from(expression.clone()).expect("Should not panic here");
+
+        let actual = input.image().collect::<Vec<_>>();
+        let expected = TruthTable::from(expression).image().collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
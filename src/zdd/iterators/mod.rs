@@ -0,0 +1,5 @@
+pub use image::ImageIterator;
+pub use support::SupportIterator;
+
+mod image;
+mod support;
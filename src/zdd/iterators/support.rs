@@ -0,0 +1,77 @@
+use crate::zdd::manager::{ZddNode, BASE, EMPTY};
+
+/// Iterates over the paths of a ZDD to the `{∅}` terminal, i.e. over the sets of its family,
+/// each returned as a Boolean point.
+pub struct SupportIterator {
+    nodes: Vec<ZddNode>,
+    input_count: usize,
+    /// Nodes which remain to be explored, each with the values of the variables above it.
+    stack: Vec<(usize, Vec<bool>)>,
+}
+
+impl SupportIterator {
+    pub(crate) fn new(nodes: &[ZddNode], root: usize, input_count: usize) -> Self {
+        Self {
+            nodes: nodes.to_vec(),
+            input_count,
+            stack: vec![(root, Vec::new())],
+        }
+    }
+}
+
+impl Iterator for SupportIterator {
+    type Item = Vec<bool>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, mut point)) = self.stack.pop() {
+            match node {
+                EMPTY => continue,
+                BASE => {
+                    point.resize(self.input_count, false);
+                    return Some(point);
+                }
+                _ => {
+                    let ZddNode { var, low, high } = self.nodes[node];
+                    // Skipped variables are `false`.
+                    point.resize(var, false);
+                    let mut high_point = point.clone();
+                    high_point.push(true);
+                    point.push(false);
+                    // The `low` branch is explored first, so the points are sorted.
+                    self.stack.push((high, high_point));
+                    self.stack.push((low, point));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::BooleanFunction;
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_support_ok() {
+        let expression = var("d") & var("b") | !var("a");
+        let input = Zdd::try_from(expression.clone()).expect("Should not panic here");
+
+        let actual = input.support().collect::<Vec<_>>();
+        let expected = TruthTable::from(expression).support().collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+        assert_eq!(input.sat_point(), expected.first().cloned());
+    }
+
+    #[test]
+    fn test_support_constants_ok() {
+        assert_eq!(
+            Zdd::<String>::mk_const(true).support().collect::<Vec<_>>(),
+            vec![Vec::<bool>::new()]
+        );
+        assert_eq!(Zdd::<String>::mk_const(false).support().count(), 0);
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+
+/// Index of the empty family, i.e. the constant `false`.
+pub(crate) const EMPTY: usize = 0;
+/// Index of the family containing only the empty set, i.e. `{∅}`.
+pub(crate) const BASE: usize = 1;
+
+/// A ZDD node. Terminal nodes use `var` equal to the number of variables.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ZddNode {
+    pub var: usize,
+    pub low: usize,
+    pub high: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+    Join,
+    Meet,
+    Minimal,
+    Maximal,
+    NonSupersets,
+    NonSubsets,
+    Subset0,
+    Subset1,
+}
+
+/// A shared node table with operation caches in which all ZDD algorithms run.
+///
+/// `Zdd` objects own a compact copy of their nodes: operations import the operands into
+/// a fresh manager, compute the result and export it (see [ZddManager::export]).
+pub(crate) struct ZddManager {
+    variable_count: usize,
+    nodes: Vec<ZddNode>,
+    unique: HashMap<ZddNode, usize>,
+    cache: HashMap<(Operation, usize, usize), usize>,
+}
+
+impl ZddManager {
+    pub fn new(variable_count: usize) -> Self {
+        let terminal = |index| ZddNode {
+            var: variable_count,
+            low: index,
+            high: index,
+        };
+        Self {
+            variable_count,
+            nodes: vec![terminal(EMPTY), terminal(BASE)],
+            unique: HashMap::new(),
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn variable_count(&self) -> usize {
+        self.variable_count
+    }
+
+    pub fn var_of(&self, index: usize) -> usize {
+        self.nodes[index].var
+    }
+
+    /// Creates (or finds) the node `var ? high : low`, applying the zero-suppression rule.
+    pub fn mk(&mut self, var: usize, low: usize, high: usize) -> usize {
+        debug_assert!(var < self.var_of(low) && var < self.var_of(high));
+        if high == EMPTY {
+            return low;
+        }
+
+        let node = ZddNode { var, low, high };
+        if let Some(index) = self.unique.get(&node) {
+            return *index;
+        }
+        self.nodes.push(node);
+        self.unique.insert(node, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    /// Imports the ZDD `root` of the node table `nodes` (with variables `0..levels.len()`),
+    /// placing its variable `v` at level `levels[v]`. The `levels` must be increasing.
+    ///
+    /// If `dont_care` is set, the result represents the same Boolean function, i.e. it
+    /// does not depend on the variables which are new in this manager. Otherwise, it represents
+    /// the same family of sets, i.e. the new variables never appear in its sets.
+    pub fn import(
+        &mut self,
+        nodes: &[ZddNode],
+        root: usize,
+        levels: &[usize],
+        dont_care: bool,
+    ) -> usize {
+        let mut is_new = vec![true; self.variable_count];
+        for level in levels {
+            is_new[*level] = false;
+        }
+
+        let mut cache = HashMap::new();
+        self.import_rec(nodes, root, 0, levels, &is_new, dont_care, &mut cache)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn import_rec(
+        &mut self,
+        nodes: &[ZddNode],
+        node: usize,
+        level: usize,
+        levels: &[usize],
+        is_new: &[bool],
+        dont_care: bool,
+        cache: &mut HashMap<(usize, usize), usize>,
+    ) -> usize {
+        if node == EMPTY {
+            return EMPTY;
+        }
+        let node_level = match node {
+            BASE => self.variable_count,
+            _ => levels[nodes[node].var],
+        };
+        // Without don't-care nodes, all levels above the node can be skipped.
+        let level = if dont_care { level } else { node_level };
+        if level == self.variable_count {
+            return node;
+        }
+        if let Some(result) = cache.get(&(node, level)) {
+            return *result;
+        }
+
+        let result = if node_level == level {
+            let ZddNode { low, high, .. } = nodes[node];
+            let low = self.import_rec(nodes, low, level + 1, levels, is_new, dont_care, cache);
+            let high = self.import_rec(nodes, high, level + 1, levels, is_new, dont_care, cache);
+            self.mk(level, low, high)
+        } else if is_new[level] {
+            let inner = self.import_rec(nodes, node, level + 1, levels, is_new, dont_care, cache);
+            self.mk(level, inner, inner)
+        } else {
+            // The variable is suppressed in the original ZDD, i.e. it must be false.
+            self.import_rec(nodes, node, level + 1, levels, is_new, dont_care, cache)
+        };
+
+        cache.insert((node, level), result);
+        result
+    }
+
+    /// Copies the nodes reachable from `root` into a compact node table in DFS post-order
+    /// (which is canonical for a given function), returning the table and the new root.
+    pub fn export(&self, root: usize) -> (Vec<ZddNode>, usize) {
+        let mut result = self.nodes[..2].to_vec();
+        let mut new_index = HashMap::from([(EMPTY, EMPTY), (BASE, BASE)]);
+
+        let mut stack = vec![(root, false)];
+        while let Some((node, expanded)) = stack.pop() {
+            if new_index.contains_key(&node) {
+                continue;
+            }
+            let ZddNode { var, low, high } = self.nodes[node];
+            if expanded {
+                result.push(ZddNode {
+                    var,
+                    low: new_index[&low],
+                    high: new_index[&high],
+                });
+                new_index.insert(node, result.len() - 1);
+            } else {
+                stack.push((node, true));
+                stack.push((high, false));
+                stack.push((low, false));
+            }
+        }
+
+        (result, new_index[&root])
+    }
+
+    /// The family of all subsets of the variables, i.e. the constant `true` function.
+    pub fn power_set(&mut self) -> usize {
+        (0..self.variable_count)
+            .rev()
+            .fold(BASE, |inner, var| self.mk(var, inner, inner))
+    }
+
+    /// The cofactors of `node` with respect to `var`, assuming `var` is not below its root.
+    fn cofactors(&self, node: usize, var: usize) -> (usize, usize) {
+        let ZddNode {
+            var: node_var,
+            low,
+            high,
+        } = self.nodes[node];
+        if node_var == var {
+            (low, high)
+        } else {
+            (node, EMPTY)
+        }
+    }
+
+    fn cached(&self, operation: Operation, left: usize, right: usize) -> Option<usize> {
+        self.cache.get(&(operation, left, right)).copied()
+    }
+
+    fn store(&mut self, operation: Operation, left: usize, right: usize, result: usize) -> usize {
+        self.cache.insert((operation, left, right), result);
+        result
+    }
+
+    pub fn union(&mut self, left: usize, right: usize) -> usize {
+        if left == EMPTY || left == right {
+            return right;
+        } else if right == EMPTY {
+            return left;
+        }
+        let (left, right) = (left.min(right), left.max(right));
+        if let Some(result) = self.cached(Operation::Union, left, right) {
+            return result;
+        }
+
+        let var = self.var_of(left).min(self.var_of(right));
+        let (left_0, left_1) = self.cofactors(left, var);
+        let (right_0, right_1) = self.cofactors(right, var);
+        let low = self.union(left_0, right_0);
+        let high = self.union(left_1, right_1);
+        let result = self.mk(var, low, high);
+        self.store(Operation::Union, left, right, result)
+    }
+
+    pub fn intersection(&mut self, left: usize, right: usize) -> usize {
+        if left == EMPTY || right == EMPTY {
+            return EMPTY;
+        } else if left == right {
+            return left;
+        }
+        let (left, right) = (left.min(right), left.max(right));
+        if let Some(result) = self.cached(Operation::Intersection, left, right) {
+            return result;
+        }
+
+        let var = self.var_of(left).min(self.var_of(right));
+        let (left_0, left_1) = self.cofactors(left, var);
+        let (right_0, right_1) = self.cofactors(right, var);
+        let low = self.intersection(left_0, right_0);
+        let high = self.intersection(left_1, right_1);
+        let result = self.mk(var, low, high);
+        self.store(Operation::Intersection, left, right, result)
+    }
+
+    pub fn difference(&mut self, left: usize, right: usize) -> usize {
+        if left == EMPTY || left == right {
+            return EMPTY;
+        } else if right == EMPTY {
+            return left;
+        }
+        if let Some(result) = self.cached(Operation::Difference, left, right) {
+            return result;
+        }
+
+        let var = self.var_of(left).min(self.var_of(right));
+        let (left_0, left_1) = self.cofactors(left, var);
+        let (right_0, right_1) = self.cofactors(right, var);
+        let low = self.difference(left_0, right_0);
+        let high = self.difference(left_1, right_1);
+        let result = self.mk(var, low, high);
+        self.store(Operation::Difference, left, right, result)
+    }
+
+    /// `{ a ∪ b | a ∈ left, b ∈ right }`
+    pub fn join(&mut self, left: usize, right: usize) -> usize {
+        if left == EMPTY || right == EMPTY {
+            return EMPTY;
+        } else if left == BASE {
+            return right;
+        } else if right == BASE {
+            return left;
+        }
+        let (left, right) = (left.min(right), left.max(right));
+        if let Some(result) = self.cached(Operation::Join, left, right) {
+            return result;
+        }
+
+        let var = self.var_of(left).min(self.var_of(right));
+        let (left_0, left_1) = self.cofactors(left, var);
+        let (right_0, right_1) = self.cofactors(right, var);
+        let low = self.join(left_0, right_0);
+        let high_11 = self.join(left_1, right_1);
+        let high_10 = self.join(left_1, right_0);
+        let high_01 = self.join(left_0, right_1);
+        let high = self.union(high_11, high_10);
+        let high = self.union(high, high_01);
+        let result = self.mk(var, low, high);
+        self.store(Operation::Join, left, right, result)
+    }
+
+    /// `{ a ∩ b | a ∈ left, b ∈ right }`
+    pub fn meet(&mut self, left: usize, right: usize) -> usize {
+        if left == EMPTY || right == EMPTY {
+            return EMPTY;
+        } else if left == BASE || right == BASE {
+            return BASE;
+        }
+        let (left, right) = (left.min(right), left.max(right));
+        if let Some(result) = self.cached(Operation::Meet, left, right) {
+            return result;
+        }
+
+        let var = self.var_of(left).min(self.var_of(right));
+        let (left_0, left_1) = self.cofactors(left, var);
+        let (right_0, right_1) = self.cofactors(right, var);
+        let low_00 = self.meet(left_0, right_0);
+        let low_01 = self.meet(left_0, right_1);
+        let low_10 = self.meet(left_1, right_0);
+        let low = self.union(low_00, low_01);
+        let low = self.union(low, low_10);
+        let high = self.meet(left_1, right_1);
+        let result = self.mk(var, low, high);
+        self.store(Operation::Meet, left, right, result)
+    }
+
+    fn contains_empty_set(&self, mut node: usize) -> bool {
+        while node > BASE {
+            node = self.nodes[node].low;
+        }
+        node == BASE
+    }
+
+    /// The sets of `family` which are not a superset of any set in `filter`.
+    fn non_supersets(&mut self, family: usize, filter: usize) -> usize {
+        if filter == EMPTY {
+            return family;
+        } else if family == EMPTY || family == filter || self.contains_empty_set(filter) {
+            return EMPTY;
+        } else if family == BASE {
+            return BASE;
+        }
+        if let Some(result) = self.cached(Operation::NonSupersets, family, filter) {
+            return result;
+        }
+
+        let (family_var, filter_var) = (self.var_of(family), self.var_of(filter));
+        let result = if family_var > filter_var {
+            let filter_0 = self.nodes[filter].low;
+            self.non_supersets(family, filter_0)
+        } else {
+            let (family_0, family_1) = self.cofactors(family, family_var);
+            let (filter_0, filter_1) = self.cofactors(filter, family_var);
+            let low = self.non_supersets(family_0, filter_0);
+            let high_0 = self.non_supersets(family_1, filter_0);
+            let high_1 = self.non_supersets(family_1, filter_1);
+            let high = self.intersection(high_0, high_1);
+            self.mk(family_var, low, high)
+        };
+        self.store(Operation::NonSupersets, family, filter, result)
+    }
+
+    /// The sets of `family` which are not a subset of any set in `filter`.
+    fn non_subsets(&mut self, family: usize, filter: usize) -> usize {
+        if family == EMPTY || family == filter {
+            return EMPTY;
+        } else if filter == EMPTY {
+            return family;
+        } else if family == BASE {
+            return EMPTY;
+        } else if filter == BASE {
+            return self.difference(family, BASE);
+        }
+        if let Some(result) = self.cached(Operation::NonSubsets, family, filter) {
+            return result;
+        }
+
+        let (family_var, filter_var) = (self.var_of(family), self.var_of(filter));
+        let result = if family_var > filter_var {
+            let ZddNode { low, high, .. } = self.nodes[filter];
+            let filter = self.union(low, high);
+            self.non_subsets(family, filter)
+        } else {
+            let (family_0, family_1) = self.cofactors(family, family_var);
+            let (filter_0, filter_1) = self.cofactors(filter, family_var);
+            let filter_any = self.union(filter_0, filter_1);
+            let low = self.non_subsets(family_0, filter_any);
+            let high = self.non_subsets(family_1, filter_1);
+            self.mk(family_var, low, high)
+        };
+        self.store(Operation::NonSubsets, family, filter, result)
+    }
+
+    /// The sets of `family` which have no proper subset in `family`.
+    pub fn minimal(&mut self, family: usize) -> usize {
+        if family <= BASE {
+            return family;
+        }
+        if let Some(result) = self.cached(Operation::Minimal, family, family) {
+            return result;
+        }
+
+        let ZddNode { var, low, high } = self.nodes[family];
+        let low = self.minimal(low);
+        let high = self.minimal(high);
+        let high = self.non_supersets(high, low);
+        let result = self.mk(var, low, high);
+        self.store(Operation::Minimal, family, family, result)
+    }
+
+    /// The sets of `family` which have no proper superset in `family`.
+    pub fn maximal(&mut self, family: usize) -> usize {
+        if family <= BASE {
+            return family;
+        }
+        if let Some(result) = self.cached(Operation::Maximal, family, family) {
+            return result;
+        }
+
+        let ZddNode { var, low, high } = self.nodes[family];
+        let low = self.maximal(low);
+        let high = self.maximal(high);
+        let low = self.non_subsets(low, high);
+        let result = self.mk(var, low, high);
+        self.store(Operation::Maximal, family, family, result)
+    }
+
+    /// The sets of `family` which do not contain `var`.
+    pub fn subset_0(&mut self, family: usize, var: usize) -> usize {
+        let node = self.nodes[family];
+        if node.var > var {
+            return family;
+        } else if node.var == var {
+            return node.low;
+        }
+        if let Some(result) = self.cached(Operation::Subset0, family, var) {
+            return result;
+        }
+
+        let low = self.subset_0(node.low, var);
+        let high = self.subset_0(node.high, var);
+        let result = self.mk(node.var, low, high);
+        self.store(Operation::Subset0, family, var, result)
+    }
+
+    /// The sets of `family` which contain `var`, with `var` removed.
+    pub fn subset_1(&mut self, family: usize, var: usize) -> usize {
+        let node = self.nodes[family];
+        if node.var > var {
+            return EMPTY;
+        } else if node.var == var {
+            return node.high;
+        }
+        if let Some(result) = self.cached(Operation::Subset1, family, var) {
+            return result;
+        }
+
+        let low = self.subset_1(node.low, var);
+        let high = self.subset_1(node.high, var);
+        let result = self.mk(node.var, low, high);
+        self.store(Operation::Subset1, family, var, result)
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use crate::zdd::manager::{ZddManager, ZddNode, BASE, EMPTY};
+
+pub mod iterators;
+pub(crate) mod manager;
+mod set_operations;
+mod traits;
+
+/*
+   A zero-suppressed decision diagram represents a Boolean function through the family of
+   sets of variables which are `true` in its satisfying points. Unlike in a BDD, a variable
+   skipped on a path is `false` (not a don't-care), and a node whose `high` edge leads to
+   the empty family is removed.
+
+   Hence, the same `Zdd` can be seen either as a Boolean function over its `inputs`, or as
+   a family of subsets of its `inputs`. Boolean operations (bit operators, `BooleanFunction`)
+   first extend the operands to the union of their inputs such that the new variables are
+   don't-cares. The set operations (see `set_operations`) extend the operands such that
+   the new variables are never present in the sets.
+
+   Conversions:
+
+   Bdd -> Zdd: reorder the Bdd to the sorted order and rebuild it level by level, inserting
+   a node with equal children for every variable skipped in the Bdd.
+
+   Zdd -> Bdd: rebuild it level by level using if-then-else, with `!x & ...` for every
+   variable skipped in the Zdd.
+
+   Table -> Zdd: split the table rows by the value of each variable recursively.
+
+   Zdd -> Table, Zdd -> Expression: iterate the support / follow the Zdd nodes.
+
+   Expression -> Zdd: through a Bdd.
+
+*/
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Zdd<TLiteral>
+where
+    TLiteral: Debug + Clone + Eq + Ord,
+{
+    /// Sorted vector of unique variables. The ZDD variable `i` corresponds to `inputs[i]`.
+    inputs: Vec<TLiteral>,
+    /// The nodes reachable from `root`, in DFS post-order (see `ZddManager::export`). The
+    /// first two nodes are the terminals, the empty family and `{∅}`. Since the order
+    /// is canonical, two `Zdd` objects are equal iff they represent the same family.
+    nodes: Vec<ZddNode>,
+    root: usize,
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord> Zdd<TLiteral> {
+    pub fn mk_const(value: bool) -> Zdd<TLiteral> {
+        let root = if value { BASE } else { EMPTY };
+        Zdd::from_manager(Vec::new(), &ZddManager::new(0), root)
+    }
+
+    pub fn mk_literal(variable: TLiteral, value: bool) -> Zdd<TLiteral> {
+        let mut manager = ZddManager::new(1);
+        // The negative literal is the family `{∅}` over `[variable]`.
+        let root = if value {
+            manager.mk(0, EMPTY, BASE)
+        } else {
+            BASE
+        };
+        Zdd::from_manager(vec![variable], &manager, root)
+    }
+
+    /// Creates the `Zdd` of the given family of `sets`. Its inputs are all the variables
+    /// that appear in some of the sets.
+    pub fn from_sets<I: IntoIterator<Item = BTreeSet<TLiteral>>>(sets: I) -> Zdd<TLiteral> {
+        let sets = sets.into_iter().collect::<Vec<_>>();
+        let inputs = sets
+            .iter()
+            .flatten()
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let mut manager = ZddManager::new(inputs.len());
+        let mut root = EMPTY;
+        for set in sets {
+            let set_root = inputs
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, var)| set.contains(var))
+                .fold(BASE, |inner, (level, _)| manager.mk(level, EMPTY, inner));
+            root = manager.union(root, set_root);
+        }
+        Zdd::from_manager(inputs, &manager, root)
+    }
+
+    /// The family of sets represented by this `Zdd`, i.e. for each satisfying point, the set
+    /// of variables which are `true`. The sets are listed in the order of
+    /// [crate::traits::BooleanFunction::support].
+    pub fn sets(&self) -> Vec<BTreeSet<TLiteral>> {
+        self.support_points()
+            .map(|point| {
+                point
+                    .into_iter()
+                    .zip(&self.inputs)
+                    .filter(|(value, _)| *value)
+                    .map(|(_, var)| var.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The number of sets in the family represented by this `Zdd` (same as its weight).
+    pub fn set_count(&self) -> BigUint {
+        fold_nodes(
+            &self.nodes,
+            self.root,
+            BigUint::zero(),
+            BigUint::one(),
+            |_, low, high| low + high,
+        )
+    }
+
+    /// The number of nodes of this `Zdd`, including the terminal nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord> Zdd<TLiteral> {
+    pub(crate) fn from_manager(inputs: Vec<TLiteral>, manager: &ZddManager, root: usize) -> Self {
+        let (nodes, root) = manager.export(root);
+        Self {
+            inputs,
+            nodes,
+            root,
+        }
+    }
+
+    pub(crate) fn nodes(&self) -> &[ZddNode] {
+        &self.nodes
+    }
+
+    pub(crate) fn root(&self) -> usize {
+        self.root
+    }
+
+    pub(crate) fn inputs_slice(&self) -> &[TLiteral] {
+        &self.inputs
+    }
+
+    /// The ZDD variable of `variable`, or `None` if it is not an input of this `Zdd`.
+    fn level_of(&self, variable: &TLiteral) -> Option<usize> {
+        self.inputs.binary_search(variable).ok()
+    }
+
+    /// Imports this `Zdd` into `manager`, whose variables are the sorted `inputs`
+    /// (a superset of `self.inputs`). See `ZddManager::import`.
+    fn import_into(&self, manager: &mut ZddManager, inputs: &[TLiteral], dont_care: bool) -> usize {
+        let levels = self
+            .inputs
+            .iter()
+            .map(|var| {
+                inputs
+                    .binary_search(var)
+                    .expect("Inputs should be a superset")
+            })
+            .collect::<Vec<_>>();
+        manager.import(&self.nodes, self.root, &levels, dont_care)
+    }
+
+    /// Creates a manager with a copy of this `Zdd`, returning the manager and the copied root.
+    fn to_manager(&self) -> (ZddManager, usize) {
+        let mut manager = ZddManager::new(self.inputs.len());
+        let root = self.import_into(&mut manager, &self.inputs, false);
+        (manager, root)
+    }
+
+    /// Imports `self` and `other` into a common manager over the union of their inputs.
+    fn union_and_extend(
+        &self,
+        other: &Zdd<TLiteral>,
+        dont_care: bool,
+    ) -> (ZddManager, usize, usize, Vec<TLiteral>) {
+        let inputs = self
+            .inputs
+            .iter()
+            .chain(&other.inputs)
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let mut manager = ZddManager::new(inputs.len());
+        let self_root = self.import_into(&mut manager, &inputs, dont_care);
+        let other_root = other.import_into(&mut manager, &inputs, dont_care);
+        (manager, self_root, other_root, inputs)
+    }
+
+    /// Removes the `variables` from the inputs of a `Zdd` computed in `manager` over
+    /// `self.inputs`. The `root` must not depend on any of the removed variables.
+    fn prune_variables<P: Fn(&TLiteral) -> bool>(
+        &self,
+        manager: &ZddManager,
+        root: usize,
+        is_removed: P,
+    ) -> Zdd<TLiteral> {
+        let mut new_levels = Vec::with_capacity(self.inputs.len());
+        let mut inputs = Vec::with_capacity(self.inputs.len());
+        for var in &self.inputs {
+            new_levels.push(inputs.len());
+            if !is_removed(var) {
+                inputs.push(var.clone());
+            }
+        }
+
+        let (mut nodes, root) = manager.export(root);
+        for (index, node) in nodes.iter_mut().enumerate() {
+            node.var = match index {
+                EMPTY | BASE => inputs.len(),
+                _ => new_levels[node.var],
+            };
+        }
+        Zdd {
+            inputs,
+            nodes,
+            root,
+        }
+    }
+
+    /// Applies the binary operation `op` of the manager to `self` and `other`, extending both
+    /// to the union of their inputs (see `ZddManager::import` for the meaning of `dont_care`).
+    fn apply<F: Fn(&mut ZddManager, usize, usize) -> usize>(
+        &self,
+        other: &Zdd<TLiteral>,
+        dont_care: bool,
+        op: F,
+    ) -> Zdd<TLiteral> {
+        let (mut manager, self_root, other_root, inputs) = self.union_and_extend(other, dont_care);
+        let root = op(&mut manager, self_root, other_root);
+        Zdd::from_manager(inputs, &manager, root)
+    }
+
+    /// Iterates over the satisfying points (in the sorted order of inputs).
+    fn support_points(&self) -> iterators::SupportIterator {
+        iterators::SupportIterator::new(&self.nodes, self.root, self.inputs.len())
+    }
+}
+
+/// Evaluates the ZDD `root` of `nodes` in the given `point`.
+pub(crate) fn evaluate_point(nodes: &[ZddNode], root: usize, point: &[bool]) -> bool {
+    let mut node = root;
+    for (level, value) in point.iter().enumerate() {
+        if node == EMPTY {
+            return false;
+        }
+        let ZddNode { var, low, high } = nodes[node];
+        if var == level {
+            node = if *value { high } else { low };
+        } else if *value {
+            // The variable is skipped, i.e. it must be false.
+            return false;
+        }
+    }
+    node == BASE
+}
+
+/// Computes a value for every node of the ZDD `root` of `nodes` bottom-up, where `op` combines
+/// a node with the values of its children. Returns the value of the `root`.
+pub(crate) fn fold_nodes<R, F: FnMut(&ZddNode, &R, &R) -> R>(
+    nodes: &[ZddNode],
+    root: usize,
+    empty: R,
+    base: R,
+    mut op: F,
+) -> R {
+    // Exported node tables are in post-order, so every node is processed after its children.
+    let mut results = Vec::with_capacity(nodes.len());
+    results.push(empty);
+    results.push(base);
+    for node in &nodes[2..] {
+        let result = op(node, &results[node.low], &results[node.high]);
+        results.push(result);
+    }
+    results.swap_remove(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::BooleanFunction;
+
+    fn set(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_mk_const_and_literal_ok() {
+        assert_eq!(Zdd::<String>::mk_const(true).sets(), vec![set(&[])]);
+        assert!(Zdd::<String>::mk_const(false).sets().is_empty());
+
+        let positive = Zdd::mk_literal("a".to_string(), true);
+        let negative = Zdd::mk_literal("a".to_string(), false);
+        assert_eq!(positive.sets(), vec![set(&["a"])]);
+        assert_eq!(negative.sets(), vec![set(&[])]);
+        assert_eq!(positive.node_count(), 3);
+        assert_eq!(negative.node_count(), 2);
+    }
+
+    #[test]
+    fn test_from_sets_ok() {
+        let family = vec![set(&["a", "c"]), set(&["b"]), set(&[]), set(&["a", "c"])];
+        let zdd = Zdd::from_sets(family);
+
+        assert_eq!(zdd.inputs(), set(&["a", "b", "c"]));
+        assert_eq!(zdd.set_count(), BigUint::from(3u8));
+        assert_eq!(zdd.sets(), vec![set(&[]), set(&["b"]), set(&["a", "c"])]);
+        assert_eq!(zdd.weight(), zdd.set_count());
+    }
+
+    #[test]
+    fn test_canonical_equality_ok() {
+        let first = Zdd::from_sets(vec![set(&["a"]), set(&["b", "c"])]);
+        let second = Zdd::from_sets(vec![set(&["c", "b"]), set(&["a"]), set(&["a"])]);
+
+        assert_eq!(first, second);
+        assert_ne!(
+            first,
+            Zdd::from_sets(vec![set(&["a"]), set(&["b"]), set(&["c"])])
+        );
+    }
+}
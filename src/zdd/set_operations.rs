@@ -0,0 +1,171 @@
+use std::fmt::Debug;
+
+use crate::zdd::manager::ZddManager;
+use crate::zdd::Zdd;
+
+/// Operations which treat a `Zdd` as a family of sets of its inputs.
+///
+/// The binary operations first extend both operands to the union of their inputs such that
+/// the new variables do not appear in any set, i.e. the families themselves are unchanged.
+/// Consequently, they can differ from the corresponding Boolean operations: for example,
+/// the union of `{{a}}` and `{{b}}` is `{{a}, {b}}`, while `a | b` is also satisfied
+/// by `{a, b}`.
+impl<TLiteral: Debug + Clone + Eq + Ord> Zdd<TLiteral> {
+    /// The sets which are in `self` or in `other`.
+    pub fn union(&self, other: &Zdd<TLiteral>) -> Zdd<TLiteral> {
+        self.apply(other, false, ZddManager::union)
+    }
+
+    /// The sets which are both in `self` and in `other`.
+    pub fn intersection(&self, other: &Zdd<TLiteral>) -> Zdd<TLiteral> {
+        self.apply(other, false, ZddManager::intersection)
+    }
+
+    /// The sets which are in `self` but not in `other`.
+    pub fn difference(&self, other: &Zdd<TLiteral>) -> Zdd<TLiteral> {
+        self.apply(other, false, ZddManager::difference)
+    }
+
+    /// The family `{ a ∪ b | a ∈ self, b ∈ other }`.
+    pub fn join(&self, other: &Zdd<TLiteral>) -> Zdd<TLiteral> {
+        self.apply(other, false, ZddManager::join)
+    }
+
+    /// The family `{ a ∩ b | a ∈ self, b ∈ other }`.
+    pub fn meet(&self, other: &Zdd<TLiteral>) -> Zdd<TLiteral> {
+        self.apply(other, false, ZddManager::meet)
+    }
+
+    /// The sets of this family which have no proper subset in this family.
+    pub fn minimal(&self) -> Zdd<TLiteral> {
+        let (mut manager, root) = self.to_manager();
+        let root = manager.minimal(root);
+        Zdd::from_manager(self.inputs.clone(), &manager, root)
+    }
+
+    /// The sets of this family which have no proper superset in this family.
+    pub fn maximal(&self) -> Zdd<TLiteral> {
+        let (mut manager, root) = self.to_manager();
+        let root = manager.maximal(root);
+        Zdd::from_manager(self.inputs.clone(), &manager, root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::zdd::Zdd;
+    use std::collections::BTreeSet;
+
+    fn family(sets: &[&[&str]]) -> Zdd<String> {
+        Zdd::from_sets(
+            sets.iter()
+                .map(|set| set.iter().map(|it| it.to_string()).collect::<BTreeSet<_>>()),
+        )
+    }
+
+    #[test]
+    fn test_union_intersection_difference_ok() {
+        let left = family(&[&["a"], &["a", "b"], &[]]);
+        let right = family(&[&["a", "b"], &["c"]]);
+
+        assert_eq!(
+            left.union(&right),
+            family(&[&[], &["a"], &["a", "b"], &["c"]])
+        );
+        assert_eq!(
+            left.intersection(&right).sets(),
+            family(&[&["a", "b"]]).sets()
+        );
+        assert_eq!(
+            left.difference(&right).sets(),
+            family(&[&[], &["a"]]).sets()
+        );
+        assert_eq!(right.difference(&left).sets(), family(&[&["c"]]).sets());
+    }
+
+    #[test]
+    fn test_union_is_not_boolean_or_ok() {
+        let left = family(&[&["a"]]);
+        let right = family(&[&["b"]]);
+
+        assert_eq!(left.union(&right), family(&[&["a"], &["b"]]));
+        assert_eq!((&left | &right).set_count(), 3u8.into());
+    }
+
+    #[test]
+    fn test_join_meet_ok() {
+        let left = family(&[&["a"], &["b"]]);
+        let right = family(&[&["b", "c"], &[]]);
+
+        assert_eq!(
+            left.join(&right),
+            family(&[&["a"], &["b"], &["a", "b", "c"], &["b", "c"]])
+        );
+        assert_eq!(left.meet(&right).sets(), family(&[&[], &["b"]]).sets());
+        assert_eq!(left.join(&family(&[])).set_count(), 0u8.into());
+        assert_eq!(left.join(&family(&[&[]])), left);
+    }
+
+    #[test]
+    fn test_minimal_maximal_ok() {
+        let input = family(&[
+            &["a"],
+            &["a", "b"],
+            &["b", "c"],
+            &["c"],
+            &["a", "b", "c"],
+            &["d"],
+        ]);
+
+        assert_eq!(
+            input.minimal().sets(),
+            family(&[&["a"], &["c"], &["d"]]).sets()
+        );
+        assert_eq!(
+            input.maximal().sets(),
+            family(&[&["a", "b", "c"], &["d"]]).sets()
+        );
+
+        let with_empty = input.union(&family(&[&[]]));
+        assert_eq!(with_empty.minimal().sets(), family(&[&[]]).sets());
+        assert_eq!(with_empty.maximal(), input.maximal());
+    }
+
+    #[test]
+    fn test_random_against_naive_ok() {
+        use crate::random::RandomFunctions;
+
+        let inputs = (0..5).map(|it| format!("x{it}")).collect::<Vec<_>>();
+        for seed in 0..20 {
+            let mut random = RandomFunctions::new(inputs.clone(), seed);
+            let left = Zdd::from(random.uniform_table());
+            let right = Zdd::from(random.uniform_table());
+            let (left_sets, right_sets) = (left.sets(), right.sets());
+
+            let minimal = left_sets
+                .iter()
+                .filter(|set| !left_sets.iter().any(|it| it != *set && it.is_subset(set)))
+                .cloned();
+            let maximal = left_sets
+                .iter()
+                .filter(|set| !left_sets.iter().any(|it| it != *set && it.is_superset(set)))
+                .cloned();
+            let join = left_sets.iter().flat_map(|a| {
+                right_sets
+                    .iter()
+                    .map(move |b| a.union(b).cloned().collect::<BTreeSet<_>>())
+            });
+            let meet = left_sets.iter().flat_map(|a| {
+                right_sets
+                    .iter()
+                    .map(move |b| a.intersection(b).cloned().collect::<BTreeSet<_>>())
+            });
+
+            let sorted = |zdd: Zdd<String>| zdd.sets().into_iter().collect::<BTreeSet<_>>();
+            assert_eq!(sorted(left.minimal()), minimal.collect());
+            assert_eq!(sorted(left.maximal()), maximal.collect());
+            assert_eq!(sorted(left.join(&right)), join.collect());
+            assert_eq!(sorted(left.meet(&right)), meet.collect());
+        }
+    }
+}
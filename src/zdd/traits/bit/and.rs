@@ -0,0 +1,65 @@
+use crate::zdd::manager::ZddManager;
+use crate::zdd::Zdd;
+use std::fmt::Debug;
+use std::ops::{BitAnd, BitAndAssign};
+
+fn and_common<TLiteral: Debug + Clone + Eq + Ord>(
+    me: &Zdd<TLiteral>,
+    other: &Zdd<TLiteral>,
+) -> Zdd<TLiteral> {
+    me.apply(other, true, ZddManager::intersection)
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord + 'static> BitAnd for Zdd<TLiteral> {
+    type Output = Zdd<TLiteral>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        and_common(&self, &rhs)
+    }
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord + 'static> BitAnd for &Zdd<TLiteral> {
+    type Output = Zdd<TLiteral>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        and_common(self, rhs)
+    }
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord + 'static> BitAndAssign for Zdd<TLiteral> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = and_common(self, &rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::BooleanFunction;
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_and_same_variables() {
+        let left = var("a") & !var("b");
+        let right = var("a") | var("b");
+
+        let actual = Zdd::try_from(left.clone()).unwrap() & Zdd::try_from(right.clone()).unwrap();
+        let expected = Zdd::try_from(left & right).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_and_different_variables() {
+        let left = var("a") & !var("c");
+        let right = var("b") | !var("d");
+
+        let mut actual = Zdd::try_from(left.clone()).unwrap();
+        actual &= Zdd::try_from(right.clone()).unwrap();
+        let expected = left & right;
+
+        assert_eq!(actual, Zdd::try_from(expected.clone()).unwrap());
+        assert!(TruthTable::from(actual).is_equivalent(&TruthTable::from(expected)));
+    }
+}
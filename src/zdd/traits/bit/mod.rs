@@ -0,0 +1,4 @@
+mod and;
+mod not;
+mod or;
+mod xor;
@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+use std::ops::Not;
+
+use crate::zdd::Zdd;
+
+impl<T: Debug + Clone + Ord> Not for &Zdd<T> {
+    type Output = Zdd<T>;
+
+    fn not(self) -> Self::Output {
+        let (mut manager, root) = self.to_manager();
+        let power_set = manager.power_set();
+        let root = manager.difference(power_set, root);
+        Zdd::from_manager(self.inputs.clone(), &manager, root)
+    }
+}
+
+impl<T: Debug + Clone + Ord> Not for Zdd<T> {
+    type Output = Zdd<T>;
+
+    fn not(self) -> Self::Output {
+        (&self).not()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_not_ok() {
+        let input = var("a") & !var("b") | var("c");
+
+        let actual = !Zdd::try_from(input.clone()).unwrap();
+        let expected = Zdd::try_from(!input).unwrap();
+
+        assert_eq!(actual, expected);
+        assert_eq!(!Zdd::<String>::mk_const(true), Zdd::mk_const(false));
+        assert_eq!(!Zdd::mk_literal("a", true), Zdd::mk_literal("a", false));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::zdd::manager::ZddManager;
+use crate::zdd::Zdd;
+use std::fmt::Debug;
+use std::ops::{BitXor, BitXorAssign};
+
+fn xor_common<TLiteral: Debug + Clone + Eq + Ord>(
+    me: &Zdd<TLiteral>,
+    other: &Zdd<TLiteral>,
+) -> Zdd<TLiteral> {
+    me.apply(other, true, |manager: &mut ZddManager, left, right| {
+        let left_only = manager.difference(left, right);
+        let right_only = manager.difference(right, left);
+        manager.union(left_only, right_only)
+    })
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord + 'static> BitXor for Zdd<TLiteral> {
+    type Output = Zdd<TLiteral>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        xor_common(&self, &rhs)
+    }
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord + 'static> BitXor for &Zdd<TLiteral> {
+    type Output = Zdd<TLiteral>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        xor_common(self, rhs)
+    }
+}
+
+impl<TLiteral: Debug + Clone + Eq + Ord + 'static> BitXorAssign for Zdd<TLiteral> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = xor_common(self, &rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::BooleanFunction;
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_xor_same_variables() {
+        let left = var("a") & !var("b");
+        let right = var("a") | var("b");
+
+        let actual = Zdd::try_from(left.clone()).unwrap() ^ Zdd::try_from(right.clone()).unwrap();
+        let expected = Zdd::try_from((left.clone() & !right.clone()) | (!left & right)).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_xor_different_variables() {
+        let left = var("a") & !var("c");
+        let right = var("b") | !var("d");
+
+        let mut actual = Zdd::try_from(left.clone()).unwrap();
+        actual ^= Zdd::try_from(right.clone()).unwrap();
+        let expected = (left.clone() & !right.clone()) | (!left & right);
+
+        assert_eq!(actual, Zdd::try_from(expected.clone()).unwrap());
+        assert!(TruthTable::from(actual).is_equivalent(&TruthTable::from(expected)));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::bdd::Bdd;
+use crate::iterators::DomainIterator;
+use crate::traits::{BooleanFunction, BooleanValuation};
+use crate::zdd::iterators::{ImageIterator, SupportIterator};
+use crate::zdd::manager::{ZddManager, EMPTY};
+use crate::zdd::Zdd;
+use num_bigint::BigUint;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::iter::{zip, Zip};
+
+impl<T: Debug + Clone + Ord> BooleanFunction<T> for Zdd<T> {
+    type DomainIterator = DomainIterator;
+    type RangeIterator = ImageIterator;
+    type RelationIterator = Zip<DomainIterator, ImageIterator>;
+    type SupportIterator = SupportIterator;
+
+    fn inputs(&self) -> BTreeSet<T> {
+        self.inputs.iter().cloned().collect()
+    }
+
+    fn essential_inputs(&self) -> BTreeSet<T> {
+        let (mut manager, root) = self.to_manager();
+        self.inputs
+            .iter()
+            .enumerate()
+            .filter(|(level, _)| {
+                // Both restrictions are in the same manager, hence they are equal iff
+                // they represent the same function.
+                manager.subset_0(root, *level) != manager.subset_1(root, *level)
+            })
+            .map(|(_, var)| var.clone())
+            .collect()
+    }
+
+    fn domain(&self) -> Self::DomainIterator {
+        DomainIterator::from_count(self.inputs.len())
+    }
+
+    fn image(&self) -> Self::RangeIterator {
+        ImageIterator::new(self.inputs.len(), &self.nodes, self.root)
+    }
+
+    fn relation(&self) -> Self::RelationIterator {
+        zip(self.domain(), self.image())
+    }
+
+    fn support(&self) -> Self::SupportIterator {
+        self.support_points()
+    }
+
+    fn weight(&self) -> BigUint {
+        self.set_count()
+    }
+
+    fn restrict(&self, valuation: &BooleanValuation<T>) -> Self {
+        let (mut manager, root) = self.to_manager();
+        let root = valuation
+            .iter()
+            .fold(root, |root, (var, value)| match self.level_of(var) {
+                Some(level) if *value => manager.subset_1(root, level),
+                Some(level) => manager.subset_0(root, level),
+                None => root,
+            });
+
+        self.prune_variables(&manager, root, |var| valuation.contains_key(var))
+    }
+
+    fn substitute(&self, mapping: &BTreeMap<T, Self>) -> Self {
+        let to_bdd = |zdd: &Zdd<T>| {
+            Bdd::try_from(zdd.clone())
+                .expect("Substitution result should have at most 2^16 variables")
+        };
+        let bdd_mapping = mapping
+            .iter()
+            .map(|(var, zdd)| (var.clone(), to_bdd(zdd)))
+            .collect::<BTreeMap<_, _>>();
+
+        Zdd::from(to_bdd(self).substitute(&bdd_mapping))
+    }
+
+    fn existential_quantification(&self, variables: BTreeSet<T>) -> Self {
+        self.eliminate(&variables, ZddManager::union)
+    }
+
+    fn universal_quantification(&self, variables: BTreeSet<T>) -> Self {
+        self.eliminate(&variables, ZddManager::intersection)
+    }
+
+    fn derivative(&self, variables: BTreeSet<T>) -> Self {
+        self.eliminate(&variables, |manager, low, high| {
+            let low_only = manager.difference(low, high);
+            let high_only = manager.difference(high, low);
+            manager.union(low_only, high_only)
+        })
+    }
+
+    fn is_equivalent(&self, other: &Self) -> bool {
+        let (_manager, self_root, other_root, _inputs) = self.union_and_extend(other, true);
+
+        self_root == other_root
+    }
+
+    fn is_implied_by(&self, other: &Self) -> bool {
+        let (mut manager, self_root, other_root, _inputs) = self.union_and_extend(other, true);
+
+        manager.difference(other_root, self_root) == EMPTY
+    }
+}
+
+impl<T: Debug + Clone + Ord> Zdd<T> {
+    /// For each of the `variables`, replaces the function `F` with `op(F[v = 0], F[v = 1])`
+    /// and removes `v` from the inputs.
+    fn eliminate<F: Fn(&mut ZddManager, usize, usize) -> usize>(
+        &self,
+        variables: &BTreeSet<T>,
+        op: F,
+    ) -> Zdd<T> {
+        let (mut manager, root) = self.to_manager();
+        let root =
+            variables
+                .iter()
+                .filter_map(|var| self.level_of(var))
+                .fold(root, |root, level| {
+                    let low = manager.subset_0(root, level);
+                    let high = manager.subset_1(root, level);
+                    op(&mut manager, low, high)
+                });
+
+        self.prune_variables(&manager, root, |var| variables.contains(var))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::{var, Expression};
+    use crate::traits::BooleanFunction;
+    use crate::zdd::Zdd;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn both(expression: Expression<String>) -> (Zdd<String>, Bdd<String>) {
+        (
+            Zdd::try_from(expression.clone()).expect("Should not panic here"),
+            Bdd::try_from(expression).expect("Should not panic here"),
+        )
+    }
+
+    fn names(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn test_inputs_and_essential_inputs_ok() {
+        let (zdd, bdd) = both((var("a") & var("b")) | (var("a") & !var("b")) | !var("c"));
+
+        assert_eq!(zdd.inputs(), bdd.inputs());
+        assert_eq!(zdd.essential_inputs(), names(&["a", "c"]));
+        assert_eq!(zdd.essential_inputs(), bdd.essential_inputs());
+        assert_eq!(zdd.degree(), 3);
+        assert_eq!(zdd.essential_degree(), 2);
+    }
+
+    #[test]
+    fn test_domain_image_relation_weight_ok() {
+        let (zdd, bdd) = both(var("a") ^ !var("b") | var("c"));
+
+        assert!(zdd.domain().eq(bdd.domain()));
+        assert!(zdd.image().eq(bdd.image()));
+        assert!(zdd.relation().eq(bdd.relation()));
+        assert_eq!(zdd.weight(), bdd.weight());
+    }
+
+    #[test]
+    fn test_restrict_ok() {
+        let (zdd, bdd) = both((var("a") | var("b")) & !var("c"));
+        let valuation = BTreeMap::from([
+            ("a".to_string(), false),
+            ("c".to_string(), false),
+            ("x".to_string(), true),
+        ]);
+
+        let actual = zdd.restrict(&valuation);
+
+        assert_eq!(actual.inputs(), names(&["b"]));
+        assert_eq!(actual, Zdd::try_from(var("b")).unwrap());
+        assert_eq!(Bdd::try_from(actual).unwrap(), bdd.restrict(&valuation));
+    }
+
+    #[test]
+    fn test_substitute_ok() {
+        let (zdd, _) = both(var("a") & !var("c"));
+        let mapping = BTreeMap::from([(
+            "a".to_string(),
+            Zdd::try_from(var("a") | var("b") | var("c")).unwrap(),
+        )]);
+
+        let actual = zdd.substitute(&mapping);
+        let expected = Zdd::try_from((var("a") | var("b") | var("c")) & !var("c")).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_quantification_and_derivative_ok() {
+        let (zdd, bdd) = both((var("a") & var("b")) | (!var("a") & var("c")) | var("d"));
+        let variables = names(&["a", "d"]);
+
+        let check = |actual: Zdd<String>, expected: Bdd<String>| {
+            assert_eq!(Bdd::try_from(actual).unwrap(), expected);
+        };
+
+        check(
+            zdd.existential_quantification(variables.clone()),
+            bdd.existential_quantification(variables.clone()),
+        );
+        check(
+            zdd.universal_quantification(variables.clone()),
+            bdd.universal_quantification(variables.clone()),
+        );
+        check(zdd.derivative(variables.clone()), bdd.derivative(variables));
+    }
+
+    #[test]
+    fn test_is_equivalent_and_implied_ok() {
+        let (left, _) = both(var("a") & var("b"));
+        let (right, _) = both(var("b") & var("a") & (var("c") | !var("c")));
+        let (weaker, _) = both(var("a") | var("d"));
+
+        assert!(left.is_equivalent(&right));
+        assert!(right.is_equivalent(&left));
+        assert!(weaker.is_implied_by(&left));
+        assert!(!left.is_implied_by(&weaker));
+        assert!(!left.is_equivalent(&weaker));
+    }
+}
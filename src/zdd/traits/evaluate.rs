@@ -0,0 +1,65 @@
+use crate::traits::Evaluate;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use crate::zdd::{evaluate_point, Zdd};
+use itertools::Itertools;
+
+impl<TLiteral: Debug + Clone + Eq + Ord> Evaluate<TLiteral> for Zdd<TLiteral> {
+    fn evaluate_with_default(
+        &self,
+        literal_values: &BTreeMap<TLiteral, bool>,
+        default_value: bool,
+    ) -> bool {
+        let point = self
+            .inputs
+            .iter()
+            .map(|input| *literal_values.get(input).unwrap_or(&default_value))
+            .collect_vec();
+
+        evaluate_point(&self.nodes, self.root, &point)
+    }
+
+    fn evaluate_checked(
+        &self,
+        literal_values: &BTreeMap<TLiteral, bool>,
+    ) -> Result<bool, Vec<TLiteral>> {
+        let (point, errors): (Vec<_>, Vec<_>) = self
+            .inputs
+            .iter()
+            .map(|input| literal_values.get(input).copied().ok_or(input.clone()))
+            .partition_result();
+
+        if !errors.is_empty() {
+            Err(errors)
+        } else {
+            Ok(evaluate_point(&self.nodes, self.root, &point))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::var;
+
+    #[test]
+    fn test_evaluate_ok() {
+        let input =
+            Zdd::try_from(!(var("a") & var("b")) & !var("c")).expect("Should not panic here");
+
+        let mapping = BTreeMap::from([("a".to_string(), true), ("b".to_string(), false)]);
+
+        assert!(input.evaluate(&mapping));
+        assert!(!input.evaluate_with_default(&mapping, true));
+        assert_eq!(input.evaluate_checked(&mapping), Err(vec!["c".to_string()]));
+
+        let mapping = BTreeMap::from([
+            ("a".to_string(), true),
+            ("b".to_string(), true),
+            ("c".to_string(), false),
+            ("d".to_string(), true),
+        ]);
+        assert_eq!(input.evaluate_checked(&mapping), Ok(false));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::bdd::Bdd;
+use crate::zdd::manager::{ZddManager, BASE, EMPTY};
+use crate::zdd::Zdd;
+use biodivine_lib_bdd::{Bdd as InnerBdd, BddPointer};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> From<Bdd<T>> for Zdd<T> {
+    fn from(value: Bdd<T>) -> Self {
+        let mut sorted = value.variable_order().to_vec();
+        sorted.sort();
+        let bdd = value
+            .reorder(&sorted)
+            .expect("Sorted inputs should be a valid variable order");
+
+        let mut manager = ZddManager::new(sorted.len());
+        let mut cache = HashMap::new();
+        let root = from_bdd_rec(
+            bdd.inner(),
+            bdd.inner().root_pointer(),
+            0,
+            &mut manager,
+            &mut cache,
+        );
+        Zdd::from_manager(sorted, &manager, root)
+    }
+}
+
+/// Builds the ZDD of the BDD `node` over the variables starting from `level`.
+fn from_bdd_rec(
+    bdd: &InnerBdd,
+    node: BddPointer,
+    level: usize,
+    manager: &mut ZddManager,
+    cache: &mut HashMap<(BddPointer, usize), usize>,
+) -> usize {
+    if node.is_zero() {
+        return EMPTY;
+    } else if level == manager.variable_count() {
+        return BASE;
+    }
+    if let Some(result) = cache.get(&(node, level)) {
+        return *result;
+    }
+
+    let result = if !node.is_terminal() && bdd.var_of(node).to_index() == level {
+        let low = from_bdd_rec(bdd, bdd.low_link_of(node), level + 1, manager, cache);
+        let high = from_bdd_rec(bdd, bdd.high_link_of(node), level + 1, manager, cache);
+        manager.mk(level, low, high)
+    } else {
+        // The variable is a don't-care in the BDD.
+        let inner = from_bdd_rec(bdd, node, level + 1, manager, cache);
+        manager.mk(level, inner, inner)
+    };
+
+    cache.insert((node, level), result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::BooleanFunction;
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_zdd_from_bdd_ok() {
+        let expression = (var("a") & var("c")) | (!var("b") & var("d"));
+        let bdd = Bdd::try_from(expression.clone()).unwrap();
+
+        let actual = Zdd::from(bdd.clone());
+
+        assert_eq!(actual.inputs(), bdd.inputs());
+        assert!(actual.image().eq(TruthTable::from(expression).image()));
+        assert_eq!(Bdd::try_from(actual).unwrap(), bdd);
+    }
+
+    #[test]
+    fn test_zdd_from_reordered_bdd_ok() {
+        let expression = (var("a") & var("c")) | (!var("b") & var("d"));
+        let bdd = Bdd::try_from(expression.clone()).unwrap();
+        let order = ["d", "b", "a", "c"].map(String::from);
+
+        let actual = Zdd::from(bdd.reorder(&order).unwrap());
+
+        assert_eq!(actual, Zdd::from(bdd));
+    }
+
+    #[test]
+    fn test_zdd_from_bdd_constants_ok() {
+        assert_eq!(
+            Zdd::from(Bdd::<String>::mk_const(true)),
+            Zdd::mk_const(true)
+        );
+        assert_eq!(
+            Zdd::from(Bdd::<String>::mk_const(false)),
+            Zdd::mk_const(false)
+        );
+
+        let tautology = Bdd::try_from(var("a") | !var("a")).unwrap();
+        assert_eq!(Zdd::from(tautology).set_count(), 2u8.into());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::zdd::Zdd;
+use std::fmt::Debug;
+use std::num::TryFromIntError;
+
+impl<T: Debug + Clone + Ord> TryFrom<Expression<T>> for Zdd<T> {
+    type Error = TryFromIntError;
+
+    /// The conversion goes through a `Bdd`, hence it has the same limit on the number
+    /// of variables.
+    fn try_from(value: Expression<T>) -> Result<Self, Self::Error> {
+        Ok(Zdd::from(Bdd::try_from(value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::{bool, var, Expression};
+    use crate::traits::{BooleanFunction, SemanticEq};
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_zdd_from_expression_ok() {
+        let expression = (var("a") & !var("b")) | (var("c") & var("a"));
+
+        let actual = Zdd::try_from(expression.clone()).unwrap();
+
+        assert_eq!(actual.inputs(), expression.inputs());
+        assert!(Expression::from(actual).semantic_eq(&expression));
+    }
+
+    #[test]
+    fn test_zdd_from_expression_constant_ok() {
+        let actual = Zdd::try_from(bool(true) & bool(false)).unwrap();
+
+        assert_eq!(actual, Zdd::<String>::mk_const(false));
+    }
+}
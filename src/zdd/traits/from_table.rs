@@ -0,0 +1,59 @@
+use crate::table::TruthTable;
+use crate::traits::BooleanFunction;
+use crate::zdd::manager::{ZddManager, BASE, EMPTY};
+use crate::zdd::Zdd;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> From<TruthTable<T>> for Zdd<T> {
+    fn from(value: TruthTable<T>) -> Self {
+        let inputs = value.inputs().into_iter().collect::<Vec<_>>();
+        let outputs = value.image().collect::<Vec<_>>();
+
+        let mut manager = ZddManager::new(inputs.len());
+        let root = from_rows_rec(&outputs, 0, &mut manager);
+        Zdd::from_manager(inputs, &manager, root)
+    }
+}
+
+/// Builds the ZDD of the table `rows` (a power of two in size) over the variables starting
+/// from `level`. The first half of the rows has the variable at `level` set to `false`.
+fn from_rows_rec(rows: &[bool], level: usize, manager: &mut ZddManager) -> usize {
+    if rows.len() == 1 {
+        return if rows[0] { BASE } else { EMPTY };
+    }
+
+    let (low_rows, high_rows) = rows.split_at(rows.len() / 2);
+    let low = from_rows_rec(low_rows, level + 1, manager);
+    let high = from_rows_rec(high_rows, level + 1, manager);
+    manager.mk(level, low, high)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::table::TruthTable;
+    use crate::traits::BooleanFunction;
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_zdd_from_table_ok() {
+        let table = TruthTable::from((var("a") | var("b")) & !var("c"));
+
+        let actual = Zdd::from(table.clone());
+
+        assert_eq!(actual.inputs(), table.inputs());
+        assert!(actual.image().eq(table.image()));
+        assert_eq!(TruthTable::from(actual), table);
+    }
+
+    #[test]
+    fn test_zdd_from_table_constant_ok() {
+        let table = TruthTable::from(var("a") & !var("a"));
+
+        let actual = Zdd::from(table.clone());
+
+        assert_eq!(actual.node_count(), 2);
+        assert_eq!(actual.weight(), 0u8.into());
+        assert_eq!(TruthTable::from(actual), table);
+    }
+}
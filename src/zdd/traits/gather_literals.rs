@@ -0,0 +1,11 @@
+use crate::zdd::Zdd;
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use crate::traits::GatherLiterals;
+
+impl<TLiteral: Debug + Clone + Eq + Ord> GatherLiterals<TLiteral> for Zdd<TLiteral> {
+    fn gather_literals_rec(&self, current: &mut BTreeSet<TLiteral>) {
+        current.extend(self.inputs.clone())
+    }
+}
@@ -0,0 +1,10 @@
+mod bit;
+mod boolean_function;
+mod evaluate;
+mod from_bdd;
+mod from_expression;
+mod from_table;
+mod gather_literals;
+mod monotonicity;
+mod sample_support;
+mod weighted_count;
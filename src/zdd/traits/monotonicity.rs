@@ -0,0 +1,60 @@
+use crate::bdd::Bdd;
+use crate::expressions::Expression;
+use crate::traits::{Monotonicity, RegulationSign};
+use crate::zdd::Zdd;
+use std::fmt::Debug;
+
+/// All operations convert the ZDD into a `Bdd`, so they panic if the ZDD has more
+/// than `2^16` inputs.
+impl<T: Debug + Clone + Ord> Monotonicity<T> for Zdd<T> {
+    fn regulation_sign(&self, variable: &T) -> RegulationSign {
+        to_bdd(self).regulation_sign(variable)
+    }
+
+    fn is_monotone(&self) -> bool {
+        to_bdd(self).is_monotone()
+    }
+
+    fn upper_monotone_approximation(&self) -> Self {
+        Zdd::from(to_bdd(self).upper_monotone_approximation())
+    }
+
+    fn lower_monotone_approximation(&self) -> Self {
+        Zdd::from(to_bdd(self).lower_monotone_approximation())
+    }
+
+    fn minimal_positive_dnf(&self) -> Option<Expression<T>> {
+        to_bdd(self).minimal_positive_dnf()
+    }
+}
+
+fn to_bdd<T: Debug + Clone + Ord>(zdd: &Zdd<T>) -> Bdd<T> {
+    Bdd::try_from(zdd.clone()).expect("ZDD should have at most 2^16 inputs")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::traits::{BooleanFunction, Monotonicity, RegulationSign};
+    use crate::zdd::Zdd;
+
+    #[test]
+    fn test_monotone_ok() {
+        let function = Zdd::try_from(var("a") & (var("b") | !var("c"))).unwrap();
+
+        assert_eq!(
+            function.regulation_sign(&"c".to_string()),
+            RegulationSign::Inhibiting
+        );
+        assert!(!function.is_monotone());
+        assert!(function
+            .upper_monotone_approximation()
+            .is_equivalent(&Zdd::try_from(var("a")).unwrap()));
+        assert!(function
+            .lower_monotone_approximation()
+            .is_equivalent(&Zdd::try_from(var("a") & var("b")).unwrap()));
+
+        let monotone = Zdd::try_from(var("a") | (var("a") & var("b"))).unwrap();
+        assert_eq!(monotone.minimal_positive_dnf(), Some(var("a")));
+    }
+}
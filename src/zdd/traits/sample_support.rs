@@ -0,0 +1,99 @@
+use crate::traits::{BooleanPoint, BooleanValuation, SampleSupport};
+use crate::utils::random_below;
+use crate::zdd::manager::{ZddManager, ZddNode, BASE};
+use crate::zdd::Zdd;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> SampleSupport<T> for Zdd<T> {
+    fn sample_support_restricted(
+        &self,
+        count: usize,
+        seed: u64,
+        restriction: &BooleanValuation<T>,
+    ) -> Vec<BooleanPoint> {
+        let restricted = restriction
+            .iter()
+            .filter(|(var, _)| self.level_of(var).is_some())
+            .fold(self.clone(), |zdd, (var, value)| {
+                zdd.apply(
+                    &Zdd::mk_literal(var.clone(), *value),
+                    true,
+                    ZddManager::intersection,
+                )
+            });
+
+        // The number of sets below every node (the node table is in post-order).
+        let mut cardinalities = vec![BigUint::zero(), BigUint::one()];
+        for node in &restricted.nodes[2..] {
+            let cardinality = &cardinalities[node.low] + &cardinalities[node.high];
+            cardinalities.push(cardinality);
+        }
+        let total = &cardinalities[restricted.root];
+        if total.is_zero() {
+            return Vec::new();
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| {
+                let mut rank = random_below(&mut rng, total);
+                // Skipped variables are `false`, so only the visited nodes are assigned.
+                let mut point = vec![false; self.inputs.len()];
+                let mut node = restricted.root;
+                while node != BASE {
+                    let ZddNode { var, low, high } = restricted.nodes[node];
+                    if rank < cardinalities[low] {
+                        node = low;
+                    } else {
+                        point[var] = true;
+                        rank -= &cardinalities[low];
+                        node = high;
+                    }
+                }
+                point
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expressions::var;
+    use crate::traits::{BooleanFunction, SampleSupport};
+    use crate::zdd::Zdd;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_sample_support_uniform_ok() {
+        let zdd = Zdd::try_from(var("a") | (var("b") & var("c") & var("d"))).unwrap();
+        let support = zdd.support().collect::<Vec<_>>();
+
+        let samples = zdd.sample_support(9000, 11);
+
+        let mut counts = HashMap::new();
+        for point in samples {
+            assert!(support.contains(&point));
+            *counts.entry(point).or_insert(0) += 1;
+        }
+        // Each of the 9 points is expected 1000 times.
+        assert_eq!(counts.len(), 9);
+        assert!(counts.values().all(|count| (850..1150).contains(count)));
+    }
+
+    #[test]
+    fn test_sample_support_restricted_ok() {
+        let zdd = Zdd::try_from((var("a") & var("b")) | !var("c")).unwrap();
+        let restriction = BTreeMap::from([("c".to_string(), true), ("x".to_string(), false)]);
+
+        let samples = zdd.sample_support_restricted(10, 1, &restriction);
+
+        assert_eq!(samples, vec![vec![true, true, true]; 10]);
+        assert!(Zdd::<String>::mk_const(false)
+            .sample_support(10, 3)
+            .is_empty());
+    }
+}
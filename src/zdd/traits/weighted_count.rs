@@ -0,0 +1,63 @@
+use crate::traits::{Semiring, WeightedCount};
+use crate::zdd::{fold_nodes, Zdd};
+use std::fmt::Debug;
+
+impl<T: Debug + Clone + Ord> WeightedCount<T> for Zdd<T> {
+    fn weighted_count<S: Semiring, F: Fn(&T, bool) -> S>(&self, weight: F) -> S {
+        let weights = self
+            .inputs
+            .iter()
+            .map(|input| (weight(input, false), weight(input, true)))
+            .collect::<Vec<_>>();
+        let variable_count = weights.len();
+
+        // The variables skipped by an edge from `from` to `to` are `false`.
+        let skipped = |from: usize, to: usize| {
+            weights[from..to]
+                .iter()
+                .fold(S::one(), |acc, (w_false, _)| acc.mul(w_false))
+        };
+
+        let (root_level, count) = fold_nodes(
+            &self.nodes,
+            self.root,
+            (variable_count, S::zero()),
+            (variable_count, S::one()),
+            |node, (low_level, low), (high_level, high)| {
+                let (w_false, w_true) = &weights[node.var];
+                let low = w_false.mul(low).mul(&skipped(node.var + 1, *low_level));
+                let high = w_true.mul(high).mul(&skipped(node.var + 1, *high_level));
+                (node.var, low.add(&high))
+            },
+        );
+        count.mul(&skipped(0, root_level))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bdd::Bdd;
+    use crate::expressions::var;
+    use crate::traits::WeightedCount;
+    use crate::zdd::Zdd;
+    use num_rational::BigRational;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_probability_matches_bdd_ok() {
+        let expression = (var("a") & !var("c")) | (!var("b") & var("d"));
+        let zdd = Zdd::try_from(expression.clone()).unwrap();
+        let bdd = Bdd::try_from(expression).unwrap();
+        let probabilities = BTreeMap::from([
+            ("a".to_string(), BigRational::new(1.into(), 3.into())),
+            ("b".to_string(), BigRational::new(2.into(), 7.into())),
+            ("d".to_string(), BigRational::new(4.into(), 5.into())),
+        ]);
+
+        assert_eq!(
+            zdd.probability(&probabilities),
+            bdd.probability(&probabilities)
+        );
+        assert!(!Zdd::<String>::mk_const(false).weighted_count(|_, _| true));
+    }
+}